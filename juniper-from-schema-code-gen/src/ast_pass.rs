@@ -2,12 +2,16 @@ pub mod ast_data_pass;
 pub mod code_gen_pass;
 pub mod directive_parsing;
 pub mod error;
+pub mod lints;
 pub mod schema_visitor;
 
 pub use self::{code_gen_pass::CodeGenPass, error::ErrorKind};
 use graphql_parser::Pos;
 
-use graphql_parser::{query::Name, schema::Type};
+use graphql_parser::{
+    query::Name,
+    schema::{DirectiveDefinition, Type},
+};
 use proc_macro2::{Span, TokenStream};
 use quote::quote;
 use syn::{self, Ident};
@@ -40,3 +44,14 @@ pub enum TypeKind {
 pub trait EmitError<'doc> {
     fn emit_non_fatal_error(&mut self, pos: Pos, kind: ErrorKind<'doc>);
 }
+
+pub trait EmitLint<'doc> {
+    /// Reports the lint as a warning or an error depending on its level, or ignores it if it is
+    /// allowed.
+    fn emit_lint(&mut self, pos: Pos, lint: lints::Lint<'doc>);
+}
+
+pub trait CustomDirectives<'doc> {
+    /// The definition of a directive declared in the schema with `directive @name on ...`.
+    fn custom_directive(&self, name: &str) -> Option<&'doc DirectiveDefinition>;
+}
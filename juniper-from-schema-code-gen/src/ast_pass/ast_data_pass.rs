@@ -1,34 +1,83 @@
 use crate::ast_pass::{
-    directive_parsing::{DateTimeScalarType, ParseDirective},
+    directive_parsing::{
+        DateBackend, DateTimeScalarType, EnumArguments, Ownership, ParseDirective, Payload,
+        TypeArguments, UnionArguments,
+    },
     error::{Error, ErrorKind},
     schema_visitor::SchemaVisitor,
-    type_name, EmitError,
+    type_name, CustomDirectives, EmitError,
 };
 use graphql_parser::{
     schema::{Document, *},
     Pos,
 };
+use heck::CamelCase;
 use std::collections::{BTreeSet, HashMap, HashSet};
 
+const BUILT_IN_DIRECTIVES: &[&str] = &["juniper", "deprecated", "skip", "include"];
+
 #[derive(Debug)]
 pub struct AstData<'doc> {
     interface_implementors: HashMap<&'doc str, Vec<&'doc str>>,
+    implemented_interfaces: HashMap<&'doc str, &'doc [String]>,
     user_scalars: HashSet<&'doc str>,
     enum_variants: HashSet<&'doc str>,
     input_object_field_types: HashMap<&'doc str, HashMap<&'doc String, &'doc Type>>,
     errors: BTreeSet<Error<'doc>>,
     raw_schema: &'doc str,
     include_time_zone_on_date_time_scalar: bool,
+    date_backend: DateBackend,
+    date_time_backend: DateBackend,
+    type_arguments: HashMap<&'doc str, TypeArguments>,
+    enum_arguments: HashMap<&'doc str, EnumArguments>,
+    union_arguments: HashMap<&'doc str, UnionArguments>,
+    enum_variant_names: HashMap<&'doc str, HashMap<&'doc str, String>>,
+    validated_input_objects: HashSet<&'doc str>,
+    tri_state_input_object_fields: HashSet<(&'doc str, &'doc str)>,
+    custom_directives: HashMap<&'doc str, &'doc DirectiveDefinition>,
+    object_types: HashMap<&'doc str, Pos>,
+    root_types: Option<RootTypes<'doc>>,
+}
+
+/// The names of the root operation types. Subscriptions aren't supported so they're left out.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct RootTypes<'doc> {
+    pub query: Option<&'doc str>,
+    pub mutation: Option<&'doc str>,
 }
 
 impl<'doc> SchemaVisitor<'doc> for AstData<'doc> {
+    fn visit_schema_definition(&mut self, schema_def: &'doc SchemaDefinition) {
+        self.root_types = Some(RootTypes {
+            query: schema_def.query.as_deref(),
+            mutation: schema_def.mutation.as_deref(),
+        });
+    }
+
     fn visit_object_type(&mut self, obj: &'doc ObjectType) {
+        self.object_types.insert(&obj.name, obj.position);
+        self.implemented_interfaces
+            .insert(&obj.name, &obj.implements_interfaces);
+
         for interface in &obj.implements_interfaces {
             self.interface_implementors
                 .entry(interface)
-                .or_insert_with(Vec::new)
+                .or_default()
                 .push(&obj.name);
         }
+
+        let args = self.parse_directives(obj);
+        self.type_arguments.insert(&obj.name, args);
+    }
+
+    fn visit_interface_type(&mut self, interface: &'doc InterfaceType) {
+        let args = self.parse_directives(interface);
+        self.type_arguments.insert(&interface.name, args);
+    }
+
+    fn visit_union_type(&mut self, union: &'doc UnionType) {
+        let args = self.parse_directives(union);
+        self.union_arguments.insert(&union.name, args);
     }
 
     fn visit_scalar_type(&mut self, scalar: &'doc ScalarType) {
@@ -40,6 +89,12 @@ impl<'doc> SchemaVisitor<'doc> for AstData<'doc> {
                 } else {
                     self.include_time_zone_on_date_time_scalar = false;
                 }
+                self.date_time_backend = args.backend;
+                self.user_scalars.insert(name);
+            }
+            name if name == crate::DATE_SCALAR_NAME => {
+                let args = self.parse_directives(scalar);
+                self.date_backend = args.backend.unwrap_or_default();
                 self.user_scalars.insert(name);
             }
             name => {
@@ -50,13 +105,57 @@ impl<'doc> SchemaVisitor<'doc> for AstData<'doc> {
 
     fn visit_enum_type(&mut self, enum_type: &'doc EnumType) {
         self.enum_variants.insert(&enum_type.name);
+
+        let args = self.parse_directives(enum_type);
+        self.enum_arguments.insert(&enum_type.name, args);
+
+        let mut values_by_variant_name = HashMap::<String, &'doc str>::new();
+        for enum_value in &enum_type.values {
+            let args = self.parse_directives(enum_value);
+            let variant_name = args
+                .rust_name
+                .name
+                .unwrap_or_else(|| enum_value.name.to_camel_case());
+
+            // This also catches keywords, since syn doesn't parse those as identifiers
+            if syn::parse_str::<syn::Ident>(&variant_name).is_err() {
+                self.emit_non_fatal_error(
+                    enum_value.position,
+                    ErrorKind::InvalidEnumVariantName(variant_name.clone()),
+                );
+            } else if let Some(other_value) = values_by_variant_name.get(&variant_name) {
+                self.emit_non_fatal_error(
+                    enum_value.position,
+                    ErrorKind::EnumVariantNameCollision {
+                        name: variant_name.clone(),
+                        value_a: other_value,
+                        value_b: &enum_value.name,
+                    },
+                );
+            }
+
+            values_by_variant_name.insert(variant_name.clone(), &enum_value.name);
+            self.enum_variant_names
+                .entry(&enum_type.name)
+                .or_default()
+                .insert(&enum_value.name, variant_name);
+        }
     }
 
     fn visit_input_object_type(&mut self, input_type: &'doc InputObjectType) {
         for field in &input_type.fields {
+            let args = self.parse_directives(field);
+            if !args.validations.is_empty() {
+                self.validated_input_objects.insert(&input_type.name);
+            }
+            if args.tri_state.value {
+                self.tri_state_input_object_fields
+                    .insert((&input_type.name, &field.name));
+            }
+
             self.input_object_field_types
                 .entry(&input_type.name)
-                .or_insert_with(HashMap::new)
+                .or_default()
                 .insert(&field.name, &field.value_type);
         }
     }
@@ -68,7 +167,10 @@ impl<'doc> AstData<'doc> {
         doc: &'doc Document,
     ) -> Result<Self, BTreeSet<Error<'doc>>> {
         let mut data = Self::new(raw_schema);
+        data.collect_custom_directives(doc);
         data.visit_document(doc);
+        data.find_nested_validated_input_objects();
+        data.find_implicit_root_types();
 
         if data.errors.is_empty() {
             Ok(data)
@@ -80,15 +182,110 @@ impl<'doc> AstData<'doc> {
     fn new(raw_schema: &'doc str) -> Self {
         Self {
             interface_implementors: Default::default(),
+            implemented_interfaces: Default::default(),
             user_scalars: Default::default(),
             enum_variants: Default::default(),
             input_object_field_types: Default::default(),
             errors: Default::default(),
             raw_schema,
             include_time_zone_on_date_time_scalar: true,
+            date_backend: DateBackend::default(),
+            date_time_backend: DateBackend::default(),
+            type_arguments: Default::default(),
+            enum_arguments: Default::default(),
+            union_arguments: Default::default(),
+            enum_variant_names: Default::default(),
+            validated_input_objects: Default::default(),
+            tri_state_input_object_fields: Default::default(),
+            custom_directives: Default::default(),
+            object_types: Default::default(),
+            root_types: None,
+        }
+    }
+
+    // Directives can be used before they're declared so these are collected before visiting the
+    // rest of the schema. Declarations of built in directives are allowed, so schemas can declare
+    // `@juniper` for other tools, but they don't change anything.
+    fn collect_custom_directives(&mut self, doc: &'doc Document) {
+        for def in &doc.definitions {
+            let directive = match def {
+                Definition::DirectiveDefinition(directive) => directive,
+                _ => continue,
+            };
+
+            if BUILT_IN_DIRECTIVES.contains(&&*directive.name) {
+                continue;
+            }
+
+            if self.custom_directives.contains_key(&*directive.name) {
+                self.emit_non_fatal_error(
+                    directive.position,
+                    ErrorKind::DirectiveDefinedMoreThanOnce(&directive.name),
+                );
+            } else {
+                self.custom_directives.insert(&directive.name, directive);
+            }
+        }
+    }
+
+    // Without a `schema { ... }` definition the spec says the root types are the ones named `Query`,
+    // `Mutation`, and `Subscription`. Schemas without a `Query` type either don't get a root type,
+    // which lets them define types used elsewhere.
+    fn find_implicit_root_types(&mut self) {
+        if self.root_types.is_some() {
+            return;
+        }
+
+        let root_type = |name: &'static str| {
+            self.object_types
+                .get_key_value(name)
+                .map(|(name, pos)| (*name, *pos))
+        };
+
+        let query = match root_type("Query") {
+            Some((name, _)) => name,
+            None => return,
+        };
+        let mutation = root_type("Mutation").map(|(name, _)| name);
+
+        if let Some((_, pos)) = root_type("Subscription") {
+            self.emit_non_fatal_error(pos, ErrorKind::SubscriptionsNotSupported);
+        }
+
+        self.root_types = Some(RootTypes {
+            query: Some(query),
+            mutation,
+        });
+    }
+
+    // Input objects with fields of input object types that need validating must be validated as
+    // well, so keep adding those until there are no more.
+    fn find_nested_validated_input_objects(&mut self) {
+        loop {
+            let nested = self
+                .input_object_field_types
+                .iter()
+                .filter(|(name, _)| !self.validated_input_objects.contains(*name))
+                .filter(|(_, fields)| {
+                    fields
+                        .values()
+                        .any(|type_| self.validated_input_objects.contains(&**type_name(type_)))
+                })
+                .map(|(name, _)| *name)
+                .collect::<Vec<_>>();
+
+            if nested.is_empty() {
+                break;
+            }
+
+            self.validated_input_objects.extend(nested);
         }
     }
 
+    pub fn root_types(&self) -> RootTypes<'doc> {
+        self.root_types.unwrap_or_default()
+    }
+
     pub fn get_implementors_of_interface(&self, name: &str) -> Option<&Vec<&str>> {
         self.interface_implementors.get(name)
     }
@@ -113,6 +310,14 @@ impl<'doc> AstData<'doc> {
         }
     }
 
+    pub fn date_backend(&self) -> DateBackend {
+        self.date_backend
+    }
+
+    pub fn date_time_backend(&self) -> DateBackend {
+        self.date_time_backend
+    }
+
     pub fn uuid_scalar_defined(&self) -> bool {
         self.is_scalar(crate::UUID_SCALAR_NAME)
     }
@@ -121,6 +326,73 @@ impl<'doc> AstData<'doc> {
         self.is_scalar(crate::URL_SCALAR_NAME)
     }
 
+    pub fn type_guard(&self, name: &str) -> Option<&syn::Expr> {
+        self.type_arguments.get(name)?.guard.expr.as_ref()
+    }
+
+    // The guards of the interfaces an object implements followed by the guard on the object
+    // itself. Interface guards must apply to the object's fields as well since they can be
+    // queried directly with fragments such as `... on User { secret }`.
+    pub fn object_guards(&self, name: &str) -> Vec<&syn::Expr> {
+        self.implemented_interfaces
+            .get(name)
+            .into_iter()
+            .flat_map(|interfaces| interfaces.iter())
+            .map(|interface| &**interface)
+            .chain(std::iter::once(name))
+            .filter_map(|name| self.type_guard(name))
+            .collect()
+    }
+
+    // Objects without ownership of their own use the ownership of the first interface they
+    // implement that has one. Otherwise the interface's fields would expect a different return
+    // type than the object's field methods.
+    pub fn type_ownership(&self, name: &str) -> Option<Ownership> {
+        std::iter::once(name)
+            .chain(
+                self.implemented_interfaces
+                    .get(name)
+                    .into_iter()
+                    .flat_map(|interfaces| interfaces.iter())
+                    .map(|interface| &**interface),
+            )
+            .find_map(|name| self.type_arguments.get(name)?.ownership)
+    }
+
+    pub fn interface_payload(&self, name: &str) -> Payload {
+        self.type_arguments
+            .get(name)
+            .map(|args| args.payload)
+            .unwrap_or_default()
+    }
+
+    pub fn union_arguments(&self, name: &str) -> Option<&UnionArguments> {
+        self.union_arguments.get(name)
+    }
+
+    // Interfaces and unions with `cow` payloads borrow their variants so their enums take a
+    // lifetime.
+    pub fn type_has_lifetime(&self, name: &str) -> bool {
+        let union_payload = self.union_arguments(name).map(|args| args.payload);
+        self.interface_payload(name) == Payload::Cow || union_payload == Some(Payload::Cow)
+    }
+
+    pub fn enum_arguments(&self, name: &str) -> Option<&EnumArguments> {
+        self.enum_arguments.get(name)
+    }
+
+    pub fn enum_variant_name(&self, enum_name: &str, value_name: &str) -> Option<&str> {
+        self.enum_variant_names
+            .get(enum_name)?
+            .get(value_name)
+            .map(|name| name.as_str())
+    }
+
+    // Whether an input object has fields, or nested fields, with validation directives.
+    pub fn input_object_is_validated(&self, name: &str) -> bool {
+        self.validated_input_objects.contains(name)
+    }
+
     pub fn is_scalar(&self, name: &str) -> bool {
         self.user_scalars.contains(name)
     }
@@ -146,27 +418,43 @@ impl<'doc> AstData<'doc> {
         }
     }
 
-    pub fn input_object_field_names(
+    pub fn is_input_object(&self, name: &str) -> bool {
+        self.input_object_field_types.contains_key(name)
+    }
+
+    pub fn input_object_fields(
         &self,
-        input_type_name: &'doc str,
-    ) -> Option<HashSet<&'doc &String>> {
-        let field_map = self.input_object_field_types.get(input_type_name)?;
-        let mut out = HashSet::new();
-        for key in field_map.keys() {
-            out.insert(key);
-        }
-        Some(out)
+        input_type_name: &str,
+    ) -> Option<&HashMap<&'doc String, &'doc Type>> {
+        self.input_object_field_types.get(input_type_name)
     }
 
-    #[allow(clippy::ptr_arg)]
-    pub fn input_object_field_type_name(
+    pub fn input_object_field_type(
         &self,
-        input_type_name: &'doc str,
-        field_name: &'doc String,
-    ) -> Option<&'doc Name> {
-        let field_map = &self.input_object_field_types.get(input_type_name)?;
-        let type_ = field_map.get(field_name)?;
-        Some(type_name(&type_))
+        input_type_name: &str,
+        field_name: &str,
+    ) -> Option<&'doc Type> {
+        self.input_object_field_types
+            .get(input_type_name)?
+            .iter()
+            .find(|(name, _)| name.as_str() == field_name)
+            .map(|(_, type_)| *type_)
+    }
+
+    pub fn input_object_field_is_tri_state(&self, input_type_name: &str, field_name: &str) -> bool {
+        self.tri_state_input_object_fields
+            .iter()
+            .any(|(ty, field)| *ty == input_type_name && *field == field_name)
+    }
+
+    pub fn has_custom_directives(&self) -> bool {
+        !self.custom_directives.is_empty()
+    }
+}
+
+impl<'doc> CustomDirectives<'doc> for AstData<'doc> {
+    fn custom_directive(&self, name: &str) -> Option<&'doc DirectiveDefinition> {
+        self.custom_directives.get(name).copied()
     }
 }
 
@@ -1,13 +1,22 @@
+mod gen_directive_metadata;
 mod gen_query_trails;
+mod lint_schema;
+mod validate_default_values;
+mod validate_schema;
 
 use super::{
     error::{Error, ErrorKind},
-    ident, quote_ident, type_name, EmitError, TypeKind,
+    ident,
+    lints::{Lint, LintLevel, LintLevels},
+    quote_ident, type_name, CustomDirectives, EmitError, EmitLint, TypeKind,
 };
 use crate::{
     ast_pass::{
         ast_data_pass::{AstData, DateTimeScalarDefinition},
-        directive_parsing::{Deprecation, FieldArguments, Ownership, ParseDirective},
+        directive_parsing::{
+            DataTypeArguments, DateBackend, Deprecation, Derive, EnumArguments, Ownership,
+            ParseDirective, Payload, ScalarRepr, Validations,
+        },
         schema_visitor::SchemaVisitor,
     },
     nullable_type::NullableType,
@@ -18,21 +27,30 @@ use graphql_parser::{
     Pos,
 };
 use heck::{CamelCase, SnakeCase};
+use lint_schema::SchemaLinter;
 use proc_macro2::{TokenStream, TokenTree};
 use quote::quote;
 use std::{
-    collections::{BTreeMap, BTreeSet, HashSet},
+    collections::{BTreeMap, BTreeSet},
+    convert::TryFrom,
     iter::Extend,
     string::ToString,
 };
 use syn::Ident;
+use validate_default_values::DefaultValueValidator;
+use validate_schema::SchemaValidator;
 
 #[derive(Debug)]
 pub struct CodeGenPass<'doc> {
     tokens: TokenStream,
     error_type: syn::Type,
     context_type: syn::Type,
+    middleware: Option<syn::Type>,
+    default_ownership: Ownership,
+    data_type_arguments: DataTypeArguments,
+    lint_levels: LintLevels,
     errors: BTreeSet<Error<'doc>>,
+    warnings: BTreeSet<(Pos, Lint<'doc>)>,
     ast_data: AstData<'doc>,
     raw_schema: &'doc str,
 }
@@ -45,29 +63,24 @@ impl<'doc> SchemaVisitor<'doc> for CodeGenPass<'doc> {
 
         self.parse_directives(schema_def);
 
-        let query = match &schema_def.query {
-            Some(query) => ident(query),
-            None => {
-                self.emit_non_fatal_error(schema_def.position, ErrorKind::NoQueryType);
-                return;
-            }
-        };
-
-        let mutation = match &schema_def.mutation {
-            Some(mutation) => quote_ident(mutation),
-            None => {
-                let context_type = &self.context_type;
-                quote! { juniper::EmptyMutation<#context_type> }
-            }
-        };
-
-        self.extend(quote! {
-            /// The GraphQL schema type generated by `juniper-from-schema`.
-            pub type Schema = juniper::RootNode<'static, #query, #mutation>;
-        });
+        if schema_def.query.is_none() {
+            self.emit_non_fatal_error(schema_def.position, ErrorKind::NoQueryType);
+        }
     }
 
     fn visit_scalar_type(&mut self, scalar_type: &'doc ScalarType) {
+        if let Some(feature) = self.special_case_scalar_feature(&scalar_type.name) {
+            if !feature_enabled(feature) {
+                self.emit_non_fatal_error(
+                    scalar_type.position,
+                    ErrorKind::FeatureNotEnabled {
+                        usage: format!("`scalar {}`", scalar_type.name),
+                        feature,
+                    },
+                );
+            }
+        }
+
         match &*scalar_type.name {
             name if name == crate::DATE_TIME_SCALAR_NAME => {
                 // We don't need to parse and check the directives here because that is done by
@@ -79,12 +92,22 @@ impl<'doc> SchemaVisitor<'doc> for CodeGenPass<'doc> {
                         ErrorKind::SpecialCaseScalarWithDescription,
                     );
                 }
+
+                if self.ast_data.date_time_backend() == DateBackend::Time {
+                    let inner = match self.ast_data.date_time_scalar_definition() {
+                        Some(DateTimeScalarDefinition::WithoutTimeZone) => {
+                            TimeType::PrimitiveDateTime
+                        }
+                        _ => TimeType::OffsetDateTime,
+                    };
+                    self.gen_time_scalar_type(name, inner);
+                }
             }
             name if name == crate::DATE_SCALAR_NAME
                 || name == crate::URL_SCALAR_NAME
                 || name == crate::UUID_SCALAR_NAME =>
             {
-                self.parse_directives(scalar_type);
+                let args = self.parse_directives(scalar_type);
 
                 if scalar_type.description.is_some() {
                     self.emit_non_fatal_error(
@@ -92,24 +115,66 @@ impl<'doc> SchemaVisitor<'doc> for CodeGenPass<'doc> {
                         ErrorKind::SpecialCaseScalarWithDescription,
                     );
                 }
+
+                if args.repr.is_some() {
+                    self.emit_non_fatal_error(
+                        scalar_type.position,
+                        ErrorKind::SpecialCaseScalarWithRepr,
+                    );
+                }
+
+                if name == crate::DATE_SCALAR_NAME {
+                    if self.ast_data.date_backend() == DateBackend::Time {
+                        self.gen_time_scalar_type(name, TimeType::Date);
+                    }
+                } else if args.backend.is_some() {
+                    self.emit_non_fatal_error(
+                        scalar_type.position,
+                        ErrorKind::BackendOnNonDateScalar,
+                    );
+                }
             }
             name => {
-                self.parse_directives(scalar_type);
+                let args = self.parse_directives(scalar_type);
 
-                let name = ident(name);
+                if args.backend.is_some() {
+                    self.emit_non_fatal_error(
+                        scalar_type.position,
+                        ErrorKind::BackendOnNonDateScalar,
+                    );
+                }
+
+                let repr = args.repr.unwrap_or(ScalarRepr::String);
+
+                if let Some(feature) = repr.required_feature() {
+                    if !feature_enabled(feature) {
+                        self.emit_non_fatal_error(
+                            scalar_type.position,
+                            ErrorKind::FeatureNotEnabled {
+                                usage: format!("`@juniper(repr: \"{}\")`", repr.as_str()),
+                                feature,
+                            },
+                        );
+                    }
+                }
+
+                let graphql_name = name;
+                // Types are referenced with camel cased names so the definition has to match.
+                let name = ident(name.to_camel_case());
                 let description = &scalar_type
                     .description
                     .as_ref()
                     .map(|desc| quote! { description: #desc })
                     .unwrap_or(quote! {});
 
-                self.gen_scalar_type_with_data(&name, &description);
+                self.gen_scalar_type_with_data(&name, graphql_name, description, repr);
             }
         };
     }
 
     fn visit_object_type(&mut self, obj_type: &'doc ObjectType) {
-        self.parse_directives(obj_type);
+        // We don't need to parse and check the directives here because that is done by
+        // `AstData::visit_object_type`
 
         let struct_name = ident(&obj_type.name);
 
@@ -118,7 +183,7 @@ impl<'doc> SchemaVisitor<'doc> for CodeGenPass<'doc> {
         let field_tokens = obj_type
             .fields
             .iter()
-            .map(|field| self.collect_data_for_field_gen(field))
+            .map(|field| self.collect_data_for_field_gen(&obj_type.name, field))
             .collect::<Vec<_>>();
 
         let trait_methods = field_tokens
@@ -128,11 +193,12 @@ impl<'doc> SchemaVisitor<'doc> for CodeGenPass<'doc> {
                 let args = &field.trait_args;
                 let context_type = &self.context_type;
                 let return_type = self.field_return_type_tokens(&field);
+                let doc = trait_method_doc(&obj_type.name, field, &return_type);
 
                 match field.type_kind {
                     TypeKind::Scalar => {
                         quote! {
-                            /// Field method generated by `juniper-from-schema`.
+                            #doc
                             fn #field_name<'a>(
                                 &self,
                                 executor: &juniper::Executor<'a, #context_type>,
@@ -141,13 +207,13 @@ impl<'doc> SchemaVisitor<'doc> for CodeGenPass<'doc> {
                         }
                     }
                     TypeKind::Type => {
-                        let query_trail_type = ident(&field.inner_type);
+                        let query_trail_type = &field.query_trail_type;
                         let trail = quote! {
                             &QueryTrail<'a, #query_trail_type, juniper_from_schema::Walked>
                         };
 
                         quote! {
-                            /// Field method generated by `juniper-from-schema`.
+                            #doc
                             fn #field_name<'a>(
                                 &self,
                                 executor: &juniper::Executor<'a, #context_type>,
@@ -200,7 +266,8 @@ impl<'doc> SchemaVisitor<'doc> for CodeGenPass<'doc> {
     }
 
     fn visit_interface_type(&mut self, interface: &'doc InterfaceType) {
-        self.parse_directives(interface);
+        // We don't need to parse and check the directives here because that is done by
+        // `AstData::visit_interface_type`
 
         let interface_name = ident(&interface.name);
 
@@ -220,81 +287,27 @@ impl<'doc> SchemaVisitor<'doc> for CodeGenPass<'doc> {
 
         let implementors = implementors.iter().map(ident).collect::<Vec<_>>();
 
-        // Enum
-        let variants = implementors.iter().map(|name| {
-            quote! { #name(#name) }
-        });
-        self.extend(quote! {
-            pub enum #interface_name {
-                #(#variants),*
-            }
-        });
-
-        // From implementations
-        for variant in &implementors {
-            self.extend(quote! {
-                impl std::convert::From<#variant> for #interface_name {
-                    fn from(x: #variant) -> #interface_name {
-                        #interface_name::#variant(x)
-                    }
-                }
-            });
-        }
+        let payload = self.ast_data.interface_payload(&interface.name);
+        self.gen_variant_enum(&interface_name, &implementors, payload);
 
-        // Resolvers
-        let instance_resolvers = implementors.iter().map(|name| {
-            quote! {
-                &#name => match *self { #interface_name::#name(ref h) => Some(h), _ => None }
-            }
-        });
+        let instance_resolvers = gen_instance_resolvers(&interface_name, &implementors);
 
         let field_tokens: Vec<FieldTokens> = interface
             .fields
             .iter()
-            .map(|field| self.collect_data_for_field_gen(field))
+            .map(|field| self.collect_data_for_field_gen(&interface.name, field))
             .collect::<Vec<_>>();
 
         let field_token_streams = field_tokens
             .iter()
-            .map(|field| {
-                let field_name = &field.name;
-                let args = &field.macro_args;
-
-                let description = doc_tokens(&field.description);
-
-                let arms = implementors.iter().map(|variant| {
-                    let trait_name = trait_map_for_struct_name(&variant);
-                    let struct_name = variant;
-
-                    let body = gen_field_body(&field, &quote! {inner}, &struct_name, &trait_name);
-
-                    quote! {
-                        #interface_name::#struct_name(ref inner) => {
-                            #body
-                        }
-                    }
-                });
-
-                let all_args = to_field_args_list(&args);
-                let deprecation = &field.deprecation;
-                let return_type = self.field_return_type_tokens(&field);
-
-                quote! {
-                    #description
-                    #deprecation
-                    field #field_name(#all_args) -> #return_type {
-                        match *self {
-                            #(#arms),*
-                        }
-                    }
-                }
-            })
+            .map(|field| self.gen_interface_field(field, interface, &implementors))
             .collect::<Vec<_>>();
 
         let context_type = &self.context_type;
+        let header = macro_type_header(&interface_name, context_type, payload);
 
         let code = quote! {
-            juniper::graphql_interface!(#interface_name: #context_type |&self| {
+            juniper::graphql_interface!(#header |&self| {
                 description: #description
 
                 #(#field_token_streams)*
@@ -308,38 +321,30 @@ impl<'doc> SchemaVisitor<'doc> for CodeGenPass<'doc> {
     }
 
     fn visit_union_type(&mut self, union: &'doc UnionType) {
-        self.parse_directives(union);
+        // We don't need to parse and check the directives here because that is done by
+        // `AstData::visit_union_type`
+        let args = self
+            .ast_data
+            .union_arguments(&union.name)
+            .cloned()
+            .unwrap_or_default();
 
         let union_name = ident(&union.name);
         let implementors = union.types.iter().map(ident).collect::<Vec<_>>();
+        let payload = args.payload;
 
-        // Enum
-        let variants = implementors.iter().map(|name| {
-            quote! { #name(#name) }
-        });
-        self.extend(quote! {
-            pub enum #union_name {
-                #(#variants),*
+        if let Some(rust_type) = &args.rust_type.path {
+            // The rest of the generated code refers to the union by its name
+            if !rust_type.is_ident(&union.name) {
+                self.extend(quote! {
+                    pub type #union_name = #rust_type;
+                });
             }
-        });
-
-        // From implementations
-        for variant in &implementors {
-            self.extend(quote! {
-                impl std::convert::From<#variant> for #union_name {
-                    fn from(x: #variant) -> #union_name {
-                        #union_name::#variant(x)
-                    }
-                }
-            })
+        } else {
+            self.gen_variant_enum(&union_name, &implementors, payload);
         }
 
-        // Resolvers
-        let instance_resolvers = implementors.iter().map(|name| {
-            quote! {
-                &#name => match *self { #union_name::#name(ref h) => Some(h), _ => None }
-            }
-        });
+        let instance_resolvers = gen_instance_resolvers(&union_name, &implementors);
 
         let description = union
             .description
@@ -348,9 +353,10 @@ impl<'doc> SchemaVisitor<'doc> for CodeGenPass<'doc> {
             .unwrap_or_else(String::new);
 
         let context_type = &self.context_type;
+        let header = macro_type_header(&union_name, context_type, payload);
 
         let code = quote! {
-            juniper::graphql_union!(#union_name: #context_type |&self| {
+            juniper::graphql_union!(#header |&self| {
                 description: #description
 
                 instance_resolvers: |_| {
@@ -362,30 +368,28 @@ impl<'doc> SchemaVisitor<'doc> for CodeGenPass<'doc> {
     }
 
     fn visit_enum_type(&mut self, enum_type: &'doc EnumType) {
-        self.parse_directives(enum_type);
+        // We don't need to parse and check the directives here because that is done by
+        // `AstData::visit_enum_type`
+        let args = self
+            .ast_data
+            .enum_arguments(&enum_type.name)
+            .cloned()
+            .unwrap_or_default();
 
         let name = to_enum_name(&enum_type.name);
 
-        let values = enum_type
-            .values
-            .iter()
-            .map(|enum_value| self.gen_enum_value(enum_value));
-
-        let description = doc_tokens(&enum_type.description);
+        self.gen_enum(enum_type, &args);
+        if let Some(rust_type) = &args.rust_type.path {
+            self.gen_enum_conversions(enum_type, rust_type);
+        }
 
         let string_to_enum_value_mappings = enum_type.values.iter().map(|enum_value| {
             let graphql_name = &enum_value.name;
-            let variant = to_enum_name(&graphql_name);
+            let variant = self.enum_variant_name(&enum_type.name, graphql_name);
             quote! { &#graphql_name => #name::#variant, }
         });
 
         let code = quote! {
-            #description
-            #[derive(juniper::GraphQLEnum, Debug, Eq, PartialEq, Copy, Clone, Hash)]
-            pub enum #name {
-                #(#values)*
-            }
-
             impl<'a, 'b> query_trails::FromLookAheadValue<#name>
                 for &'a juniper::LookAheadValue<'b, juniper::DefaultScalarValue>
             {
@@ -417,7 +421,13 @@ impl<'doc> SchemaVisitor<'doc> for CodeGenPass<'doc> {
     }
 
     fn visit_input_object_type(&mut self, input_object: &'doc InputObjectType) {
-        self.parse_directives(input_object);
+        let input_object_args = self.parse_directives(input_object);
+        let args = self
+            .data_type_arguments
+            .extend(&input_object_args.data_type);
+        let derives = extra_derives(INPUT_OBJECT_DERIVES, &args.derive);
+        let attributes = &args.attributes.attrs;
+        let serde = serde_derive();
 
         let name = ident(&input_object.name);
 
@@ -433,14 +443,22 @@ impl<'doc> SchemaVisitor<'doc> for CodeGenPass<'doc> {
                 }
 
                 let arg = self.argument_to_name_and_rust_type(&field);
-                let name = ident(arg.name);
-                let rust_type = arg.macro_type;
+                let name = ident(&arg.name);
+                let rust_type = arg.input_object_field_type();
 
                 let description = doc_tokens(&field.description);
+                let serde_rename = serde_rename(&field.name);
+                let serde_tri_state = if arg.tri_state {
+                    serde_tri_state()
+                } else {
+                    quote! {}
+                };
 
                 quote! {
                     #[allow(missing_docs)]
                     #description
+                    #serde_rename
+                    #serde_tri_state
                     pub #name: #rust_type
                 }
             })
@@ -464,8 +482,14 @@ impl<'doc> SchemaVisitor<'doc> for CodeGenPass<'doc> {
                 let arg = self.argument_to_name_and_rust_type(&field);
                 let name = ident(&arg.name);
                 let temp_name = ident(&format!("{}_temp", arg.name));
-                quote! {
-                    #name: #temp_name.unwrap_or_else(|| panic!("Field `{}` was not set", stringify!(#name))),
+                if arg.tri_state {
+                    quote! {
+                        #name: #temp_name.unwrap_or(juniper_from_schema::Nullable::Absent),
+                    }
+                } else {
+                    quote! {
+                        #name: #temp_name.unwrap_or_else(|| panic!("Field `{}` was not set", stringify!(#name))),
+                    }
                 }
             })
             .collect::<Vec<_>>();
@@ -477,7 +501,7 @@ impl<'doc> SchemaVisitor<'doc> for CodeGenPass<'doc> {
                 let arg = self.argument_to_name_and_rust_type(&field);
                 let name = &arg.name;
                 let temp_name = ident(&format!("{}_temp", arg.name));
-                let rust_type = arg.macro_type;
+                let rust_type = arg.input_object_field_type();
                 quote! {
                     #name => {
                         #temp_name = Some(
@@ -490,13 +514,45 @@ impl<'doc> SchemaVisitor<'doc> for CodeGenPass<'doc> {
             })
             .collect::<Vec<_>>();
 
+        let builder = if input_object_args.builder.value {
+            self.gen_input_object_builder(input_object)
+        } else {
+            quote! {}
+        };
+
+        // Juniper's derive treats missing fields as `null` so we implement the traits ourselves
+        // if any field has to tell them apart.
+        let has_tri_state_fields = input_object
+            .fields
+            .iter()
+            .any(|field| self.argument_to_name_and_rust_type(field).tri_state);
+        let validate = if self.ast_data.input_object_is_validated(&input_object.name) {
+            self.gen_input_object_validate(input_object)
+        } else {
+            quote! {}
+        };
+
+        let (graphql_derive, graphql_impls) = if has_tri_state_fields {
+            (quote! {}, self.gen_input_object_impls(input_object))
+        } else {
+            (quote! { juniper::GraphQLInputObject, }, quote! {})
+        };
+
         let code = quote! {
-            #[derive(juniper::GraphQLInputObject, Debug, Clone)]
+            #[derive(#graphql_derive Debug, Clone #(, #derives)*)]
+            #serde
+            #(#attributes)*
             #description
             pub struct #name {
                 #(#fields),*
             }
 
+            #graphql_impls
+
+            #validate
+
+            #builder
+
             impl<'a, 'b> query_trails::FromLookAheadValue<#name>
                 for &'a juniper::LookAheadValue<'b, juniper::DefaultScalarValue>
             {
@@ -535,10 +591,6 @@ impl<'doc> SchemaVisitor<'doc> for CodeGenPass<'doc> {
         self.extend(code)
     }
 
-    fn visit_directive_definition(&mut self, inner: &'doc schema::DirectiveDefinition) {
-        self.emit_non_fatal_error(inner.position, ErrorKind::TypeExtensionNotSupported)
-    }
-
     fn visit_scalar_type_extension(&mut self, inner: &'doc schema::ScalarTypeExtension) {
         self.emit_non_fatal_error(inner.position, ErrorKind::TypeExtensionNotSupported)
     }
@@ -565,18 +617,28 @@ impl<'doc> SchemaVisitor<'doc> for CodeGenPass<'doc> {
 }
 
 impl<'doc> CodeGenPass<'doc> {
+    #[allow(clippy::too_many_arguments)]
     pub fn new(
         raw_schema: &'doc str,
         error_type: syn::Type,
         context_type: syn::Type,
+        middleware: Option<syn::Type>,
+        default_ownership: Ownership,
+        data_type_arguments: DataTypeArguments,
+        lint_levels: LintLevels,
         ast_data: AstData<'doc>,
     ) -> Self {
         CodeGenPass {
             tokens: quote! {},
             error_type,
             context_type,
+            middleware,
+            default_ownership,
+            data_type_arguments,
+            lint_levels,
             ast_data,
             errors: BTreeSet::new(),
+            warnings: BTreeSet::new(),
             raw_schema,
         }
     }
@@ -590,14 +652,80 @@ impl<'doc> CodeGenPass<'doc> {
 
         self.gen_query_trails(doc);
         self.visit_document(doc);
+        self.gen_schema_type();
+        self.gen_directive_metadata(doc);
+        self.gen_lint_warnings();
 
         self.check_for_errors()?;
         Ok(self.tokens)
     }
 
+    // There is no stable way for proc macros to emit warnings, so each warning uses a deprecated
+    // constant which makes rustc print the message instead.
+    fn gen_lint_warnings(&mut self) {
+        let warnings = std::mem::take(&mut self.warnings);
+
+        for (pos, lint) in warnings {
+            let name = ident(lint.name());
+            let note = format!(
+                "{}\n --> schema:{}:{}\n`{}` is set to warn. Change it with `lints: [{}: allow]`",
+                lint,
+                pos.line,
+                pos.column,
+                lint.name(),
+                lint.name(),
+            );
+
+            self.extend(quote! {
+                const _: () = {
+                    #[deprecated(note = #note)]
+                    #[allow(non_upper_case_globals)]
+                    const #name: () = ();
+                    #name
+                };
+            });
+        }
+    }
+
+    // Generated here rather than when visiting the schema definition since schemas without one
+    // use the default root type names.
+    fn gen_schema_type(&mut self) {
+        let root_types = self.ast_data.root_types();
+
+        let query = match root_types.query {
+            Some(query) => ident(query),
+            None => return,
+        };
+
+        let mutation = match root_types.mutation {
+            Some(mutation) => quote_ident(mutation),
+            None => {
+                let context_type = &self.context_type;
+                quote! { juniper::EmptyMutation<#context_type> }
+            }
+        };
+
+        self.extend(quote! {
+            /// The GraphQL schema type generated by `juniper-from-schema`.
+            pub type Schema = juniper::RootNode<'static, #query, #mutation>;
+        });
+    }
+
     fn validate_doc(&mut self, doc: &'doc Document) {
+        SchemaValidator::new(self, doc).visit_document(doc);
+
+        // The other validators assume every type they look up is defined
+        if !self.errors.is_empty() {
+            return;
+        }
+
         FieldNameCaseValidator::new(self).visit_document(doc);
         UuidNameCaseValidator::new(self).visit_document(doc);
+        DefaultValueValidator::new(self).visit_document(doc);
+
+        if self.errors.is_empty() {
+            SchemaLinter::new(self).lint_document(doc);
+        }
     }
 
     fn check_for_errors(&self) -> Result<(), BTreeSet<Error<'doc>>> {
@@ -613,16 +741,253 @@ impl<'doc> CodeGenPass<'doc> {
         Err(())
     }
 
-    fn gen_scalar_type_with_data(&mut self, name: &Ident, description: &TokenStream) {
+    // The feature that provides the Rust type of a special case scalar, which is also what enables
+    // Juniper's implementations for it.
+    fn special_case_scalar_feature(&self, name: &str) -> Option<&'static str> {
+        match name {
+            name if name == crate::DATE_SCALAR_NAME => {
+                Some(self.ast_data.date_backend().required_feature())
+            }
+            name if name == crate::DATE_TIME_SCALAR_NAME => {
+                Some(self.ast_data.date_time_backend().required_feature())
+            }
+            name if name == crate::UUID_SCALAR_NAME => Some("uuid"),
+            name if name == crate::URL_SCALAR_NAME => Some("url"),
+            _ => None,
+        }
+    }
+
+    fn gen_scalar_type_with_data(
+        &mut self,
+        name: &Ident,
+        graphql_name: &str,
+        description: &TokenStream,
+        repr: ScalarRepr,
+    ) {
+        let serde = scalar_serde_attributes();
+
+        if repr == ScalarRepr::String {
+            return self.gen_string_scalar_type(name, graphql_name, description, serde);
+        }
+
+        let (inner_type, derives) = match repr {
+            ScalarRepr::I64 | ScalarRepr::BigInt => (
+                quote! { i64 },
+                quote! { Debug, Eq, PartialEq, Ord, PartialOrd, Clone, Copy, Hash },
+            ),
+            ScalarRepr::F64 => (
+                quote! { f64 },
+                quote! { Debug, PartialEq, PartialOrd, Clone, Copy },
+            ),
+            ScalarRepr::Bool => (
+                quote! { bool },
+                quote! { Debug, Eq, PartialEq, Ord, PartialOrd, Clone, Copy, Hash },
+            ),
+            ScalarRepr::Json => (
+                quote! { juniper_from_schema::serde_json::Value },
+                quote! { Debug, Eq, PartialEq, Clone },
+            ),
+            ScalarRepr::Decimal => (
+                quote! { juniper_from_schema::rust_decimal::Decimal },
+                quote! { Debug, Eq, PartialEq, Ord, PartialOrd, Clone, Copy, Hash },
+            ),
+            ScalarRepr::Duration => (
+                quote! { std::time::Duration },
+                quote! { Debug, Eq, PartialEq, Ord, PartialOrd, Clone, Copy, Hash },
+            ),
+            ScalarRepr::NaiveTime => (
+                quote! { chrono::NaiveTime },
+                quote! { Debug, Eq, PartialEq, Ord, PartialOrd, Clone, Copy, Hash },
+            ),
+            ScalarRepr::String => unreachable!(),
+        };
+
+        let (resolve, from_input_value, from_str, from_look_ahead_value) = match repr {
+            ScalarRepr::I64 => (
+                quote! { juniper_from_schema::scalar_repr::i64_to_value(self.0) },
+                quote! { juniper_from_schema::scalar_repr::i64_from_input_value(v) },
+                quote! { juniper_from_schema::scalar_repr::i64_from_str(value) },
+                quote! { juniper_from_schema::scalar_repr::i64_from_look_ahead_value(self) },
+            ),
+            ScalarRepr::BigInt => (
+                quote! { juniper_from_schema::scalar_repr::bigint_to_value(self.0) },
+                quote! { juniper_from_schema::scalar_repr::bigint_from_input_value(v) },
+                quote! { juniper_from_schema::scalar_repr::bigint_from_str(value) },
+                quote! { juniper_from_schema::scalar_repr::bigint_from_look_ahead_value(self) },
+            ),
+            ScalarRepr::F64 => (
+                quote! { juniper::Value::scalar(self.0) },
+                quote! { juniper_from_schema::scalar_repr::f64_from_input_value(v) },
+                quote! { <f64 as juniper::ParseScalarValue>::from_str(value) },
+                quote! { query_trails::FromLookAheadValue::<f64>::from(self) },
+            ),
+            ScalarRepr::Bool => (
+                quote! { juniper::Value::scalar(self.0) },
+                quote! { juniper_from_schema::scalar_repr::bool_from_input_value(v) },
+                quote! { <bool as juniper::ParseScalarValue>::from_str(value) },
+                quote! { query_trails::FromLookAheadValue::<bool>::from(self) },
+            ),
+            ScalarRepr::Json => (
+                quote! { juniper_from_schema::scalar_repr::json_to_value(&self.0) },
+                quote! { juniper_from_schema::scalar_repr::json_from_input_value(v) },
+                quote! { juniper_from_schema::scalar_repr::json_from_str(value) },
+                quote! { juniper_from_schema::scalar_repr::json_from_look_ahead_value(self) },
+            ),
+            ScalarRepr::Decimal => (
+                quote! { juniper_from_schema::scalar_repr::decimal_to_value(&self.0) },
+                quote! { juniper_from_schema::scalar_repr::decimal_from_input_value(v) },
+                quote! { juniper_from_schema::scalar_repr::decimal_from_str(value) },
+                quote! { juniper_from_schema::scalar_repr::decimal_from_look_ahead_value(self) },
+            ),
+            ScalarRepr::Duration => (
+                quote! { juniper_from_schema::scalar_repr::duration_to_value(&self.0) },
+                quote! { juniper_from_schema::scalar_repr::duration_from_input_value(v) },
+                quote! { <String as juniper::ParseScalarValue>::from_str(value) },
+                quote! { juniper_from_schema::scalar_repr::duration_from_look_ahead_value(self) },
+            ),
+            ScalarRepr::NaiveTime => (
+                quote! { juniper_from_schema::scalar_repr::naive_time_to_value(&self.0) },
+                quote! { juniper_from_schema::scalar_repr::naive_time_from_input_value(v) },
+                quote! { <String as juniper::ParseScalarValue>::from_str(value) },
+                quote! { juniper_from_schema::scalar_repr::naive_time_from_look_ahead_value(self) },
+            ),
+            ScalarRepr::String => unreachable!(),
+        };
+
+        self.gen_newtype_scalar_type(
+            name,
+            graphql_name,
+            description,
+            serde,
+            NewtypeScalar {
+                inner_type,
+                derives,
+                resolve,
+                from_input_value,
+                from_str,
+                from_look_ahead_value,
+            },
+        );
+    }
+
+    // Used for the `Date` and `DateTimeUtc` scalars with `@juniper(backend: "time")`. Juniper
+    // doesn't support the time crate, so unlike with chrono they have to be wrapped in a newtype.
+    fn gen_time_scalar_type(&mut self, name: &str, inner: TimeType) {
+        let (inner_type, helper) = match inner {
+            TimeType::Date => (quote! { juniper_from_schema::time::Date }, "time_date"),
+            TimeType::OffsetDateTime => (
+                quote! { juniper_from_schema::time::OffsetDateTime },
+                "offset_date_time",
+            ),
+            TimeType::PrimitiveDateTime => (
+                quote! { juniper_from_schema::time::PrimitiveDateTime },
+                "primitive_date_time",
+            ),
+        };
+        let to_value = ident(format!("{}_to_value", helper));
+        let from_input_value = ident(format!("{}_from_input_value", helper));
+        let from_look_ahead_value = ident(format!("{}_from_look_ahead_value", helper));
+
+        self.gen_newtype_scalar_type(
+            &ident(name),
+            name,
+            &quote! {},
+            scalar_serde_attributes(),
+            NewtypeScalar {
+                inner_type,
+                derives: quote! { Debug, Eq, PartialEq, Ord, PartialOrd, Clone, Copy, Hash },
+                resolve: quote! { juniper_from_schema::scalar_repr::#to_value(&self.0) },
+                from_input_value: quote! {
+                    juniper_from_schema::scalar_repr::#from_input_value(v)
+                },
+                from_str: quote! { <String as juniper::ParseScalarValue>::from_str(value) },
+                from_look_ahead_value: quote! {
+                    juniper_from_schema::scalar_repr::#from_look_ahead_value(self)
+                },
+            },
+        );
+    }
+
+    fn gen_newtype_scalar_type(
+        &mut self,
+        name: &Ident,
+        graphql_name: &str,
+        description: &TokenStream,
+        serde: TokenStream,
+        scalar: NewtypeScalar,
+    ) {
+        let NewtypeScalar {
+            inner_type,
+            derives,
+            resolve,
+            from_input_value,
+            from_str,
+            from_look_ahead_value,
+        } = scalar;
+
+        self.extend(quote! {
+            /// Custom scalar type generated by `juniper-from-schema`.
+            #[derive(#derives)]
+            #serde
+            pub struct #name(pub #inner_type);
+
+            juniper::graphql_scalar!(#name as #graphql_name {
+                #description
+
+                resolve(&self) -> juniper::Value {
+                    #resolve
+                }
+
+                from_input_value(v: &InputValue) -> Option<#name> {
+                    #from_input_value.map(#name)
+                }
+
+                from_str<'a>(value: ScalarToken<'a>) -> juniper::ParseScalarResult<'a> {
+                    #from_str
+                }
+            });
+
+            impl std::ops::Deref for #name {
+                type Target = #inner_type;
+
+                fn deref(&self) -> &Self::Target {
+                    &self.0
+                }
+            }
+
+            impl std::ops::DerefMut for #name {
+                fn deref_mut(&mut self) -> &mut Self::Target {
+                    &mut self.0
+                }
+            }
+
+            impl<'a, 'b> query_trails::FromLookAheadValue<#name>
+                for &'a juniper::LookAheadValue<'b, juniper::DefaultScalarValue>
+            {
+                fn from(self) -> #name {
+                    #name(#from_look_ahead_value)
+                }
+            }
+        })
+    }
+
+    fn gen_string_scalar_type(
+        &mut self,
+        name: &Ident,
+        graphql_name: &str,
+        description: &TokenStream,
+        serde: TokenStream,
+    ) {
         // We explicity don't implement `From<T> where T: Into<String>` because then users wouldn't
         // be able to add their own `From` implementations, since `From<T>` overlaps with other
         // implementations.
         self.extend(quote! {
             /// Custom scalar type generated by `juniper-from-schema`.
             #[derive(Debug, Eq, PartialEq, Ord, PartialOrd, Clone, Hash)]
+            #serde
             pub struct #name(pub String);
 
-            juniper::graphql_scalar!(#name {
+            juniper::graphql_scalar!(#name as #graphql_name {
                 #description
 
                 resolve(&self) -> juniper::Value {
@@ -677,12 +1042,9 @@ impl<'doc> CodeGenPass<'doc> {
     }
 
     fn argument_to_name_and_rust_type(&mut self, arg: &'doc InputValue) -> FieldArgument<'doc> {
-        self.parse_directives(arg);
+        let tri_state_type = self.tri_state_type(arg);
 
-        let default_value_tokens = arg
-            .default_value
-            .as_ref()
-            .map(|value| self.quote_value(&value, type_name(&arg.value_type), arg.position));
+        let default_value_tokens = self.quote_default_value(arg);
 
         let arg_name = arg.name.to_snake_case();
 
@@ -702,13 +1064,53 @@ impl<'doc> CodeGenPass<'doc> {
 
         FieldArgument {
             name: arg_name,
+            graphql_name: &arg.name,
             macro_type,
-            trait_type,
+            tri_state: tri_state_type.is_some(),
+            trait_type: tri_state_type.unwrap_or(trait_type),
             default_value: default_value_tokens,
             description: &arg.description,
         }
     }
 
+    // The type `QueryTrail`s are parameterized over. Types with a lifetime use `'static` since the
+    // trail never holds any values of them.
+    pub(super) fn query_trail_type(&self, name: &str) -> TokenStream {
+        let type_name = ident(name);
+        if self.ast_data.type_has_lifetime(name) {
+            quote! { #type_name<'static> }
+        } else {
+            quote! { #type_name }
+        }
+    }
+
+    // `juniper_from_schema::Nullable<T>` if the argument or input field has
+    // `@juniper(tri_state: true)`.
+    pub(super) fn tri_state_type(&mut self, input_value: &'doc InputValue) -> Option<TokenStream> {
+        if !self.parse_directives(input_value).tri_state.value {
+            return None;
+        }
+
+        if let Type::NonNullType(_) = input_value.value_type {
+            self.emit_non_fatal_error(input_value.position, ErrorKind::TriStateOnNonNullType);
+            return None;
+        }
+
+        if input_value.default_value.is_some() {
+            self.emit_non_fatal_error(input_value.position, ErrorKind::TriStateWithDefaultValue);
+            return None;
+        }
+
+        let (inner_type, _) = self.gen_field_type(
+            &Type::NonNullType(Box::new(input_value.value_type.clone())),
+            &FieldTypeDestination::Argument,
+            false,
+            input_value.position,
+        );
+
+        Some(quote! { juniper_from_schema::Nullable<#inner_type> })
+    }
+
     fn gen_field_type(
         &mut self,
         field_type: &Type,
@@ -729,20 +1131,19 @@ impl<'doc> CodeGenPass<'doc> {
         };
 
         let as_ref = match destination {
-            FieldTypeDestination::Return(attrs) => match attrs.ownership {
-                Ownership::AsRef => true,
-                Ownership::Borrowed => false,
-                Ownership::Owned => false,
-            },
+            FieldTypeDestination::Return(ownership) => *ownership == Ownership::AsRef,
             FieldTypeDestination::Argument => false,
         };
 
         let (tokens, ty) = self.gen_nullable_field_type(field_type, as_ref, pos);
 
         match (destination, ty) {
-            (FieldTypeDestination::Return(attrs), ref ty) => match attrs.ownership {
+            (FieldTypeDestination::Return(ownership), ref ty) => match ownership {
                 Ownership::Owned | Ownership::AsRef => (tokens, *ty),
                 Ownership::Borrowed => (quote! { &#tokens }, *ty),
+                Ownership::Arc => (quote! { std::sync::Arc<#tokens> }, *ty),
+                Ownership::Boxed => (quote! { Box<#tokens> }, *ty),
+                Ownership::Cow => (quote! { std::borrow::Cow<'_, #tokens> }, *ty),
             },
 
             (FieldTypeDestination::Argument, ty @ TypeKind::Scalar) => (tokens, ty),
@@ -787,12 +1188,20 @@ impl<'doc> CodeGenPass<'doc> {
         }
     }
 
-    fn collect_data_for_field_gen(&mut self, field: &'doc Field) -> FieldTokens<'doc> {
+    fn collect_data_for_field_gen(
+        &mut self,
+        parent_type_name: &str,
+        field: &'doc Field,
+    ) -> FieldTokens<'doc> {
         let name = ident(&field.name);
 
         let inner_type = type_name(&field.field_type).to_camel_case();
 
         let attributes = self.parse_directives(field);
+        let ownership = attributes
+            .ownership
+            .or_else(|| self.ast_data.type_ownership(parent_type_name))
+            .unwrap_or(self.default_ownership);
         let deprecation = attributes
             .deprecated
             .as_ref()
@@ -801,11 +1210,24 @@ impl<'doc> CodeGenPass<'doc> {
 
         let (field_type, type_kind) = self.gen_field_type(
             &field.field_type,
-            &FieldTypeDestination::Return(&attributes),
+            &FieldTypeDestination::Return(ownership),
             false,
             field.position,
         );
 
+        // Juniper can't resolve `Cow`s so they're wrapped in a type it can resolve
+        let macro_field_type = if ownership == Ownership::Cow {
+            let (owned_type, _) = self.gen_field_type(
+                &field.field_type,
+                &FieldTypeDestination::Return(Ownership::Owned),
+                false,
+                field.position,
+            );
+            quote! { juniper_from_schema::CowValue<'_, #owned_type> }
+        } else {
+            field_type.clone()
+        };
+
         let field_method = ident(format!("field_{}", name.to_string().to_snake_case()));
 
         let args_data = field
@@ -840,7 +1262,12 @@ impl<'doc> CodeGenPass<'doc> {
             .iter()
             .map(|arg| {
                 let name = ident(&arg.name);
-                if let Some(default_value) = &arg.default_value {
+                let graphql_name = arg.graphql_name;
+                if arg.tri_state {
+                    quote! {
+                        juniper_from_schema::Nullable::from_argument(#name, &executor, #graphql_name)
+                    }
+                } else if let Some(default_value) = &arg.default_value {
                     quote! {
                         #name.unwrap_or_else(|| #default_value)
                     }
@@ -850,27 +1277,592 @@ impl<'doc> CodeGenPass<'doc> {
             })
             .collect::<Vec<_>>();
 
-        FieldTokens {
-            name,
-            macro_args,
+        let argument_validations = field
+            .arguments
+            .iter()
+            .filter_map(|arg| self.gen_argument_validation(arg))
+            .collect::<Vec<_>>();
+        let validation = if argument_validations.is_empty() {
+            None
+        } else {
+            Some(quote! {
+                let mut validator = juniper_from_schema::Validator::new();
+                {
+                    let validator = &mut validator;
+                    #(#argument_validations)*
+                }
+                validator.finish()?;
+            })
+        };
+
+        FieldTokens {
+            name,
+            macro_args,
             trait_args,
             field_type,
+            macro_field_type,
             field_method,
             params,
             description: &field.description,
             type_kind,
-            inner_type,
+            query_trail_type: self.query_trail_type(&inner_type),
             deprecation,
             infallible: attributes.infallible.value,
+            guard: attributes.guard.expr,
+            validation,
+            ownership,
+            argument_names: field.arguments.iter().map(|arg| arg.name.clone()).collect(),
+        }
+    }
+
+    // Generates the enum behind an interface or union along with `From` implementations for
+    // each variant.
+    fn gen_variant_enum(&mut self, name: &Ident, implementors: &[Ident], payload: Payload) {
+        if payload == Payload::Cow {
+            let variants = implementors.iter().map(|variant| {
+                quote! { #variant(std::borrow::Cow<'a, #variant>) }
+            });
+            self.extend(quote! {
+                pub enum #name<'a> {
+                    #(#variants),*
+                }
+            });
+
+            for variant in implementors {
+                self.extend(quote! {
+                    impl<'a> std::convert::From<#variant> for #name<'a> {
+                        fn from(x: #variant) -> #name<'a> {
+                            #name::#variant(std::borrow::Cow::Owned(x))
+                        }
+                    }
+
+                    impl<'a> std::convert::From<&'a #variant> for #name<'a> {
+                        fn from(x: &'a #variant) -> #name<'a> {
+                            #name::#variant(std::borrow::Cow::Borrowed(x))
+                        }
+                    }
+                });
+            }
+
+            return;
+        }
+
+        let wrapper = match payload {
+            Payload::Owned | Payload::Cow => None,
+            Payload::Boxed => Some(quote! { std::boxed::Box }),
+            Payload::Arc => Some(quote! { std::sync::Arc }),
+            Payload::Rc => Some(quote! { std::rc::Rc }),
+        };
+
+        let variants = implementors.iter().map(|variant| match &wrapper {
+            Some(wrapper) => quote! { #variant(#wrapper<#variant>) },
+            None => quote! { #variant(#variant) },
+        });
+        self.extend(quote! {
+            pub enum #name {
+                #(#variants),*
+            }
+        });
+
+        for variant in implementors {
+            if let Some(wrapper) = &wrapper {
+                self.extend(quote! {
+                    impl std::convert::From<#variant> for #name {
+                        fn from(x: #variant) -> #name {
+                            #name::#variant(#wrapper::new(x))
+                        }
+                    }
+
+                    impl std::convert::From<#wrapper<#variant>> for #name {
+                        fn from(x: #wrapper<#variant>) -> #name {
+                            #name::#variant(x)
+                        }
+                    }
+                })
+            } else {
+                self.extend(quote! {
+                    impl std::convert::From<#variant> for #name {
+                        fn from(x: #variant) -> #name {
+                            #name::#variant(x)
+                        }
+                    }
+                })
+            }
+        }
+    }
+
+    fn gen_enum(&mut self, enum_type: &'doc EnumType, args: &EnumArguments) {
+        let args = self.data_type_arguments.extend(&args.data_type);
+        let derives = extra_derives(ENUM_DERIVES, &args.derive);
+        let attributes = &args.attributes.attrs;
+        let serde = serde_derive();
+
+        let name = to_enum_name(&enum_type.name);
+
+        let values = enum_type
+            .values
+            .iter()
+            .map(|enum_value| self.gen_enum_value(&enum_type.name, enum_value))
+            .collect::<Vec<_>>();
+
+        let description = doc_tokens(&enum_type.description);
+
+        self.extend(quote! {
+            #description
+            #[derive(juniper::GraphQLEnum, Debug, Eq, PartialEq, Copy, Clone, Hash #(, #derives)*)]
+            #serde
+            #(#attributes)*
+            pub enum #name {
+                #(#values)*
+            }
+        });
+    }
+
+    // Conversions between the generated enum and an existing one given with
+    // `@juniper(rust_type: "...")`. Juniper's traits are implemented for the generated enum so the
+    // existing one can live in any crate. The matches are exhaustive so if the variants of the
+    // Rust enum don't match the values in the schema it won't compile.
+    fn gen_enum_conversions(&mut self, enum_type: &'doc EnumType, rust_type: &syn::Path) {
+        let name = to_enum_name(&enum_type.name);
+
+        let variants = enum_type
+            .values
+            .iter()
+            .map(|enum_value| self.enum_variant_name(&enum_type.name, &enum_value.name))
+            .collect::<Vec<_>>();
+
+        self.extend(quote! {
+            impl std::convert::From<#name> for #rust_type {
+                fn from(value: #name) -> #rust_type {
+                    match value {
+                        #( #name::#variants => #rust_type::#variants, )*
+                    }
+                }
+            }
+
+            impl std::convert::From<#rust_type> for #name {
+                fn from(value: #rust_type) -> #name {
+                    match value {
+                        #( #rust_type::#variants => #name::#variants, )*
+                    }
+                }
+            }
+        });
+    }
+
+    // A `new` constructor taking the non-null fields and `with_*` setters for the nullable ones.
+    fn gen_input_object_builder(&mut self, input_object: &'doc InputObjectType) -> TokenStream {
+        let name = ident(&input_object.name);
+
+        let mut params = Vec::new();
+        let mut initializers = Vec::new();
+        let mut setters = Vec::new();
+
+        for field in &input_object.fields {
+            let nullable = self
+                .ast_data
+                .input_object_field_is_nullable(&input_object.name, &field.name)
+                .unwrap_or(true);
+            let field_name = ident(field.name.to_snake_case());
+
+            let tri_state = self.parse_directives(field).tri_state.value;
+
+            if nullable {
+                let (inner_type, _) = self.gen_field_type(
+                    &Type::NonNullType(Box::new(field.value_type.clone())),
+                    &FieldTypeDestination::Argument,
+                    false,
+                    field.position,
+                );
+                let setter_name = ident(format!("with_{}", field_name));
+                let doc = format!("Set `{}`.", field.name);
+
+                let (empty, set) = if tri_state {
+                    (
+                        quote! { juniper_from_schema::Nullable::Absent },
+                        quote! { juniper_from_schema::Nullable::Value(#field_name) },
+                    )
+                } else {
+                    (quote! { None }, quote! { Some(#field_name) })
+                };
+
+                initializers.push(quote! { #field_name: #empty });
+                setters.push(quote! {
+                    #[doc = #doc]
+                    pub fn #setter_name(mut self, #field_name: #inner_type) -> Self {
+                        self.#field_name = #set;
+                        self
+                    }
+                });
+            } else {
+                let (field_type, _) = self.gen_field_type(
+                    &field.value_type,
+                    &FieldTypeDestination::Argument,
+                    false,
+                    field.position,
+                );
+
+                initializers.push(quote! { #field_name });
+                params.push(quote! { #field_name: #field_type });
+            }
+        }
+
+        let new_doc = format!(
+            "Create a new `{}` from its non-null fields. Nullable fields are left empty.",
+            input_object.name
+        );
+
+        quote! {
+            impl #name {
+                #[doc = #new_doc]
+                #[allow(clippy::too_many_arguments)]
+                pub fn new(#(#params),*) -> Self {
+                    #name {
+                        #(#initializers),*
+                    }
+                }
+
+                #(#setters)*
+            }
+        }
+    }
+
+    // Implement the traits `#[derive(juniper::GraphQLInputObject)]` would have, except that fields
+    // missing from the input become `Nullable::Absent` for tri-state fields.
+    fn gen_input_object_impls(&mut self, input_object: &'doc InputObjectType) -> TokenStream {
+        let name = ident(&input_object.name);
+        let graphql_name = &input_object.name;
+
+        let meta_description = match &input_object.description {
+            Some(description) => quote! { let meta = meta.description(#description); },
+            None => quote! {},
+        };
+
+        let mut meta_fields = Vec::new();
+        let mut from_inputs = Vec::new();
+        let mut to_inputs = Vec::new();
+
+        for field in &input_object.fields {
+            let arg = self.argument_to_name_and_rust_type(field);
+            let field_name = ident(&arg.name);
+            let field_graphql_name = arg.graphql_name;
+            let field_type = arg.input_object_field_type();
+
+            let field_description = match &field.description {
+                Some(description) => quote! { let field = field.description(#description); },
+                None => quote! {},
+            };
+
+            let missing = if arg.tri_state {
+                quote! { juniper_from_schema::Nullable::Absent }
+            } else {
+                quote! {
+                    juniper::FromInputValue::from_input_value(&juniper::InputValue::null())?
+                }
+            };
+
+            meta_fields.push(quote! {
+                {
+                    let field = registry.arg::<#field_type>(#field_graphql_name, &());
+                    #field_description
+                    field
+                },
+            });
+            from_inputs.push(quote! {
+                #field_name: match obj.get(#field_graphql_name) {
+                    Some(value) => juniper::FromInputValue::from_input_value(value)?,
+                    None => #missing,
+                },
+            });
+            to_inputs.push(quote! {
+                (#field_graphql_name, self.#field_name.to_input_value()),
+            });
+        }
+
+        quote! {
+            impl<__S> juniper::GraphQLType<__S> for #name
+            where
+                __S: juniper::ScalarValue,
+                for<'__b> &'__b __S: juniper::ScalarRefValue<'__b>,
+            {
+                type Context = ();
+                type TypeInfo = ();
+
+                fn name(_: &()) -> Option<&'static str> {
+                    Some(#graphql_name)
+                }
+
+                fn meta<'r>(
+                    _: &(),
+                    registry: &mut juniper::Registry<'r, __S>,
+                ) -> juniper::meta::MetaType<'r, __S>
+                where
+                    __S: 'r,
+                {
+                    let fields = &[
+                        #(#meta_fields)*
+                    ];
+                    let meta = registry.build_input_object_type::<#name>(&(), fields);
+                    #meta_description
+                    meta.into_meta()
+                }
+            }
+
+            impl<__S: juniper::ScalarValue> juniper::FromInputValue<__S> for #name {
+                fn from_input_value(value: &juniper::InputValue<__S>) -> Option<#name>
+                where
+                    for<'__b> &'__b __S: juniper::ScalarRefValue<'__b>,
+                {
+                    let obj = value.to_object_value()?;
+                    Some(#name {
+                        #(#from_inputs)*
+                    })
+                }
+            }
+
+            impl<__S> juniper::ToInputValue<__S> for #name
+            where
+                __S: juniper::ScalarValue,
+                for<'__b> &'__b __S: juniper::ScalarRefValue<'__b>,
+            {
+                fn to_input_value(&self) -> juniper::InputValue<__S> {
+                    juniper::InputValue::object(vec![
+                        #(#to_inputs)*
+                    ].into_iter().collect())
+                }
+            }
+        }
+    }
+
+    fn gen_input_object_validate(&mut self, input_object: &'doc InputObjectType) -> TokenStream {
+        let name = ident(&input_object.name);
+
+        let fields = input_object
+            .fields
+            .iter()
+            .filter_map(|field| {
+                let arg = self.argument_to_name_and_rust_type(field);
+                let field_name = ident(&arg.name);
+                let graphql_name = arg.graphql_name;
+                let validations = self.parse_directives(field).validations;
+
+                let validation = self.gen_validation(
+                    quote! { &self.#field_name },
+                    quote! { &path },
+                    &field.value_type,
+                    arg.tri_state,
+                    &validations,
+                    field.position,
+                )?;
+
+                Some(quote! {
+                    {
+                        let path = format!("{}.{}", path, #graphql_name);
+                        #validation
+                    }
+                })
+            })
+            .collect::<Vec<_>>();
+
+        quote! {
+            impl juniper_from_schema::Validate for #name {
+                fn validate(&self, path: &str, validator: &mut juniper_from_schema::Validator) {
+                    #(#fields)*
+                }
+            }
         }
     }
 
-    fn gen_enum_value(&mut self, enum_value: &'doc EnumValue) -> TokenStream {
+    // Arguments with default values are `Option`s inside `graphql_object!` even if they're
+    // non-null, so they're validated as if they were nullable.
+    fn gen_argument_validation(&mut self, arg: &'doc InputValue) -> Option<TokenStream> {
+        let validations = self.parse_directives(arg).validations;
+        let name = ident(arg.name.to_snake_case());
+        let graphql_name = &arg.name;
+
+        let value_type = match &arg.value_type {
+            Type::NonNullType(inner) if arg.default_value.is_some() => &**inner,
+            other => other,
+        };
+
+        self.gen_validation(
+            quote! { &#name },
+            quote! { #graphql_name },
+            value_type,
+            false,
+            &validations,
+            arg.position,
+        )
+    }
+
+    // Generate code that checks `validations` against the value `value` points to and reports
+    // violations to a `validator` in scope. Returns `None` if there is nothing to check.
+    fn gen_validation(
+        &mut self,
+        value: TokenStream,
+        path: TokenStream,
+        type_: &'doc Type,
+        tri_state: bool,
+        validations: &Validations,
+        pos: Pos,
+    ) -> Option<TokenStream> {
+        match type_ {
+            Type::NonNullType(inner) => {
+                self.gen_non_null_validation(value, path, inner, validations, pos)
+            }
+            nullable => {
+                let inner = self.gen_non_null_validation(
+                    quote! { value },
+                    path,
+                    nullable,
+                    validations,
+                    pos,
+                )?;
+
+                let pattern = if tri_state {
+                    quote! { juniper_from_schema::Nullable::Value(value) }
+                } else {
+                    quote! { Some(value) }
+                };
+
+                Some(quote! {
+                    if let #pattern = #value {
+                        #inner
+                    }
+                })
+            }
+        }
+    }
+
+    fn gen_non_null_validation(
+        &mut self,
+        value: TokenStream,
+        path: TokenStream,
+        type_: &'doc Type,
+        validations: &Validations,
+        pos: Pos,
+    ) -> Option<TokenStream> {
+        match type_ {
+            Type::NonNullType(inner) => {
+                self.gen_non_null_validation(value, path, inner, validations, pos)
+            }
+            Type::ListType(item_type) => {
+                let inner = self.gen_validation(
+                    quote! { value },
+                    quote! { &path },
+                    item_type,
+                    false,
+                    validations,
+                    pos,
+                )?;
+
+                Some(quote! {
+                    for (index, value) in (#value).iter().enumerate() {
+                        let path = format!("{}[{}]", #path, index);
+                        #inner
+                    }
+                })
+            }
+            Type::NamedType(name) => {
+                self.gen_named_type_validation(value, path, name, validations, pos)
+            }
+        }
+    }
+
+    fn gen_named_type_validation(
+        &mut self,
+        value: TokenStream,
+        path: TokenStream,
+        type_name: &'doc str,
+        validations: &Validations,
+        pos: Pos,
+    ) -> Option<TokenStream> {
+        let number = match type_name {
+            "Int" => Some(quote! { f64::from(*(#value)) }),
+            "Float" => Some(quote! { *(#value) }),
+            _ => None,
+        };
+        let string = match type_name {
+            "String" | "ID" => Some(quote! { &(#value)[..] }),
+            _ => None,
+        };
+
+        let mut checks = Vec::new();
+
+        if let Some(min) = validations.min.value {
+            match &number {
+                Some(number) => checks.push(quote! { validator.min(#path, #number, #min); }),
+                None => self.emit_unsupported_validation("min", type_name, pos),
+            }
+        }
+
+        if let Some(max) = validations.max.value {
+            match &number {
+                Some(number) => checks.push(quote! { validator.max(#path, #number, #max); }),
+                None => self.emit_unsupported_validation("max", type_name, pos),
+            }
+        }
+
+        if let Some(max_length) = validations.max_length.value {
+            match &string {
+                Some(string) => {
+                    checks.push(quote! { validator.max_length(#path, #string, #max_length); })
+                }
+                None => self.emit_unsupported_validation("max_length", type_name, pos),
+            }
+        }
+
+        if let Some(pattern) = &validations.pattern.value {
+            match &string {
+                Some(string) => {
+                    checks.push(quote! { validator.pattern(#path, #string, #pattern); })
+                }
+                None => self.emit_unsupported_validation("pattern", type_name, pos),
+            }
+        }
+
+        if self.ast_data.input_object_is_validated(type_name) {
+            checks.push(quote! {
+                juniper_from_schema::Validate::validate(#value, #path, validator);
+            });
+        }
+
+        if checks.is_empty() {
+            None
+        } else {
+            Some(quote! { #(#checks)* })
+        }
+    }
+
+    fn emit_unsupported_validation(
+        &mut self,
+        constraint: &'static str,
+        type_name: &'doc str,
+        pos: Pos,
+    ) {
+        self.emit_non_fatal_error(
+            pos,
+            ErrorKind::UnsupportedValidation {
+                constraint,
+                type_name,
+            },
+        );
+    }
+
+    // Values might be renamed with `@juniper(rust_name: "...")` so always go through `AstData`.
+    fn enum_variant_name(&self, enum_name: &str, value_name: &str) -> Ident {
+        match self.ast_data.enum_variant_name(enum_name, value_name) {
+            Some(name) => ident(name),
+            None => to_enum_name(value_name),
+        }
+    }
+
+    fn gen_enum_value(&mut self, enum_name: &str, enum_value: &'doc EnumValue) -> TokenStream {
         let graphql_name = &enum_value.name;
-        let name = to_enum_name(&graphql_name);
+        let name = self.enum_variant_name(enum_name, graphql_name);
         let description = doc_tokens(&enum_value.description);
 
-        let graphql_attr = match self.parse_directives(enum_value) {
+        let graphql_attr = match self.parse_directives(enum_value).deprecated {
             Deprecation::NoDeprecation => {
                 quote! { #[graphql(name=#graphql_name)] }
             }
@@ -882,52 +1874,99 @@ impl<'doc> CodeGenPass<'doc> {
             }
         };
 
+        let serde_rename = serde_rename(graphql_name);
+
         quote! {
             #[allow(missing_docs)]
             #graphql_attr
+            #serde_rename
             #description
             #name,
         }
     }
 
-    fn quote_value(&mut self, value: &Value, type_name: &str, pos: Pos) -> TokenStream {
-        match value {
-            Value::Float(inner) => quote! { #inner },
-            Value::Int(inner) => {
-                let number = inner
-                    .as_i64()
-                    .expect("failed to convert default number argument to i64");
-                let number =
-                    i32_from_i64(number).expect("failed to convert default number argument to i64");
-                quote! { #number }
+    // The default value of an argument, as the type the field method receives. The value has
+    // been checked by `DefaultValueValidator`.
+    pub(super) fn quote_default_value(&mut self, arg: &'doc InputValue) -> Option<TokenStream> {
+        let value = arg.default_value.as_ref()?;
+        let value_type =
+            NullableType::from_schema_type(&arg.value_type).remove_one_layer_of_nullability();
+        Some(self.quote_value(value, &value_type, arg.position))
+    }
+
+    fn quote_value(&mut self, value: &Value, type_: &NullableType, pos: Pos) -> TokenStream {
+        match (type_, value) {
+            (_, Value::Variable(_)) => {
+                self.emit_non_fatal_error(pos, ErrorKind::VariableDefaultValue);
+                quote! {}
             }
-            Value::String(inner) => quote! { #inner.to_string() },
-            Value::Boolean(inner) => quote! { #inner },
 
-            Value::Enum(variant_name) => {
-                let type_name = to_enum_name(type_name);
-                let variant_name = to_enum_name(variant_name);
-                quote! { #type_name::#variant_name }
+            (NullableType::NullableType(_), Value::Null) => quote! { None },
+            (NullableType::NullableType(inner), value) => {
+                let value_quoted = self.quote_value(value, inner, pos);
+                quote! { Some(#value_quoted) }
+            }
+
+            (NullableType::ListType(inner), Value::List(list)) => {
+                let values = list
+                    .iter()
+                    .map(|value| self.quote_value(value, inner, pos))
+                    .collect::<Vec<_>>();
+                quote! { vec![#(#values),*] }
+            }
+            // A single value is coerced into a list with one item
+            (NullableType::ListType(inner), value) => {
+                let value_quoted = self.quote_value(value, inner, pos);
+                quote! { vec![#value_quoted] }
+            }
+
+            (NullableType::NamedType(type_name), value) => {
+                self.quote_named_value(value, type_name, pos)
+            }
+        }
+    }
+
+    fn quote_named_value(&mut self, value: &Value, type_name: &str, pos: Pos) -> TokenStream {
+        match (type_name, value) {
+            ("Float", Value::Int(inner)) => {
+                let number = inner.as_i64().unwrap_or_default() as f64;
+                quote! { #number }
+            }
+            ("ID", Value::String(inner)) => quote! { juniper::ID::new(#inner) },
+            ("ID", Value::Int(inner)) => {
+                let id = inner.as_i64().unwrap_or_default().to_string();
+                quote! { juniper::ID::new(#id) }
             }
 
-            Value::List(list) => {
-                let mut acc = quote! { let mut vec = Vec::new(); };
-                for value in list {
-                    let value_quoted = self.quote_value(value, type_name, pos);
-                    acc.extend(quote! { vec.push(#value_quoted); });
+            (name, value) if self.ast_data.is_scalar(name) => {
+                let (rust_type, _) = self.graphql_scalar_type_to_rust_type(name, pos);
+                let input_value = quote_scalar_input_value(value);
+                let msg = format!("Invalid default value for the `{}` scalar", name);
+                quote! {
+                    <#rust_type as juniper::FromInputValue>::from_input_value(&#input_value)
+                        .expect(#msg)
                 }
-                acc.extend(quote! { vec });
-                quote! { { #acc } }
             }
 
-            Value::Object(map) => self.quote_object_value(map, type_name, pos),
+            (_, Value::Float(inner)) => quote! { #inner },
+            (_, Value::Int(inner)) => {
+                let number = i32::try_from(inner.as_i64().unwrap_or_default()).unwrap_or_default();
+                quote! { #number }
+            }
+            (_, Value::String(inner)) => quote! { #inner.to_string() },
+            (_, Value::Boolean(inner)) => quote! { #inner },
 
-            Value::Variable(_) => {
-                self.emit_non_fatal_error(pos, ErrorKind::VariableDefaultValue);
-                quote! {}
+            (_, Value::Enum(variant_name)) => {
+                let variant_name = self.enum_variant_name(type_name, variant_name);
+                let type_name = to_enum_name(type_name);
+                quote! { #type_name::#variant_name }
             }
 
-            Value::Null => quote! { None },
+            (_, Value::Object(map)) => self.quote_object_value(map, type_name, pos),
+
+            (_, Value::List(_)) | (_, Value::Null) | (_, Value::Variable(_)) => {
+                unreachable!("invalid default values are reported by `DefaultValueValidator`")
+            }
         }
     }
 
@@ -939,58 +1978,53 @@ impl<'doc> CodeGenPass<'doc> {
     ) -> TokenStream {
         let name = ident(&type_name);
 
-        let mut fields_seen = HashSet::new();
-
-        // Set fields given in `map`
-        let mut field_assigments = map
-            .iter()
-            .map(|(key, value)| {
-                fields_seen.insert(key);
-                let field_name = ident(key.to_snake_case());
-
-                let field_type_name = self
-                    .ast_data
-                    .input_object_field_type_name(&type_name, &key)
-                    .unwrap_or_else(|| {
-                        panic!("input_object_field_type_name {} {}", type_name, key)
-                    });
+        let fields = self
+            .ast_data
+            .input_object_fields(type_name)
+            .map(|fields| {
+                let mut fields = fields
+                    .iter()
+                    .map(|(name, type_)| (*name, *type_))
+                    .collect::<Vec<_>>();
+                fields.sort_by_key(|(name, _)| *name);
+                fields
+            })
+            .unwrap_or_default();
 
-                let value_quote = self.quote_value(value, field_type_name, pos);
-                match self
+        let field_assigments = fields
+            .into_iter()
+            .map(|(field_name, field_type)| {
+                let tri_state = self
                     .ast_data
-                    .input_object_field_is_nullable(&type_name, &key)
-                {
-                    Some(true) | None => {
-                        if value == &Value::Null {
-                            quote! { #field_name: #value_quote }
-                        } else {
-                            quote! { #field_name: Some(#value_quote) }
-                        }
+                    .input_object_field_is_tri_state(type_name, field_name);
+                let value = map.get(field_name);
+                let field_name = ident(field_name.to_snake_case());
+
+                let value_quote = match (tri_state, value) {
+                    (true, None) => quote! { juniper_from_schema::Nullable::Absent },
+                    (true, Some(Value::Null)) => quote! { juniper_from_schema::Nullable::Null },
+                    (true, Some(value)) => {
+                        let value_type = NullableType::from_schema_type(field_type)
+                            .remove_one_layer_of_nullability();
+                        let value_quote = self.quote_value(value, &value_type, pos);
+                        quote! { juniper_from_schema::Nullable::Value(#value_quote) }
                     }
-                    Some(false) => quote! { #field_name: #value_quote },
-                }
+                    (false, None) => quote! { None },
+                    (false, Some(value)) => {
+                        let value_type = NullableType::from_schema_type(field_type);
+                        self.quote_value(value, &value_type, pos)
+                    }
+                };
+
+                quote! { #field_name: #value_quote }
             })
             .collect::<Vec<_>>();
 
-        // Set fields not given in map to `None`
-        if let Some(fields) = self.ast_data.input_object_field_names(&type_name) {
-            for field_name in fields {
-                if !fields_seen.contains(field_name) {
-                    let field_name = ident(field_name.to_snake_case());
-                    field_assigments.push(quote! {
-                        #field_name: None
-                    });
-                }
-            }
-        }
-
-        let tokens = quote! {
+        quote! {
             #name {
-                #(#field_assigments),*,
+                #(#field_assigments),*
             }
-        };
-
-        quote! { { #tokens } }
+        }
     }
 
     // Type according to https://graphql.org/learn/schema/#scalar-types
@@ -1010,7 +2044,19 @@ impl<'doc> CodeGenPass<'doc> {
                     self.emit_fatal_error(pos, ErrorKind::DateScalarNotDefined)
                         .ok();
                 }
-                (quote! { chrono::naive::NaiveDate }, TypeKind::Scalar)
+                match self.ast_data.date_backend() {
+                    DateBackend::Chrono => (quote! { chrono::naive::NaiveDate }, TypeKind::Scalar),
+                    DateBackend::Time => (quote_ident(name), TypeKind::Scalar),
+                }
+            }
+            name if name == crate::DATE_TIME_SCALAR_NAME
+                && self.ast_data.date_time_backend() == DateBackend::Time =>
+            {
+                if !self.ast_data.date_time_scalar_defined() {
+                    self.emit_fatal_error(pos, ErrorKind::DateTimeScalarNotDefined)
+                        .ok();
+                }
+                (quote_ident(name), TypeKind::Scalar)
             }
             name if name == crate::DATE_TIME_SCALAR_NAME => {
                 let tokens = match self.ast_data.date_time_scalar_definition() {
@@ -1054,8 +2100,10 @@ impl<'doc> CodeGenPass<'doc> {
     }
 
     fn field_return_type_tokens(&self, field: &FieldTokens) -> TokenStream {
-        let field_type = &field.field_type;
+        self.return_type_tokens(field, &field.field_type)
+    }
 
+    fn return_type_tokens(&self, field: &FieldTokens, field_type: &TokenStream) -> TokenStream {
         if field.infallible {
             quote! { #field_type }
         } else {
@@ -1064,6 +2112,116 @@ impl<'doc> CodeGenPass<'doc> {
         }
     }
 
+    // Guards from the object and its interfaces followed by the guard on the field itself.
+    fn field_guards<'a>(&'a self, object_name: &str, field: &'a FieldTokens) -> Vec<&'a syn::Expr> {
+        let mut guards = self.ast_data.object_guards(object_name);
+        guards.extend(&field.guard);
+        guards
+    }
+
+    // The type generated inside `graphql_object!`. Guarded and validated fields always return
+    // `juniper::FieldResult` since a denied guard or invalid argument must be able to return an
+    // error, even for infallible fields or fields with a custom error type. So do all fields when
+    // there is middleware since it's given the result of every field.
+    fn macro_return_type_tokens(&self, field: &FieldTokens, checked: bool) -> TokenStream {
+        let field_type = &field.macro_field_type;
+        if checked {
+            quote! { juniper::FieldResult<#field_type> }
+        } else {
+            self.return_type_tokens(field, field_type)
+        }
+    }
+
+    fn wrap_in_middleware(
+        &self,
+        struct_name: &Ident,
+        field: &FieldTokens,
+        body: TokenStream,
+    ) -> TokenStream {
+        let middleware = if let Some(middleware) = &self.middleware {
+            middleware
+        } else {
+            return body;
+        };
+
+        let context_type = &self.context_type;
+        let type_name = struct_name.to_string();
+        let field_name = field.name.to_string();
+
+        quote! {
+            <#middleware as juniper_from_schema::FieldMiddleware<#context_type>>::around(
+                #type_name,
+                #field_name,
+                &executor,
+                || { #body },
+            )
+        }
+    }
+
+    fn gen_interface_field(
+        &self,
+        field: &FieldTokens,
+        interface: &InterfaceType,
+        implementors: &[Ident],
+    ) -> TokenStream {
+        let interface_name = ident(&interface.name);
+        let field_name = &field.name;
+        let args = &field.macro_args;
+
+        let description = doc_tokens(field.description);
+
+        let guards_per_implementor = implementors
+            .iter()
+            .map(|variant| self.field_guards(&variant.to_string(), field))
+            .collect::<Vec<_>>();
+
+        let checked = guards_per_implementor
+            .iter()
+            .any(|guards| !guards.is_empty())
+            || field.validation.is_some()
+            || self.middleware.is_some();
+
+        let arms = implementors
+            .iter()
+            .zip(&guards_per_implementor)
+            .map(|(variant, guards)| {
+                let trait_name = trait_map_for_struct_name(variant);
+                let struct_name = variant;
+
+                let body = gen_field_body(field, &quote! {inner}, struct_name, &trait_name);
+
+                let body = if checked {
+                    gen_checked_field_body(field, guards, body)
+                } else {
+                    body
+                };
+
+                let body = self.wrap_in_middleware(struct_name, field, body);
+                let body = wrap_in_tracing_span(struct_name, field, body);
+                let body = wrap_cow_value(field, checked, body);
+
+                quote! {
+                    #interface_name::#struct_name(ref inner) => {
+                        #body
+                    }
+                }
+            });
+
+        let all_args = to_field_args_list(args);
+        let deprecation = &field.deprecation;
+        let return_type = self.macro_return_type_tokens(field, checked);
+
+        quote! {
+            #description
+            #deprecation
+            field #field_name(#all_args) -> #return_type {
+                match *self {
+                    #(#arms),*
+                }
+            }
+        }
+    }
+
     fn gen_field(
         &self,
         field: &FieldTokens,
@@ -1073,7 +2231,19 @@ impl<'doc> CodeGenPass<'doc> {
         let field_name = &field.name;
         let args = &field.macro_args;
 
+        let guards = self.field_guards(&struct_name.to_string(), field);
+        let checked = !guards.is_empty() || field.validation.is_some() || self.middleware.is_some();
+
         let body = gen_field_body(&field, &quote! { &self }, struct_name, trait_name);
+        let body = if checked {
+            gen_checked_field_body(field, &guards, body)
+        } else {
+            body
+        };
+
+        let body = self.wrap_in_middleware(struct_name, field, body);
+        let body = wrap_in_tracing_span(struct_name, field, body);
+        let body = wrap_cow_value(field, checked, body);
 
         let description = field
             .description
@@ -1083,7 +2253,7 @@ impl<'doc> CodeGenPass<'doc> {
 
         let all_args = to_field_args_list(args);
         let deprecation = &field.deprecation;
-        let return_type = self.field_return_type_tokens(&field);
+        let return_type = self.macro_return_type_tokens(field, checked);
 
         quote! {
             #[doc = #description]
@@ -1095,6 +2265,31 @@ impl<'doc> CodeGenPass<'doc> {
     }
 }
 
+// `Borrow` lets variants hold their types however they like, such as in a `Box`, `Arc` or `Cow`.
+fn gen_instance_resolvers<'a>(
+    name: &'a Ident,
+    implementors: &'a [Ident],
+) -> impl Iterator<Item = TokenStream> + 'a {
+    implementors.iter().map(move |variant| {
+        quote! {
+            &#variant => match *self {
+                #name::#variant(ref h) => Some(std::borrow::Borrow::<#variant>::borrow(h)),
+                _ => None,
+            }
+        }
+    })
+}
+
+// Juniper's macros only accept lifetimes when the GraphQL name is given explicitly
+fn macro_type_header(name: &Ident, context_type: &syn::Type, payload: Payload) -> TokenStream {
+    if payload == Payload::Cow {
+        let graphql_name = name.to_string();
+        quote! { <'a> #name<'a>: #context_type as #graphql_name }
+    } else {
+        quote! { #name: #context_type }
+    }
+}
+
 fn quote_deprecation(deprecated: &Deprecation) -> TokenStream {
     match deprecated {
         Deprecation::NoDeprecation => empty_token_stream(),
@@ -1119,10 +2314,67 @@ impl Extend<TokenStream> for CodeGenPass<'_> {
     }
 }
 
+const ENUM_DERIVES: &[&str] = &["Debug", "Eq", "PartialEq", "Copy", "Clone", "Hash"];
+
+const INPUT_OBJECT_DERIVES: &[&str] = &["Debug", "Clone"];
+
+// Derives that we always add are skipped so users don't get conflicting implementations if they
+// ask for them again.
+fn extra_derives<'a>(always_derived: &[&str], derive: &'a Derive) -> Vec<&'a syn::Path> {
+    derive
+        .paths
+        .iter()
+        .filter(|path| !always_derived.iter().any(|name| path.is_ident(name)))
+        .collect()
+}
+
 fn to_enum_name(name: &str) -> Ident {
     ident(name.to_camel_case())
 }
 
+// Explains which GraphQL field a trait method belongs to and why it has the return type it has.
+// This shows up in `cargo doc` and makes it easier to fix errors about incompatible methods.
+fn trait_method_doc(
+    type_name: &str,
+    field: &FieldTokens,
+    return_type: &TokenStream,
+) -> TokenStream {
+    let infallible = if field.infallible {
+        " and the field is infallible"
+    } else {
+        ""
+    };
+
+    let note = format!(
+        "Resolves the GraphQL field `{}.{}`. Ownership is `{}`{} so this method must return `{}`.",
+        type_name,
+        field.name,
+        field.ownership.as_str(),
+        infallible,
+        type_to_string(return_type),
+    );
+
+    quote! {
+        /// Field method generated by `juniper-from-schema`.
+        ///
+        #[doc = #note]
+    }
+}
+
+// Print generated types the way a human would write them rather than how `TokenStream`
+// displays them.
+fn type_to_string(tokens: &TokenStream) -> String {
+    tokens
+        .to_string()
+        .replace(" :: ", "::")
+        .replace(" < ", "<")
+        .replace("< ", "<")
+        .replace(" >", ">")
+        .replace("& ", "&")
+        .replace(" ,", ",")
+        .replace("std::result::Result", "Result")
+}
+
 fn trait_map_for_struct_name(struct_name: &Ident) -> Ident {
     ident(format!("{}Fields", struct_name))
 }
@@ -1143,7 +2395,7 @@ fn gen_field_body(
             }
         }
         TypeKind::Type => {
-            let query_trail_type = ident(&field.inner_type);
+            let query_trail_type = &field.query_trail_type;
             quote! {
                 let look_ahead = executor.look_ahead();
                 let trail = juniper_from_schema::QueryTrail::<
@@ -1156,6 +2408,130 @@ fn gen_field_body(
     }
 }
 
+// Guards are checked before arguments are validated so denied requests don't learn anything
+// about which arguments are valid.
+fn gen_checked_field_body(
+    field: &FieldTokens,
+    guards: &[&syn::Expr],
+    body: TokenStream,
+) -> TokenStream {
+    let validation = &field.validation;
+    let into_field_result = if field.infallible {
+        quote! { Ok(value) }
+    } else {
+        quote! { value.map_err(juniper::IntoFieldError::into_field_error) }
+    };
+
+    quote! {
+        #(
+            juniper_from_schema::Guard::check(executor.context(), &(#guards))?;
+        )*
+        #validation
+        let value = { #body };
+        #into_field_result
+    }
+}
+
+// Only does something for fields with `cow` ownership.
+fn wrap_cow_value(field: &FieldTokens, checked: bool, body: TokenStream) -> TokenStream {
+    if field.ownership != Ownership::Cow {
+        return body;
+    }
+
+    if field.infallible && !checked {
+        quote! { juniper_from_schema::CowValue({ #body }) }
+    } else {
+        quote! { ({ #body }).map(juniper_from_schema::CowValue) }
+    }
+}
+
+// Only does something if the "tracing" feature is enabled.
+fn wrap_in_tracing_span(
+    struct_name: &Ident,
+    field: &FieldTokens,
+    body: TokenStream,
+) -> TokenStream {
+    if !cfg!(feature = "tracing") {
+        return body;
+    }
+
+    let type_name = struct_name.to_string();
+    let field_name = field.name.to_string();
+    let span_name = format!("{}.{}", type_name, field_name);
+    let argument_names = field.argument_names.join(", ");
+
+    quote! {
+        juniper_from_schema::tracing::span!(
+            juniper_from_schema::tracing::Level::INFO,
+            #span_name,
+            type_name = #type_name,
+            field_name = #field_name,
+            arguments = #argument_names,
+        )
+        .in_scope(|| { #body })
+    }
+}
+
+// `cfg!` only takes literals so the features that can be required by a schema are listed here.
+fn feature_enabled(feature: &str) -> bool {
+    match feature {
+        "chrono" => cfg!(feature = "chrono"),
+        "uuid" => cfg!(feature = "uuid"),
+        "url" => cfg!(feature = "url"),
+        "time" => cfg!(feature = "time"),
+        "json" => cfg!(feature = "json"),
+        "bigint" => cfg!(feature = "bigint"),
+        "decimal" => cfg!(feature = "decimal"),
+        "duration" => cfg!(feature = "duration"),
+        "naive-time" => cfg!(feature = "naive-time"),
+        other => panic!("Unknown feature `{}`", other),
+    }
+}
+
+fn scalar_serde_attributes() -> TokenStream {
+    if cfg!(feature = "serde") {
+        let derive = serde_derive();
+        quote! {
+            #derive
+            #[serde(transparent)]
+        }
+    } else {
+        quote! {}
+    }
+}
+
+// `serde` is re-exported from `juniper_from_schema` so users don't need to depend on it directly.
+fn serde_derive() -> TokenStream {
+    if !cfg!(feature = "serde") {
+        return quote! {};
+    }
+
+    quote! {
+        #[derive(juniper_from_schema::serde::Serialize, juniper_from_schema::serde::Deserialize)]
+        #[serde(crate = "juniper_from_schema::serde")]
+    }
+}
+
+// Serialize using the GraphQL name so the data matches what clients send and receive.
+fn serde_rename(graphql_name: &str) -> TokenStream {
+    if !cfg!(feature = "serde") {
+        return quote! {};
+    }
+
+    quote! { #[serde(rename = #graphql_name)] }
+}
+
+// Fields that are absent are skipped rather than serialized as `null`.
+fn serde_tri_state() -> TokenStream {
+    if !cfg!(feature = "serde") {
+        return quote! {};
+    }
+
+    quote! {
+        #[serde(default, skip_serializing_if = "juniper_from_schema::Nullable::is_absent")]
+    }
+}
+
 fn to_field_args_list(args: &[TokenStream]) -> TokenStream {
     if args.is_empty() {
         quote! { &executor }
@@ -1174,36 +2550,79 @@ struct FieldTokens<'a> {
     macro_args: Vec<TokenStream>,
     trait_args: Vec<TokenStream>,
     field_type: TokenStream,
+    macro_field_type: TokenStream,
     field_method: Ident,
     params: Vec<TokenStream>,
     description: &'a Option<String>,
     type_kind: TypeKind,
-    inner_type: Name,
+    query_trail_type: TokenStream,
     deprecation: TokenStream,
     infallible: bool,
+    guard: Option<syn::Expr>,
+    validation: Option<TokenStream>,
+    argument_names: Vec<Name>,
+    ownership: Ownership,
 }
 
 struct FieldArgument<'a> {
     name: Name,
+    graphql_name: &'a str,
     macro_type: TokenStream,
     trait_type: TokenStream,
+    tri_state: bool,
     default_value: Option<TokenStream>,
     description: &'a Option<String>,
 }
 
-// This can also be with TryInto, but that requires 1.34
-#[allow(clippy::cast_lossless)]
-fn i32_from_i64(i: i64) -> Option<i32> {
-    if i > std::i32::MAX as i64 {
-        None
-    } else {
-        Some(i as i32)
+impl<'a> FieldArgument<'a> {
+    fn input_object_field_type(&self) -> &TokenStream {
+        if self.tri_state {
+            &self.trait_type
+        } else {
+            &self.macro_type
+        }
     }
 }
 
-enum FieldTypeDestination<'a> {
+// Custom scalars are built from the value the same way Juniper builds them from query arguments.
+fn quote_scalar_input_value(value: &Value) -> TokenStream {
+    match value {
+        Value::Int(inner) => {
+            let number = inner.as_i64().unwrap_or_default();
+            match i32::try_from(number) {
+                Ok(number) => quote! { juniper::InputValue::scalar(#number) },
+                Err(_) => {
+                    let number = number as f64;
+                    quote! { juniper::InputValue::scalar(#number) }
+                }
+            }
+        }
+        Value::Float(inner) => quote! { juniper::InputValue::scalar(#inner) },
+        Value::String(inner) => quote! { juniper::InputValue::scalar(#inner.to_string()) },
+        Value::Boolean(inner) => quote! { juniper::InputValue::scalar(#inner) },
+        _ => unreachable!("invalid default values are reported by `DefaultValueValidator`"),
+    }
+}
+
+enum FieldTypeDestination {
     Argument,
-    Return(&'a FieldArguments),
+    Return(Ownership),
+}
+
+// The tokens needed to generate a custom scalar that wraps another type.
+struct NewtypeScalar {
+    inner_type: TokenStream,
+    derives: TokenStream,
+    resolve: TokenStream,
+    from_input_value: TokenStream,
+    from_str: TokenStream,
+    from_look_ahead_value: TokenStream,
+}
+
+enum TimeType {
+    Date,
+    OffsetDateTime,
+    PrimitiveDateTime,
 }
 
 fn doc_tokens(doc: &Option<String>) -> TokenStream {
@@ -1280,6 +2699,24 @@ fn is_snake_case(s: &str) -> bool {
     s.contains('_') && s.to_snake_case() == s
 }
 
+impl<'doc> CustomDirectives<'doc> for CodeGenPass<'doc> {
+    fn custom_directive(&self, name: &str) -> Option<&'doc DirectiveDefinition> {
+        self.ast_data.custom_directive(name)
+    }
+}
+
+impl<'doc> EmitLint<'doc> for CodeGenPass<'doc> {
+    fn emit_lint(&mut self, pos: Pos, lint: Lint<'doc>) {
+        match self.lint_levels.level(&lint) {
+            LintLevel::Allow => {}
+            LintLevel::Warn => {
+                self.warnings.insert((pos, lint));
+            }
+            LintLevel::Deny => self.emit_non_fatal_error(pos, ErrorKind::DeniedLint(lint)),
+        }
+    }
+}
+
 impl<'doc> EmitError<'doc> for CodeGenPass<'doc> {
     fn emit_non_fatal_error(&mut self, pos: Pos, kind: ErrorKind<'doc>) {
         let error = Error {
@@ -1295,6 +2732,265 @@ impl<'doc> EmitError<'doc> for CodeGenPass<'doc> {
 mod test {
     #[allow(unused_imports)]
     use super::*;
+    use crate::ast_pass::error::{self, InvalidInterfaceImplementation, UnsupportedDirectiveKind};
+
+    #[test]
+    fn test_type_to_string() {
+        let tokens = quote! { std::result::Result<&Option<Vec<&String> >, juniper::FieldError> };
+        assert_eq!(
+            "Result<&Option<Vec<&String>>, juniper::FieldError>",
+            type_to_string(&tokens)
+        );
+
+        let tokens = quote! { juniper::ID };
+        assert_eq!("juniper::ID", type_to_string(&tokens));
+    }
+
+    #[test]
+    fn test_trait_methods_document_expected_return_type() {
+        let schema = r#"
+            schema { query: Query }
+
+            type Query {
+                name: String!
+                count: Int! @juniper(ownership: "owned", infallible: true)
+            }
+        "#;
+
+        let doc = graphql_parser::parse_schema(schema).unwrap();
+        let ast_data = AstData::new_from_schema_and_doc(schema, &doc).unwrap();
+        let tokens = CodeGenPass::new(
+            schema,
+            crate::parse_input::default_error_type(),
+            crate::parse_input::default_context_type(),
+            None,
+            Default::default(),
+            Default::default(),
+            Default::default(),
+            ast_data,
+        )
+        .gen_juniper_code(&doc)
+        .unwrap()
+        .to_string();
+
+        assert!(tokens.contains(
+            "Resolves the GraphQL field `Query.name`. Ownership is `borrowed` so this method must \
+             return `Result<&String, juniper::FieldError>`."
+        ));
+        assert!(tokens.contains(
+            "Resolves the GraphQL field `Query.count`. Ownership is `owned` and the field is \
+             infallible so this method must return `i32`."
+        ));
+    }
+
+    fn gen_code_with_lint_levels(schema: &str, lint_levels: LintLevels) -> String {
+        let doc = graphql_parser::parse_schema(schema).unwrap();
+        let ast_data = AstData::new_from_schema_and_doc(schema, &doc).unwrap();
+        CodeGenPass::new(
+            schema,
+            crate::parse_input::default_error_type(),
+            crate::parse_input::default_context_type(),
+            None,
+            Default::default(),
+            Default::default(),
+            lint_levels,
+            ast_data,
+        )
+        .gen_juniper_code(&doc)
+        .unwrap()
+        .to_string()
+    }
+
+    const SCHEMA_WITH_UNREACHABLE_TYPE: &str = r#"
+        schema { query: Query }
+
+        type Query {
+            name: String!
+        }
+
+        type Orphan {
+            name: String!
+        }
+    "#;
+
+    #[test]
+    fn test_lints_are_allowed_by_default() {
+        let tokens = gen_code_with_lint_levels(SCHEMA_WITH_UNREACHABLE_TYPE, LintLevels::default());
+
+        assert!(!tokens.contains("deprecated"));
+    }
+
+    #[test]
+    fn test_warn_lint_level() {
+        let mut lint_levels = LintLevels::default();
+        lint_levels.set("unreachable_types", LintLevel::Warn);
+        let tokens = gen_code_with_lint_levels(SCHEMA_WITH_UNREACHABLE_TYPE, lint_levels);
+
+        assert!(tokens.contains("deprecated"));
+        assert!(tokens.contains(
+            "The type `Orphan` can't be reached from the query or mutation type\\n --> schema:8:9\\n\
+             `unreachable_types` is set to warn. Change it with `lints: [unreachable_types: allow]`"
+        ));
+    }
+
+    #[test]
+    fn test_directive_metadata_requires_custom_directives() {
+        let tokens = gen_code_with_lint_levels(
+            r#"
+                schema { query: Query }
+
+                type Query {
+                    name: String!
+                }
+            "#,
+            LintLevels::default(),
+        );
+        assert!(!tokens.contains("directives_for"));
+
+        let tokens = gen_code_with_lint_levels(
+            r#"
+                directive @auth on FIELD_DEFINITION
+
+                schema { query: Query }
+
+                type Query {
+                    name: String! @auth
+                }
+            "#,
+            LintLevels::default(),
+        );
+        assert!(tokens.contains("directives_for"));
+    }
+
+    fn with_error_kinds(schema: &str, f: impl FnOnce(Vec<ErrorKind<'_>>)) {
+        let doc = graphql_parser::parse_schema(schema).unwrap();
+        let ast_data = AstData::new_from_schema_and_doc(schema, &doc).unwrap();
+        let errors = CodeGenPass::new(
+            schema,
+            crate::parse_input::default_error_type(),
+            crate::parse_input::default_context_type(),
+            None,
+            Default::default(),
+            Default::default(),
+            Default::default(),
+            ast_data,
+        )
+        .gen_juniper_code(&doc)
+        .unwrap_err();
+
+        f(errors.into_iter().map(|error| error.kind).collect())
+    }
+
+    #[test]
+    fn test_invalid_field_guard() {
+        let schema = r#"
+            schema { query: Query }
+
+            type Query {
+                name: String! @juniper(guard: "not valid(")
+            }
+        "#;
+
+        with_error_kinds(schema, |kinds| {
+            assert_eq!(
+                vec![ErrorKind::UnsupportedDirective(
+                    UnsupportedDirectiveKind::Guard(error::Guard::InvalidExpression("not valid("))
+                )],
+                kinds
+            );
+        });
+    }
+
+    #[test]
+    fn test_interface_implementations_the_spec_allows() {
+        let schema = r#"
+            schema { query: Query }
+
+            type Query {
+                node: Node
+            }
+
+            interface Node {
+                id: ID
+                parent: Node
+                children(first: Int): [Node]
+            }
+
+            type User implements Node {
+                id: ID!
+                parent: User
+                children(first: Int, after: String): [User!]
+            }
+        "#;
+
+        with_error_kinds(schema, |kinds| {
+            let reasons = kinds
+                .into_iter()
+                .map(|kind| match kind {
+                    ErrorKind::UnsupportedInterfaceImplementation { reason, .. } => reason,
+                    other => panic!("Unexpected error {:?}", other),
+                })
+                .collect::<Vec<_>>();
+
+            assert_eq!(
+                vec![
+                    InvalidInterfaceImplementation::FieldTypeMismatch {
+                        field: "id",
+                        expected: "ID".to_string(),
+                        actual: "ID!".to_string(),
+                    },
+                    InvalidInterfaceImplementation::FieldTypeMismatch {
+                        field: "parent",
+                        expected: "Node".to_string(),
+                        actual: "User".to_string(),
+                    },
+                    InvalidInterfaceImplementation::FieldTypeMismatch {
+                        field: "children",
+                        expected: "[Node]".to_string(),
+                        actual: "[User!]".to_string(),
+                    },
+                    InvalidInterfaceImplementation::ExtraArgument {
+                        field: "children",
+                        argument: "after",
+                    },
+                ],
+                reasons
+            );
+        });
+    }
+
+    #[test]
+    fn test_interface_implementations_the_spec_forbids() {
+        let schema = r#"
+            schema { query: Query }
+
+            type Query {
+                node: Node
+            }
+
+            interface Node {
+                id: ID!
+                parent: User
+                children: [Node]
+            }
+
+            type User implements Node {
+                id: ID
+                parent: Node
+                children(first: Int!): Node
+            }
+        "#;
+
+        with_error_kinds(schema, |kinds| {
+            assert_eq!(4, kinds.len());
+            for kind in kinds {
+                match kind {
+                    ErrorKind::InvalidInterfaceImplementation { .. } => {}
+                    other => panic!("Unexpected error {:?}", other),
+                }
+            }
+        });
+    }
 
     #[test]
     fn test_is_snake_case() {
@@ -0,0 +1,200 @@
+use super::{CodeGenPass, CustomDirectives, EmitError};
+use crate::ast_pass::{error::ErrorKind, schema_visitor::SchemaVisitor};
+use graphql_parser::{schema::*, Pos};
+use proc_macro2::TokenStream;
+use quote::quote;
+
+struct DirectiveMetadataPass<'pass, 'doc> {
+    pass: &'pass mut CodeGenPass<'doc>,
+    type_directives: Vec<TokenStream>,
+    field_directives: Vec<TokenStream>,
+}
+
+impl<'doc> CodeGenPass<'doc> {
+    // Only generated for schemas that declare custom directives so other schemas don't get items
+    // that might collide with their own.
+    pub fn gen_directive_metadata(&mut self, doc: &'doc Document) {
+        if !self.ast_data.has_custom_directives() {
+            return;
+        }
+
+        let mut metadata_pass = DirectiveMetadataPass {
+            pass: self,
+            type_directives: vec![],
+            field_directives: vec![],
+        };
+        metadata_pass.visit_document(doc);
+
+        let type_directives = metadata_pass.type_directives;
+        let field_directives = metadata_pass.field_directives;
+
+        self.extend(quote! {
+            /// Custom directives used on types in the schema, as `(type name, directives)`.
+            ///
+            /// Generated by `juniper-from-schema`.
+            pub const TYPE_DIRECTIVES: &[(&str, &[juniper_from_schema::DirectiveUse])] = &[
+                #(#type_directives),*
+            ];
+
+            /// Custom directives used on fields and input object fields in the schema, as
+            /// `(type name, field name, directives)`.
+            ///
+            /// Generated by `juniper-from-schema`.
+            pub const FIELD_DIRECTIVES: &[(&str, &str, &[juniper_from_schema::DirectiveUse])] = &[
+                #(#field_directives),*
+            ];
+
+            /// The custom directives used on a field, using the names from the schema.
+            ///
+            /// Generated by `juniper-from-schema`.
+            pub fn directives_for(
+                type_name: &str,
+                field_name: &str,
+            ) -> &'static [juniper_from_schema::DirectiveUse] {
+                FIELD_DIRECTIVES
+                    .iter()
+                    .find(|(ty, field, _)| *ty == type_name && *field == field_name)
+                    .map(|(_, _, directives)| *directives)
+                    .unwrap_or(&[])
+            }
+
+            /// The custom directives used on a type, using the name from the schema.
+            ///
+            /// Generated by `juniper-from-schema`.
+            pub fn directives_for_type(
+                type_name: &str,
+            ) -> &'static [juniper_from_schema::DirectiveUse] {
+                TYPE_DIRECTIVES
+                    .iter()
+                    .find(|(ty, _)| *ty == type_name)
+                    .map(|(_, directives)| *directives)
+                    .unwrap_or(&[])
+            }
+        });
+    }
+}
+
+impl<'pass, 'doc> DirectiveMetadataPass<'pass, 'doc> {
+    fn add_type(&mut self, type_name: &str, directives: &'doc [Directive]) {
+        if let Some(uses) = self.quote_directive_uses(directives) {
+            self.type_directives.push(quote! { (#type_name, #uses) });
+        }
+    }
+
+    fn add_field(&mut self, type_name: &str, field_name: &str, directives: &'doc [Directive]) {
+        if let Some(uses) = self.quote_directive_uses(directives) {
+            self.field_directives
+                .push(quote! { (#type_name, #field_name, #uses) });
+        }
+    }
+
+    // Whether the directives are used in the right places is checked while generating the code
+    // for the types, so here we only have to pick out the custom ones.
+    fn quote_directive_uses(&mut self, directives: &'doc [Directive]) -> Option<TokenStream> {
+        let mut uses = vec![];
+
+        for dir in directives {
+            if self.pass.custom_directive(&dir.name).is_none() {
+                continue;
+            }
+
+            let name = &dir.name;
+            let arguments = dir
+                .arguments
+                .iter()
+                .map(|(key, value)| {
+                    let value = self.quote_value(value, dir.position);
+                    quote! { (#key, #value) }
+                })
+                .collect::<Vec<_>>();
+
+            uses.push(quote! {
+                juniper_from_schema::DirectiveUse {
+                    name: #name,
+                    arguments: &[#(#arguments),*],
+                }
+            });
+        }
+
+        if uses.is_empty() {
+            None
+        } else {
+            Some(quote! { &[#(#uses),*] })
+        }
+    }
+
+    fn quote_value(&mut self, value: &Value, pos: Pos) -> TokenStream {
+        match value {
+            Value::Int(inner) => {
+                let number = inner
+                    .as_i64()
+                    .expect("failed to convert directive argument to i64");
+                quote! { juniper_from_schema::DirectiveValue::Int(#number) }
+            }
+            Value::Float(inner) => quote! { juniper_from_schema::DirectiveValue::Float(#inner) },
+            Value::String(inner) => quote! { juniper_from_schema::DirectiveValue::String(#inner) },
+            Value::Boolean(inner) => {
+                quote! { juniper_from_schema::DirectiveValue::Boolean(#inner) }
+            }
+            Value::Enum(inner) => quote! { juniper_from_schema::DirectiveValue::Enum(#inner) },
+            Value::Null => quote! { juniper_from_schema::DirectiveValue::Null },
+            Value::List(list) => {
+                let values = list
+                    .iter()
+                    .map(|value| self.quote_value(value, pos))
+                    .collect::<Vec<_>>();
+                quote! { juniper_from_schema::DirectiveValue::List(&[#(#values),*]) }
+            }
+            Value::Object(map) => {
+                let fields = map
+                    .iter()
+                    .map(|(key, value)| {
+                        let value = self.quote_value(value, pos);
+                        quote! { (#key, #value) }
+                    })
+                    .collect::<Vec<_>>();
+                quote! { juniper_from_schema::DirectiveValue::Object(&[#(#fields),*]) }
+            }
+            Value::Variable(_) => {
+                self.pass
+                    .emit_non_fatal_error(pos, ErrorKind::VariableDirectiveArgument);
+                quote! { juniper_from_schema::DirectiveValue::Null }
+            }
+        }
+    }
+}
+
+impl<'pass, 'doc> SchemaVisitor<'doc> for DirectiveMetadataPass<'pass, 'doc> {
+    fn visit_scalar_type(&mut self, scalar: &'doc ScalarType) {
+        self.add_type(&scalar.name, &scalar.directives);
+    }
+
+    fn visit_object_type(&mut self, obj: &'doc ObjectType) {
+        self.add_type(&obj.name, &obj.directives);
+        for field in &obj.fields {
+            self.add_field(&obj.name, &field.name, &field.directives);
+        }
+    }
+
+    fn visit_interface_type(&mut self, interface: &'doc InterfaceType) {
+        self.add_type(&interface.name, &interface.directives);
+        for field in &interface.fields {
+            self.add_field(&interface.name, &field.name, &field.directives);
+        }
+    }
+
+    fn visit_union_type(&mut self, union: &'doc UnionType) {
+        self.add_type(&union.name, &union.directives);
+    }
+
+    fn visit_enum_type(&mut self, enum_type: &'doc EnumType) {
+        self.add_type(&enum_type.name, &enum_type.directives);
+    }
+
+    fn visit_input_object_type(&mut self, input_object: &'doc InputObjectType) {
+        self.add_type(&input_object.name, &input_object.directives);
+        for field in &input_object.fields {
+            self.add_field(&input_object.name, &field.name, &field.directives);
+        }
+    }
+}
@@ -1,5 +1,7 @@
 use super::{ident, type_name, CodeGenPass, EmitError, FieldTypeDestination, TypeKind};
-use crate::ast_pass::{error::ErrorKind, schema_visitor::SchemaVisitor};
+use crate::ast_pass::{
+    directive_parsing::DateBackend, error::ErrorKind, schema_visitor::SchemaVisitor,
+};
 use graphql_parser::schema::*;
 use heck::{CamelCase, MixedCase, SnakeCase};
 use proc_macro2::TokenStream;
@@ -53,6 +55,17 @@ impl<'doc> CodeGenPass<'doc> {
 }
 
 impl<'pass, 'doc> QueryTrailCodeGenPass<'pass, 'doc> {
+    // Implementations for types with a lifetime cover all of them, not only the `'static` marker
+    // types, so users can elide the lifetime in their `QueryTrail`s.
+    fn impl_type(&self, name: &str) -> TokenStream {
+        let type_name = ident(name);
+        if self.pass.ast_data.type_has_lifetime(name) {
+            quote! { #type_name<'_> }
+        } else {
+            quote! { #type_name }
+        }
+    }
+
     fn gen_query_trail(&mut self) {
         self.pass.extend(quote! {
             use juniper_from_schema::{Walked, NotWalked, QueryTrail};
@@ -199,6 +212,19 @@ impl<'pass, 'doc> QueryTrailCodeGenPass<'pass, 'doc> {
                 }
             }
 
+            impl<'a, 'b, T> FromLookAheadValue<juniper_from_schema::Nullable<T>>
+                for &'a juniper::LookAheadValue<'b, juniper::DefaultScalarValue>
+            where
+                &'a juniper::LookAheadValue<'b, juniper::DefaultScalarValue>: FromLookAheadValue<T>,
+            {
+                fn from(self) -> juniper_from_schema::Nullable<T> {
+                    match self {
+                        juniper::LookAheadValue::Null => juniper_from_schema::Nullable::Null,
+                        other => juniper_from_schema::Nullable::Value(other.from()),
+                    }
+                }
+            }
+
             impl<'a, 'b, T> FromLookAheadValue<Vec<T>>
                 for &'a juniper::LookAheadValue<'b, juniper::DefaultScalarValue>
             where
@@ -267,7 +293,9 @@ impl<'pass, 'doc> QueryTrailCodeGenPass<'pass, 'doc> {
             });
         }
 
-        if self.pass.ast_data.date_scalar_defined() {
+        if self.pass.ast_data.date_scalar_defined()
+            && self.pass.ast_data.date_backend() == DateBackend::Chrono
+        {
             self.pass.extend(quote! {
                 impl<'a, 'b> FromLookAheadValue<chrono::NaiveDate>
                     for &'a juniper::LookAheadValue<'b, juniper::DefaultScalarValue>
@@ -288,7 +316,9 @@ impl<'pass, 'doc> QueryTrailCodeGenPass<'pass, 'doc> {
             });
         }
 
-        if self.pass.ast_data.date_time_scalar_defined() {
+        if self.pass.ast_data.date_time_scalar_defined()
+            && self.pass.ast_data.date_time_backend() == DateBackend::Chrono
+        {
             self.pass.extend(quote! {
                 impl<'a, 'b> FromLookAheadValue<chrono::DateTime<chrono::Utc>>
                     for &'a juniper::LookAheadValue<'b, juniper::DefaultScalarValue>
@@ -329,8 +359,8 @@ impl<'pass, 'doc> QueryTrailCodeGenPass<'pass, 'doc> {
         }
     }
 
-    fn gen_field_walk_methods(&mut self, obj: InternalQueryTrailNode) {
-        let name = ident(&obj.name());
+    fn gen_field_walk_methods(&mut self, obj: InternalQueryTrailNode<'doc>) {
+        let name = self.impl_type(&obj.name());
         let trait_name = ident(&format!("QueryTrail{}Extensions", obj.name()));
         let args_trait_name = ident(&format!("QueryTrail{}ArgumentsExtensions", obj.name()));
         let fields = obj.fields();
@@ -386,7 +416,7 @@ impl<'pass, 'doc> QueryTrailCodeGenPass<'pass, 'doc> {
 
     fn gen_conversion_methods(
         &mut self,
-        original_type_name: Ident,
+        original_type_name: TokenStream,
         obj: InternalQueryTrailNode<'_>,
     ) {
         let mut destination_types = vec![];
@@ -457,14 +487,14 @@ impl<'pass, 'doc> QueryTrailCodeGenPass<'pass, 'doc> {
 
     fn gen_field_walk_method(
         &mut self,
-        field: &Field,
+        field: &'doc Field,
         obj: &InternalQueryTrailNode,
     ) -> FieldWalkMethod {
         let field_type = type_name(&field.field_type);
         let (_, ty) = self
             .pass
             .graphql_scalar_type_to_rust_type(&field_type, field.position);
-        let field_type = ident(field_type.clone().to_camel_case());
+        let field_type = self.pass.query_trail_type(&field_type.to_camel_case());
 
         match ty {
             TypeKind::Scalar => {
@@ -540,7 +570,7 @@ impl<'pass, 'doc> QueryTrailCodeGenPass<'pass, 'doc> {
 
     fn gen_args_query_trail(
         &mut self,
-        field: &Field,
+        field: &'doc Field,
         name: &Ident,
         obj: &InternalQueryTrailNode,
     ) -> (TokenStream, TokenStream, TokenStream) {
@@ -548,8 +578,6 @@ impl<'pass, 'doc> QueryTrailCodeGenPass<'pass, 'doc> {
         let mut argument_implementation = quote! {};
         let mut argument_type = quote! {};
 
-        let obj_type = ident(&obj.name());
-
         let args_method_name = ident(&format!("{}_args", name));
 
         if field.arguments.is_empty() {
@@ -557,15 +585,13 @@ impl<'pass, 'doc> QueryTrailCodeGenPass<'pass, 'doc> {
                 /// Inspect argument in incoming query.
                 ///
                 /// This field takes no arguments, so therefore it returns `()`.
-                fn #args_method_name(&self) -> ();
+                fn #args_method_name(&self);
             });
 
             argument_implementation.extend(quote! {
                 #[allow(missing_docs)]
                 #[inline]
-                fn #args_method_name(&self) -> () {
-                    ()
-                }
+                fn #args_method_name(&self) {}
             });
         } else {
             let args_type_name = ident(&format!(
@@ -582,7 +608,7 @@ impl<'pass, 'doc> QueryTrailCodeGenPass<'pass, 'doc> {
             argument_implementation.extend(quote! {
                 #[allow(missing_docs)]
                 fn #args_method_name(&'a self) -> #args_type_name<'a> {
-                    #args_type_name(self)
+                    #args_type_name(self.look_ahead)
                 }
             });
 
@@ -596,7 +622,7 @@ impl<'pass, 'doc> QueryTrailCodeGenPass<'pass, 'doc> {
                 ///
                 /// Generated by `juniper-from-schema`.
                 pub struct #args_type_name<'a>(
-                    &'a QueryTrail<'a, #obj_type, juniper_from_schema::Walked>
+                    Option<&'a juniper::LookAheadSelection<'a, juniper::DefaultScalarValue>>
                 );
 
                 impl<'a> #args_type_name<'a> {
@@ -610,16 +636,41 @@ impl<'pass, 'doc> QueryTrailCodeGenPass<'pass, 'doc> {
 
     fn gen_argument_look_ahead_methods(
         &mut self,
-        input_value: &InputValue,
+        input_value: &'doc InputValue,
         field_name: &str,
     ) -> TokenStream {
-        let default_value = input_value.default_value.as_ref().map(|value| {
-            self.pass.quote_value(
-                &value,
-                type_name(&input_value.value_type),
-                input_value.position,
-            )
-        });
+        let name = &input_value.name;
+        let ident = ident(name.to_snake_case());
+
+        if let Some(field_type) = self.pass.tri_state_type(input_value) {
+            return quote! {
+                #[allow(missing_docs)]
+                pub fn #ident(&self) -> #field_type {
+                    use juniper::LookAheadMethods;
+
+                    // these `expect`s are fine since these methods you can only obtain
+                    // arguments from walked query trails
+                    let lh = &self
+                        .0
+                        .expect("look_ahead")
+                        .select_child(#field_name)
+                        .expect("select child");
+
+                    let arg = lh.arguments().iter().find(|arg| {
+                        arg.name() == #name
+                    });
+
+                    if let Some(arg) = arg {
+                        let value = arg.value();
+                        FromLookAheadValue::<#field_type>::from(value)
+                    } else {
+                        juniper_from_schema::Nullable::Absent
+                    }
+                }
+            };
+        }
+
+        let default_value = self.pass.quote_default_value(input_value);
 
         let (field_type, _) = self.pass.gen_field_type(
             &input_value.value_type,
@@ -628,9 +679,6 @@ impl<'pass, 'doc> QueryTrailCodeGenPass<'pass, 'doc> {
             input_value.position,
         );
 
-        let name = &input_value.name;
-        let ident = ident(name.to_snake_case());
-
         if let Some(default_value) = default_value {
             quote! {
                 #[allow(missing_docs)]
@@ -641,7 +689,6 @@ impl<'pass, 'doc> QueryTrailCodeGenPass<'pass, 'doc> {
                     // arguments from walked query trails
                     let lh = &self
                         .0
-                        .look_ahead
                         .expect("look_ahead")
                         .select_child(#field_name)
                         .expect("select child");
@@ -668,7 +715,6 @@ impl<'pass, 'doc> QueryTrailCodeGenPass<'pass, 'doc> {
                     // arguments from walked query trails
                     let lh = &self
                         .0
-                        .look_ahead
                         .expect("look_ahead")
                         .select_child(#field_name)
                         .expect("select child");
@@ -821,8 +867,13 @@ mod test {
             tokens: quote! {},
             error_type: crate::parse_input::default_error_type(),
             context_type: crate::parse_input::default_context_type(),
+            middleware: None,
+            default_ownership: Default::default(),
+            data_type_arguments: Default::default(),
+            lint_levels: Default::default(),
             ast_data,
             errors: std::collections::BTreeSet::new(),
+            warnings: std::collections::BTreeSet::new(),
             raw_schema: schema,
         };
 
@@ -0,0 +1,237 @@
+use super::{validate_schema::name_and_position, CodeGenPass};
+use crate::ast_pass::{lints::Lint, schema_visitor::SchemaVisitor, type_name, EmitLint};
+use graphql_parser::{schema::*, Pos};
+use std::collections::{HashMap, HashSet};
+
+/// Checks the schema for things that are valid but probably not what you want. Lints only run on
+/// schemas that have passed validation.
+pub(super) struct SchemaLinter<'pass, 'doc> {
+    pass: &'pass mut CodeGenPass<'doc>,
+}
+
+impl<'pass, 'doc> SchemaLinter<'pass, 'doc> {
+    pub(super) fn new(pass: &'pass mut CodeGenPass<'doc>) -> Self {
+        Self { pass }
+    }
+
+    pub(super) fn lint_document(&mut self, doc: &'doc Document) {
+        self.visit_document(doc);
+        self.lint_unreachable_types(doc);
+    }
+
+    fn lint_description(
+        &mut self,
+        description: &Option<String>,
+        kind: &'static str,
+        name: String,
+        pos: Pos,
+    ) {
+        if description.is_none() {
+            self.pass
+                .emit_lint(pos, Lint::MissingDescription { kind, name });
+        }
+    }
+
+    fn lint_deprecation(&mut self, directives: &[Directive], name: String, pos: Pos) {
+        let without_reason = directives
+            .iter()
+            .any(|directive| directive.name == "deprecated" && directive.arguments.is_empty());
+
+        if without_reason {
+            self.pass
+                .emit_lint(pos, Lint::DeprecatedWithoutReason(name));
+        }
+    }
+
+    fn lint_type(&mut self, type_: &Type, pos: Pos) {
+        if is_nullable_list_of_nullable_items(type_, true) {
+            self.pass
+                .emit_lint(pos, Lint::NullableListOfNullableItems(type_.to_string()));
+        }
+    }
+
+    fn lint_fields(&mut self, type_name: &str, fields: &'doc [Field]) {
+        for field in fields {
+            let name = format!("{}.{}", type_name, field.name);
+            self.lint_description(&field.description, "field", name.clone(), field.position);
+            self.lint_deprecation(&field.directives, name, field.position);
+            self.lint_type(&field.field_type, field.position);
+
+            for arg in &field.arguments {
+                self.lint_type(&arg.value_type, arg.position);
+            }
+        }
+    }
+
+    fn lint_unreachable_types(&mut self, doc: &'doc Document) {
+        // Schemas without a query type have no roots to reach types from
+        let root_types = self.pass.ast_data.root_types();
+        if root_types.query.is_none() {
+            return;
+        }
+
+        let mut types = HashMap::new();
+        let mut implementors = HashMap::<&str, Vec<&str>>::new();
+        let mut reachable = HashSet::new();
+        let mut queue = Vec::new();
+
+        for def in &doc.definitions {
+            match def {
+                Definition::TypeDefinition(ty) => {
+                    if let TypeDefinition::Object(obj) = ty {
+                        for interface in &obj.implements_interfaces {
+                            implementors
+                                .entry(&**interface)
+                                .or_default()
+                                .push(&obj.name);
+                        }
+                    }
+                    types.insert(name_and_position(ty).0, ty);
+                }
+                // Types used by directives are part of the schema even if no field uses them
+                Definition::DirectiveDefinition(directive) => {
+                    queue.extend(input_value_types(&directive.arguments));
+                }
+                _ => {}
+            }
+        }
+
+        queue.extend(root_types.query);
+        queue.extend(root_types.mutation);
+
+        while let Some(name) = queue.pop() {
+            if !reachable.insert(name) {
+                continue;
+            }
+
+            match types.get(name) {
+                Some(TypeDefinition::Object(obj)) => {
+                    queue.extend(field_types(&obj.fields));
+                    queue.extend(obj.implements_interfaces.iter().map(|name| &**name));
+                }
+                Some(TypeDefinition::Interface(interface)) => {
+                    queue.extend(field_types(&interface.fields));
+                    queue.extend(implementors.get(name).into_iter().flatten());
+                }
+                Some(TypeDefinition::Union(union)) => {
+                    queue.extend(union.types.iter().map(|name| &**name));
+                }
+                Some(TypeDefinition::InputObject(input_object)) => {
+                    queue.extend(input_value_types(&input_object.fields));
+                }
+                Some(TypeDefinition::Scalar(_)) | Some(TypeDefinition::Enum(_)) | None => {}
+            }
+        }
+
+        for def in &doc.definitions {
+            if let Definition::TypeDefinition(ty) = def {
+                let (name, pos) = name_and_position(ty);
+                if !reachable.contains(name) {
+                    self.pass.emit_lint(pos, Lint::UnreachableType(name));
+                }
+            }
+        }
+    }
+}
+
+impl<'pass, 'doc> SchemaVisitor<'doc> for SchemaLinter<'pass, 'doc> {
+    fn visit_object_type(&mut self, obj: &'doc ObjectType) {
+        self.lint_description(&obj.description, "object", obj.name.clone(), obj.position);
+        self.lint_fields(&obj.name, &obj.fields);
+    }
+
+    fn visit_interface_type(&mut self, interface: &'doc InterfaceType) {
+        self.lint_description(
+            &interface.description,
+            "interface",
+            interface.name.clone(),
+            interface.position,
+        );
+        self.lint_fields(&interface.name, &interface.fields);
+    }
+
+    fn visit_union_type(&mut self, union: &'doc UnionType) {
+        self.lint_description(
+            &union.description,
+            "union",
+            union.name.clone(),
+            union.position,
+        );
+    }
+
+    fn visit_enum_type(&mut self, enum_type: &'doc EnumType) {
+        self.lint_description(
+            &enum_type.description,
+            "enum",
+            enum_type.name.clone(),
+            enum_type.position,
+        );
+
+        for value in &enum_type.values {
+            if !is_screaming_case(&value.name) {
+                self.pass
+                    .emit_lint(value.position, Lint::NonScreamingCaseEnumValue(&value.name));
+            }
+
+            let name = format!("{}.{}", enum_type.name, value.name);
+            self.lint_deprecation(&value.directives, name, value.position);
+        }
+    }
+
+    fn visit_input_object_type(&mut self, input_object: &'doc InputObjectType) {
+        self.lint_description(
+            &input_object.description,
+            "input object",
+            input_object.name.clone(),
+            input_object.position,
+        );
+
+        for field in &input_object.fields {
+            let name = format!("{}.{}", input_object.name, field.name);
+            self.lint_description(&field.description, "field", name, field.position);
+            self.lint_type(&field.value_type, field.position);
+        }
+    }
+}
+
+fn is_nullable_list_of_nullable_items(type_: &Type, nullable: bool) -> bool {
+    match type_ {
+        Type::NamedType(_) => false,
+        Type::NonNullType(inner) => is_nullable_list_of_nullable_items(inner, false),
+        Type::ListType(item_type) => match &**item_type {
+            Type::NonNullType(_) => is_nullable_list_of_nullable_items(item_type, false),
+            _ if nullable => true,
+            _ => is_nullable_list_of_nullable_items(item_type, true),
+        },
+    }
+}
+
+fn is_screaming_case(name: &str) -> bool {
+    name.chars()
+        .all(|c| c.is_ascii_uppercase() || c.is_ascii_digit() || c == '_')
+}
+
+fn field_types(fields: &[Field]) -> impl Iterator<Item = &str> {
+    fields.iter().flat_map(|field| {
+        std::iter::once(&**type_name(&field.field_type)).chain(input_value_types(&field.arguments))
+    })
+}
+
+fn input_value_types(values: &[InputValue]) -> impl Iterator<Item = &str> {
+    values.iter().map(|value| &**type_name(&value.value_type))
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_is_screaming_case() {
+        assert!(is_screaming_case("METER"));
+        assert!(is_screaming_case("DARK_RED"));
+        assert!(is_screaming_case("HTTP2"));
+
+        assert!(!is_screaming_case("darkRed"));
+        assert!(!is_screaming_case("Meter"));
+    }
+}
@@ -0,0 +1,169 @@
+use super::{CodeGenPass, EmitError};
+use crate::ast_pass::{
+    error::{ErrorKind, InvalidDefaultValue, ValueType},
+    schema_visitor::SchemaVisitor,
+};
+use graphql_parser::{query::Value, schema::*};
+use std::convert::TryFrom;
+
+/// Checks default values against the types of their arguments, following the input coercion
+/// rules from the GraphQL spec. Code is only generated for valid defaults, so this has to run
+/// first.
+pub(super) struct DefaultValueValidator<'pass, 'doc> {
+    pass: &'pass mut CodeGenPass<'doc>,
+}
+
+impl<'pass, 'doc> DefaultValueValidator<'pass, 'doc> {
+    pub(super) fn new(pass: &'pass mut CodeGenPass<'doc>) -> Self {
+        Self { pass }
+    }
+
+    fn validate_field_arguments(&mut self, fields: &'doc [Field]) {
+        for field in fields {
+            for arg in &field.arguments {
+                if let Some(Value::Null) = arg.default_value {
+                    self.pass.emit_non_fatal_error(
+                        arg.position,
+                        ErrorKind::InvalidDefaultValue(InvalidDefaultValue::Null),
+                    );
+                    continue;
+                }
+
+                self.validate_argument(arg);
+            }
+        }
+    }
+
+    fn validate_argument(&mut self, arg: &'doc InputValue) {
+        let value = match &arg.default_value {
+            Some(value) => value,
+            None => return,
+        };
+
+        if let Err(err) = self.check_value(value, &arg.value_type) {
+            self.pass
+                .emit_non_fatal_error(arg.position, ErrorKind::InvalidDefaultValue(err));
+        }
+    }
+
+    fn check_value(
+        &self,
+        value: &'doc Value,
+        type_: &'doc Type,
+    ) -> Result<(), InvalidDefaultValue<'doc>> {
+        match (type_, value) {
+            // Reported when generating the default value
+            (_, Value::Variable(_)) => Ok(()),
+
+            (Type::NonNullType(_), Value::Null) => Err(type_mismatch(type_, value)),
+            (Type::NonNullType(inner), value) => self.check_value(value, inner),
+
+            (_, Value::Null) => Ok(()),
+
+            (Type::ListType(inner), Value::List(values)) => values
+                .iter()
+                .try_for_each(|value| self.check_value(value, inner)),
+            // A single value is coerced into a list with one item
+            (Type::ListType(inner), value) => self.check_value(value, inner),
+
+            (Type::NamedType(name), value) => self.check_named_value(value, name, type_),
+        }
+    }
+
+    fn check_named_value(
+        &self,
+        value: &'doc Value,
+        name: &'doc str,
+        type_: &'doc Type,
+    ) -> Result<(), InvalidDefaultValue<'doc>> {
+        let ast_data = &self.pass.ast_data;
+
+        match (name, value) {
+            ("Int", Value::Int(number)) => {
+                let number = number.as_i64().unwrap_or(i64::MAX);
+                i32::try_from(number)
+                    .map(|_| ())
+                    .map_err(|_| InvalidDefaultValue::IntOutOfRange(number))
+            }
+            ("Float", Value::Int(_)) | ("Float", Value::Float(_)) => Ok(()),
+            ("String", Value::String(_)) => Ok(()),
+            ("Boolean", Value::Boolean(_)) => Ok(()),
+            ("ID", Value::String(_)) | ("ID", Value::Int(_)) => Ok(()),
+
+            (name, Value::Enum(enum_value)) if ast_data.is_enum_variant(name) => {
+                if ast_data.enum_variant_name(name, enum_value).is_some() {
+                    Ok(())
+                } else {
+                    Err(InvalidDefaultValue::UnknownEnumValue {
+                        enum_name: name,
+                        value: enum_value,
+                    })
+                }
+            }
+
+            (name, Value::Object(map)) if ast_data.is_input_object(name) => {
+                for (key, value) in map {
+                    let field_type = ast_data.input_object_field_type(name, key).ok_or(
+                        InvalidDefaultValue::UnknownField {
+                            input_object: name,
+                            field: key,
+                        },
+                    )?;
+                    self.check_value(value, field_type)?;
+                }
+
+                let fields = ast_data.input_object_fields(name).into_iter().flatten();
+                for (field, field_type) in fields {
+                    if let Type::NonNullType(_) = field_type {
+                        if !map.contains_key(*field) {
+                            return Err(InvalidDefaultValue::MissingField {
+                                input_object: name,
+                                field,
+                            });
+                        }
+                    }
+                }
+
+                Ok(())
+            }
+
+            // Custom scalars decide which values they accept when they're parsed, but they can
+            // only be parsed from scalar values
+            (name, Value::String(_))
+            | (name, Value::Int(_))
+            | (name, Value::Float(_))
+            | (name, Value::Boolean(_))
+                if ast_data.is_scalar(name) =>
+            {
+                Ok(())
+            }
+
+            _ => Err(type_mismatch(type_, value)),
+        }
+    }
+}
+
+fn type_mismatch<'doc>(type_: &Type, value: &Value) -> InvalidDefaultValue<'doc> {
+    InvalidDefaultValue::TypeMismatch {
+        expected: type_.to_string(),
+        actual: ValueType::from(value),
+    }
+}
+
+impl<'pass, 'doc> SchemaVisitor<'doc> for DefaultValueValidator<'pass, 'doc> {
+    fn visit_object_type(&mut self, obj: &'doc ObjectType) {
+        self.validate_field_arguments(&obj.fields);
+    }
+
+    fn visit_interface_type(&mut self, interface: &'doc InterfaceType) {
+        self.validate_field_arguments(&interface.fields);
+    }
+
+    // Default values of directive arguments aren't used by the generated code, so unlike field
+    // arguments they can be `null`.
+    fn visit_directive_definition(&mut self, directive: &'doc DirectiveDefinition) {
+        for arg in &directive.arguments {
+            self.validate_argument(arg);
+        }
+    }
+}
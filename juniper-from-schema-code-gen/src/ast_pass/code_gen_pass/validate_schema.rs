@@ -0,0 +1,456 @@
+use super::{CodeGenPass, EmitError};
+use crate::ast_pass::{
+    error::{ErrorKind, InvalidInterfaceImplementation, BUILT_IN_SCALARS},
+    schema_visitor::SchemaVisitor,
+    type_name,
+};
+use graphql_parser::{schema::*, Pos};
+use std::collections::{HashMap, HashSet};
+
+/// Checks the schema against the validation rules for type systems from the GraphQL spec. The
+/// rest of the code generation assumes the schema is valid, so problems found here would
+/// otherwise show up as confusing errors in the generated code.
+pub(super) struct SchemaValidator<'pass, 'doc> {
+    pass: &'pass mut CodeGenPass<'doc>,
+    types: HashMap<&'doc str, &'doc TypeDefinition>,
+}
+
+#[derive(Debug, Clone, Copy)]
+enum Usage {
+    Input,
+    Output,
+}
+
+impl<'pass, 'doc> SchemaValidator<'pass, 'doc> {
+    pub(super) fn new(pass: &'pass mut CodeGenPass<'doc>, doc: &'doc Document) -> Self {
+        let mut validator = Self {
+            pass,
+            types: HashMap::new(),
+        };
+        validator.collect_types(doc);
+        validator
+    }
+
+    fn collect_types(&mut self, doc: &'doc Document) {
+        for def in &doc.definitions {
+            let ty = match def {
+                Definition::TypeDefinition(ty) => ty,
+                _ => continue,
+            };
+            let (name, pos) = name_and_position(ty);
+
+            self.validate_name(name, pos);
+
+            if BUILT_IN_SCALARS.contains(&name) || self.types.contains_key(name) {
+                self.pass
+                    .emit_non_fatal_error(pos, ErrorKind::TypeDefinedMoreThanOnce(name));
+            } else {
+                self.types.insert(name, ty);
+            }
+        }
+    }
+
+    fn validate_name(&mut self, name: &'doc str, pos: Pos) {
+        if name.starts_with("__") {
+            self.pass
+                .emit_non_fatal_error(pos, ErrorKind::ReservedName(name));
+        }
+    }
+
+    fn validate_not_empty<T>(
+        &mut self,
+        items: &[T],
+        kind: &'static str,
+        name: &'doc str,
+        pos: Pos,
+    ) {
+        if items.is_empty() {
+            self.pass
+                .emit_non_fatal_error(pos, ErrorKind::EmptyType { kind, name });
+        }
+    }
+
+    fn validate_fields(&mut self, fields: &'doc [Field]) {
+        let mut seen = HashSet::new();
+
+        for field in fields {
+            self.validate_name(&field.name, field.position);
+            if !seen.insert(&field.name) {
+                self.pass.emit_non_fatal_error(
+                    field.position,
+                    ErrorKind::FieldDefinedMoreThanOnce(&field.name),
+                );
+            }
+
+            self.validate_type(&field.field_type, Usage::Output, field.position);
+            self.validate_arguments(&field.arguments);
+        }
+    }
+
+    fn validate_arguments(&mut self, args: &'doc [InputValue]) {
+        let mut seen = HashSet::new();
+
+        for arg in args {
+            self.validate_name(&arg.name, arg.position);
+            if !seen.insert(&arg.name) {
+                self.pass.emit_non_fatal_error(
+                    arg.position,
+                    ErrorKind::ArgumentDefinedMoreThanOnce(&arg.name),
+                );
+            }
+
+            self.validate_type(&arg.value_type, Usage::Input, arg.position);
+        }
+    }
+
+    fn validate_type(&mut self, type_: &'doc Type, usage: Usage, pos: Pos) {
+        let name = type_name(type_);
+
+        let ty = match self.types.get(&**name) {
+            Some(ty) => ty,
+            None if BUILT_IN_SCALARS.contains(&&**name) => return,
+            None => {
+                self.pass.emit_non_fatal_error(pos, undefined_type(name));
+                return;
+            }
+        };
+
+        match (usage, ty) {
+            (Usage::Output, TypeDefinition::InputObject(_)) => {
+                self.pass
+                    .emit_non_fatal_error(pos, ErrorKind::InputObjectAsOutputType(name));
+            }
+            (Usage::Input, TypeDefinition::Object(_))
+            | (Usage::Input, TypeDefinition::Interface(_))
+            | (Usage::Input, TypeDefinition::Union(_)) => {
+                self.pass.emit_non_fatal_error(
+                    pos,
+                    ErrorKind::OutputTypeAsInputType {
+                        name,
+                        kind: kind_name(ty),
+                    },
+                );
+            }
+            _ => {}
+        }
+    }
+
+    fn validate_implementation(&mut self, obj: &'doc ObjectType, interface_name: &'doc str) {
+        let interface = match self.types.get(interface_name) {
+            Some(TypeDefinition::Interface(interface)) => interface,
+            Some(ty) => {
+                let kind = kind_name(ty);
+                self.emit_invalid_implementation(
+                    obj.position,
+                    interface_name,
+                    InvalidInterfaceImplementation::NotAnInterface(kind),
+                );
+                return;
+            }
+            None => {
+                self.pass
+                    .emit_non_fatal_error(obj.position, undefined_type(interface_name));
+                return;
+            }
+        };
+
+        for interface_field in &interface.fields {
+            let field = match obj.fields.iter().find(|f| f.name == interface_field.name) {
+                Some(field) => field,
+                None => {
+                    self.emit_invalid_implementation(
+                        obj.position,
+                        interface_name,
+                        InvalidInterfaceImplementation::MissingField(&interface_field.name),
+                    );
+                    continue;
+                }
+            };
+
+            if field.field_type != interface_field.field_type {
+                let reason = InvalidInterfaceImplementation::FieldTypeMismatch {
+                    field: &field.name,
+                    expected: interface_field.field_type.to_string(),
+                    actual: field.field_type.to_string(),
+                };
+
+                if self.is_valid_implementation_field_type(
+                    &field.field_type,
+                    &interface_field.field_type,
+                ) {
+                    self.emit_unsupported_implementation(field.position, interface_name, reason);
+                } else {
+                    self.emit_invalid_implementation(field.position, interface_name, reason);
+                }
+            }
+
+            self.validate_implementation_arguments(interface_name, interface_field, field);
+        }
+    }
+
+    fn validate_implementation_arguments(
+        &mut self,
+        interface_name: &'doc str,
+        interface_field: &'doc Field,
+        field: &'doc Field,
+    ) {
+        for interface_arg in &interface_field.arguments {
+            match field
+                .arguments
+                .iter()
+                .find(|a| a.name == interface_arg.name)
+            {
+                Some(arg) if arg.value_type != interface_arg.value_type => {
+                    self.emit_invalid_implementation(
+                        arg.position,
+                        interface_name,
+                        InvalidInterfaceImplementation::ArgumentTypeMismatch {
+                            field: &field.name,
+                            argument: &arg.name,
+                            expected: interface_arg.value_type.to_string(),
+                            actual: arg.value_type.to_string(),
+                        },
+                    );
+                }
+                Some(_) => {}
+                None => {
+                    self.emit_invalid_implementation(
+                        field.position,
+                        interface_name,
+                        InvalidInterfaceImplementation::MissingArgument {
+                            field: &field.name,
+                            argument: &interface_arg.name,
+                        },
+                    );
+                }
+            }
+        }
+
+        for arg in &field.arguments {
+            if interface_field
+                .arguments
+                .iter()
+                .all(|interface_arg| interface_arg.name != arg.name)
+            {
+                let reason = InvalidInterfaceImplementation::ExtraArgument {
+                    field: &field.name,
+                    argument: &arg.name,
+                };
+
+                // The spec allows extra arguments as long as they're optional
+                if let Type::NonNullType(_) = arg.value_type {
+                    self.emit_invalid_implementation(arg.position, interface_name, reason);
+                } else {
+                    self.emit_unsupported_implementation(arg.position, interface_name, reason);
+                }
+            }
+        }
+    }
+
+    // `IsValidImplementationFieldType` from the spec. Fields may return a non-null version or a
+    // subtype of the interface field's type.
+    fn is_valid_implementation_field_type(&self, field_type: &Type, implemented: &Type) -> bool {
+        match (field_type, implemented) {
+            (Type::NonNullType(field_type), Type::NonNullType(implemented)) => {
+                self.is_valid_implementation_field_type(field_type, implemented)
+            }
+            (Type::NonNullType(field_type), implemented) => {
+                self.is_valid_implementation_field_type(field_type, implemented)
+            }
+            (Type::ListType(field_type), Type::ListType(implemented)) => {
+                self.is_valid_implementation_field_type(field_type, implemented)
+            }
+            (Type::NamedType(name), Type::NamedType(implemented)) => {
+                name == implemented || self.is_possible_type(name, implemented)
+            }
+            _ => false,
+        }
+    }
+
+    fn is_possible_type(&self, name: &str, abstract_type: &str) -> bool {
+        let obj = match self.types.get(name) {
+            Some(TypeDefinition::Object(obj)) => obj,
+            _ => return false,
+        };
+
+        match self.types.get(abstract_type) {
+            Some(TypeDefinition::Union(union)) => union.types.iter().any(|member| member == name),
+            Some(TypeDefinition::Interface(_)) => obj
+                .implements_interfaces
+                .iter()
+                .any(|interface| interface == abstract_type),
+            _ => false,
+        }
+    }
+
+    fn emit_invalid_implementation(
+        &mut self,
+        pos: Pos,
+        interface: &'doc str,
+        reason: InvalidInterfaceImplementation<'doc>,
+    ) {
+        self.pass.emit_non_fatal_error(
+            pos,
+            ErrorKind::InvalidInterfaceImplementation { interface, reason },
+        );
+    }
+
+    // Valid according to the spec but not something the generated code supports.
+    fn emit_unsupported_implementation(
+        &mut self,
+        pos: Pos,
+        interface: &'doc str,
+        reason: InvalidInterfaceImplementation<'doc>,
+    ) {
+        self.pass.emit_non_fatal_error(
+            pos,
+            ErrorKind::UnsupportedInterfaceImplementation { interface, reason },
+        );
+    }
+
+    fn validate_root_type(
+        &mut self,
+        operation: &'static str,
+        name: &'doc Option<String>,
+        pos: Pos,
+    ) {
+        let name = match name {
+            Some(name) => name,
+            None => return,
+        };
+
+        match self.types.get(&**name) {
+            Some(TypeDefinition::Object(_)) => {}
+            Some(ty) => {
+                let kind = kind_name(ty);
+                self.pass
+                    .emit_non_fatal_error(pos, ErrorKind::RootTypeNotObject { operation, kind });
+            }
+            None => self.pass.emit_non_fatal_error(pos, undefined_type(name)),
+        }
+    }
+}
+
+impl<'pass, 'doc> SchemaVisitor<'doc> for SchemaValidator<'pass, 'doc> {
+    fn visit_schema_definition(&mut self, schema_def: &'doc SchemaDefinition) {
+        let pos = schema_def.position;
+        self.validate_root_type("query", &schema_def.query, pos);
+        self.validate_root_type("mutation", &schema_def.mutation, pos);
+        self.validate_root_type("subscription", &schema_def.subscription, pos);
+    }
+
+    fn visit_directive_definition(&mut self, directive: &'doc DirectiveDefinition) {
+        self.validate_arguments(&directive.arguments);
+    }
+
+    fn visit_object_type(&mut self, obj: &'doc ObjectType) {
+        self.validate_not_empty(&obj.fields, "object", &obj.name, obj.position);
+        self.validate_fields(&obj.fields);
+
+        for interface_name in &obj.implements_interfaces {
+            self.validate_implementation(obj, interface_name);
+        }
+    }
+
+    fn visit_interface_type(&mut self, interface: &'doc InterfaceType) {
+        self.validate_not_empty(
+            &interface.fields,
+            "interface",
+            &interface.name,
+            interface.position,
+        );
+        self.validate_fields(&interface.fields);
+    }
+
+    fn visit_union_type(&mut self, union: &'doc UnionType) {
+        self.validate_not_empty(&union.types, "union", &union.name, union.position);
+
+        let mut seen = HashSet::new();
+        for member in &union.types {
+            if !seen.insert(member) {
+                self.pass.emit_non_fatal_error(
+                    union.position,
+                    ErrorKind::UnionMemberListedMoreThanOnce(member),
+                );
+            }
+
+            match self.types.get(&**member) {
+                Some(TypeDefinition::Object(_)) => {}
+                Some(ty) => {
+                    let kind = kind_name(ty);
+                    self.pass.emit_non_fatal_error(
+                        union.position,
+                        ErrorKind::UnionMemberNotObject { name: member, kind },
+                    );
+                }
+                None => self
+                    .pass
+                    .emit_non_fatal_error(union.position, undefined_type(member)),
+            }
+        }
+    }
+
+    fn visit_enum_type(&mut self, enum_type: &'doc EnumType) {
+        self.validate_not_empty(
+            &enum_type.values,
+            "enum",
+            &enum_type.name,
+            enum_type.position,
+        );
+    }
+
+    fn visit_input_object_type(&mut self, input_object: &'doc InputObjectType) {
+        self.validate_not_empty(
+            &input_object.fields,
+            "input object",
+            &input_object.name,
+            input_object.position,
+        );
+
+        let mut seen = HashSet::new();
+        for field in &input_object.fields {
+            self.validate_name(&field.name, field.position);
+            if !seen.insert(&field.name) {
+                self.pass.emit_non_fatal_error(
+                    field.position,
+                    ErrorKind::FieldDefinedMoreThanOnce(&field.name),
+                );
+            }
+
+            self.validate_type(&field.value_type, Usage::Input, field.position);
+        }
+    }
+}
+
+// The special case scalars have their own errors since they're easy to forget to define
+fn undefined_type(name: &str) -> ErrorKind<'_> {
+    match name {
+        name if name == crate::DATE_SCALAR_NAME => ErrorKind::DateScalarNotDefined,
+        name if name == crate::DATE_TIME_SCALAR_NAME => ErrorKind::DateTimeScalarNotDefined,
+        name if name == crate::UUID_SCALAR_NAME => ErrorKind::UuidScalarNotDefined,
+        name if name == crate::URL_SCALAR_NAME => ErrorKind::UrlScalarNotDefined,
+        name => ErrorKind::UndefinedType(name),
+    }
+}
+
+pub(super) fn name_and_position(ty: &TypeDefinition) -> (&str, Pos) {
+    match ty {
+        TypeDefinition::Scalar(inner) => (&inner.name, inner.position),
+        TypeDefinition::Object(inner) => (&inner.name, inner.position),
+        TypeDefinition::Interface(inner) => (&inner.name, inner.position),
+        TypeDefinition::Union(inner) => (&inner.name, inner.position),
+        TypeDefinition::Enum(inner) => (&inner.name, inner.position),
+        TypeDefinition::InputObject(inner) => (&inner.name, inner.position),
+    }
+}
+
+fn kind_name(ty: &TypeDefinition) -> &'static str {
+    match ty {
+        TypeDefinition::Scalar(_) => "scalar",
+        TypeDefinition::Object(_) => "object",
+        TypeDefinition::Interface(_) => "interface",
+        TypeDefinition::Union(_) => "union",
+        TypeDefinition::Enum(_) => "enum",
+        TypeDefinition::InputObject(_) => "input object",
+    }
+}
@@ -1,19 +1,19 @@
 use crate::ast_pass::{
     code_gen_pass::CodeGenPass,
     error::{self, ErrorKind, Juniper, UnsupportedDirectiveKind, ValueType},
-    EmitError,
+    CustomDirectives, EmitError,
 };
 use graphql_parser::{query::Value, schema::*};
 use std::convert::identity;
 
 pub trait FromDirective: Sized {
-    fn from_directive(dir: &Directive) -> Result<Self, ErrorKind>;
+    fn from_directive(dir: &Directive) -> Result<Self, ErrorKind<'_>>;
 }
 
 pub trait FromDirectiveArguments: Sized + Default {
     const KEY: &'static str;
 
-    fn from_directive_args(args: &(String, Value)) -> Option<Result<Self, ErrorKind>>;
+    fn from_directive_args(args: &(String, Value)) -> Option<Result<Self, ErrorKind<'_>>>;
 }
 
 #[derive(Debug)]
@@ -29,7 +29,7 @@ impl Default for Deprecation {
 }
 
 impl FromDirective for Deprecation {
-    fn from_directive(dir: &Directive) -> Result<Self, ErrorKind> {
+    fn from_directive(dir: &Directive) -> Result<Self, ErrorKind<'_>> {
         let name = &dir.name;
         if name != "deprecated" {
             return Err(ErrorKind::UnsupportedDirective(
@@ -96,7 +96,7 @@ macro_rules! impl_from_directive_for {
             $($name: FromDirectiveArguments,)*
         {
             #[allow(non_snake_case)]
-            fn from_directive(dir: &Directive) -> Result<Self, ErrorKind> {
+            fn from_directive(dir: &Directive) -> Result<Self, ErrorKind<'_>> {
                 let name = &dir.name;
                 if name != "juniper" {
                     return Err(ErrorKind::UnsupportedDirective(
@@ -143,19 +143,131 @@ macro_rules! impl_from_directive_for {
 
 impl_from_directive_for! { (A) }
 impl_from_directive_for! { (A, B) }
+impl_from_directive_for! { (A, B, C) }
+impl_from_directive_for! { (A, B, C, D) }
+impl_from_directive_for! { (A, B, C, D, E) }
 
 #[derive(Debug)]
 pub struct FieldArguments {
-    pub ownership: Ownership,
+    /// `None` if the field doesn't specify an ownership, meaning the default should be used.
+    pub ownership: Option<Ownership>,
     pub deprecated: Option<Deprecation>,
     pub infallible: Infallible,
+    pub guard: Guard,
 }
 
-#[derive(Debug)]
+#[derive(Debug, Default)]
+pub struct TypeArguments {
+    pub guard: Guard,
+    pub ownership: Option<Ownership>,
+    /// Only supported on interfaces.
+    pub payload: Payload,
+}
+
+/// Extra derives and attributes for generated enums and input objects.
+#[derive(Debug, Default, Clone)]
+pub struct DataTypeArguments {
+    pub derive: Derive,
+    pub attributes: Attributes,
+}
+
+impl DataTypeArguments {
+    /// Combine these arguments with the ones given to a specific type.
+    pub fn extend(&self, other: &DataTypeArguments) -> DataTypeArguments {
+        let mut derive = self.derive.paths.clone();
+        derive.extend(other.derive.paths.iter().cloned());
+
+        let mut attributes = self.attributes.attrs.clone();
+        attributes.extend(other.attributes.attrs.iter().cloned());
+
+        DataTypeArguments {
+            derive: Derive { paths: derive },
+            attributes: Attributes { attrs: attributes },
+        }
+    }
+}
+
+#[derive(Debug, Default)]
+pub struct InputValueArguments {
+    pub tri_state: TriState,
+    pub validations: Validations,
+}
+
+/// Constraints checked before a field method is called.
+#[derive(Debug, Default, Clone)]
+pub struct Validations {
+    pub min: Min,
+    pub max: Max,
+    pub max_length: MaxLength,
+    pub pattern: Pattern,
+}
+
+impl Validations {
+    pub fn is_empty(&self) -> bool {
+        self.min.value.is_none()
+            && self.max.value.is_none()
+            && self.max_length.value.is_none()
+            && self.pattern.value.is_none()
+    }
+}
+
+#[derive(Debug, Default)]
+pub struct InputObjectArguments {
+    pub data_type: DataTypeArguments,
+    pub builder: Builder,
+}
+
+#[derive(Debug, Default, Clone)]
+pub struct EnumArguments {
+    pub data_type: DataTypeArguments,
+    pub rust_type: RustType,
+}
+
+#[derive(Debug, Default, Clone)]
+pub struct UnionArguments {
+    pub payload: Payload,
+    pub rust_type: RustType,
+}
+
+#[derive(Debug, Default)]
+pub struct EnumValueArguments {
+    pub deprecated: Deprecation,
+    pub rust_name: RustName,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum Ownership {
     Owned,
     Borrowed,
     AsRef,
+    Arc,
+    Boxed,
+    Cow,
+}
+
+impl Ownership {
+    pub fn from_name(name: &str) -> Option<Self> {
+        match name {
+            "owned" => Some(Ownership::Owned),
+            "borrowed" => Some(Ownership::Borrowed),
+            "as_ref" => Some(Ownership::AsRef),
+            "arc" => Some(Ownership::Arc),
+            "boxed" => Some(Ownership::Boxed),
+            "cow" => Some(Ownership::Cow),
+            _ => None,
+        }
+    }
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Ownership::Owned => "owned",
+            Ownership::Borrowed => "borrowed",
+            Ownership::AsRef => "as_ref",
+            Ownership::Arc => "arc",
+            Ownership::Boxed => "boxed",
+            Ownership::Cow => "cow",
+        }
+    }
 }
 
 impl Default for Ownership {
@@ -167,7 +279,7 @@ impl Default for Ownership {
 impl FromDirectiveArguments for Ownership {
     const KEY: &'static str = "ownership";
 
-    fn from_directive_args((key, value): &(String, Value)) -> Option<Result<Self, ErrorKind>> {
+    fn from_directive_args((key, value): &(String, Value)) -> Option<Result<Self, ErrorKind<'_>>> {
         if key != Self::KEY {
             return None;
         }
@@ -175,23 +287,67 @@ impl FromDirectiveArguments for Ownership {
         let directive = (|| {
             let ownership_raw = value_as_string(value)?;
 
-            let ownership = match ownership_raw {
-                "owned" => Ownership::Owned,
-                "borrowed" => Ownership::Borrowed,
-                "as_ref" => Ownership::AsRef,
-                value => {
-                    return Err(ErrorKind::UnsupportedDirective(
-                        UnsupportedDirectiveKind::Ownership(error::Ownership::InvalidValue(value)),
-                    ));
-                }
-            };
+            Ownership::from_name(ownership_raw).ok_or(ErrorKind::UnsupportedDirective(
+                UnsupportedDirectiveKind::Ownership(error::Ownership::InvalidValue(ownership_raw)),
+            ))
+        })();
+        Some(directive)
+    }
+}
+
+/// How the variants of interface and union enums hold their types.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Payload {
+    #[default]
+    Owned,
+    Boxed,
+    Arc,
+    Rc,
+    Cow,
+}
+
+impl Payload {
+    pub fn from_name(name: &str) -> Option<Self> {
+        match name {
+            "owned" => Some(Payload::Owned),
+            "boxed" => Some(Payload::Boxed),
+            "arc" => Some(Payload::Arc),
+            "rc" => Some(Payload::Rc),
+            "cow" => Some(Payload::Cow),
+            _ => None,
+        }
+    }
+}
+
+impl FromDirectiveArguments for Payload {
+    const KEY: &'static str = "payload";
+
+    fn from_directive_args((key, value): &(String, Value)) -> Option<Result<Self, ErrorKind<'_>>> {
+        if key != Self::KEY {
+            return None;
+        }
 
-            Ok(ownership)
+        let directive = (|| {
+            let payload_raw = value_as_string(value)?;
+
+            Payload::from_name(payload_raw).ok_or(ErrorKind::UnsupportedDirective(
+                UnsupportedDirectiveKind::Payload(error::Payload::InvalidValue(payload_raw)),
+            ))
         })();
         Some(directive)
     }
 }
 
+// Used for arguments where we need to know if they were given or not, for example to fall back to
+// a default defined elsewhere.
+impl<T: FromDirectiveArguments> FromDirectiveArguments for Option<T> {
+    const KEY: &'static str = T::KEY;
+
+    fn from_directive_args(args: &(String, Value)) -> Option<Result<Self, ErrorKind<'_>>> {
+        T::from_directive_args(args).map(|result| result.map(Some))
+    }
+}
+
 #[derive(Debug)]
 pub struct Infallible {
     pub value: bool,
@@ -206,7 +362,263 @@ impl Default for Infallible {
 impl FromDirectiveArguments for Infallible {
     const KEY: &'static str = "infallible";
 
-    fn from_directive_args((key, value): &(String, Value)) -> Option<Result<Self, ErrorKind>> {
+    fn from_directive_args((key, value): &(String, Value)) -> Option<Result<Self, ErrorKind<'_>>> {
+        if key != Self::KEY {
+            return None;
+        }
+
+        let directive = (|| {
+            let value = value_as_bool(value)?;
+            Ok(Self { value })
+        })();
+
+        Some(directive)
+    }
+}
+
+#[derive(Debug, Default)]
+pub struct Guard {
+    pub expr: Option<syn::Expr>,
+}
+
+impl FromDirectiveArguments for Guard {
+    const KEY: &'static str = "guard";
+
+    fn from_directive_args((key, value): &(String, Value)) -> Option<Result<Self, ErrorKind<'_>>> {
+        if key != Self::KEY {
+            return None;
+        }
+
+        let directive = (|| {
+            let expr_raw = value_as_string(value)?;
+
+            let expr = syn::parse_str::<syn::Expr>(expr_raw).map_err(|_| {
+                ErrorKind::UnsupportedDirective(UnsupportedDirectiveKind::Guard(
+                    error::Guard::InvalidExpression(expr_raw),
+                ))
+            })?;
+
+            Ok(Self { expr: Some(expr) })
+        })();
+        Some(directive)
+    }
+}
+
+#[derive(Debug, Default, Clone)]
+pub struct Derive {
+    pub paths: Vec<syn::Path>,
+}
+
+impl Derive {
+    pub fn parse(paths_raw: &str) -> syn::Result<Self> {
+        use syn::{parse::Parser, punctuated::Punctuated, Token};
+
+        let paths = Punctuated::<syn::Path, Token![,]>::parse_terminated.parse_str(paths_raw)?;
+        Ok(Self {
+            paths: paths.into_iter().collect(),
+        })
+    }
+}
+
+impl FromDirectiveArguments for Derive {
+    const KEY: &'static str = "derive";
+
+    fn from_directive_args((key, value): &(String, Value)) -> Option<Result<Self, ErrorKind<'_>>> {
+        if key != Self::KEY {
+            return None;
+        }
+
+        let directive = (|| {
+            let paths_raw = value_as_string(value)?;
+
+            Self::parse(paths_raw).map_err(|_| {
+                ErrorKind::UnsupportedDirective(UnsupportedDirectiveKind::Derive(
+                    error::Derive::InvalidPaths(paths_raw),
+                ))
+            })
+        })();
+        Some(directive)
+    }
+}
+
+#[derive(Debug, Default, Clone)]
+pub struct Attributes {
+    pub attrs: Vec<syn::Attribute>,
+}
+
+impl Attributes {
+    pub fn parse(attrs_raw: &str) -> syn::Result<Self> {
+        use syn::parse::Parser;
+
+        let attrs = syn::Attribute::parse_outer.parse_str(attrs_raw)?;
+        Ok(Self { attrs })
+    }
+}
+
+impl FromDirectiveArguments for Attributes {
+    const KEY: &'static str = "attributes";
+
+    fn from_directive_args((key, value): &(String, Value)) -> Option<Result<Self, ErrorKind<'_>>> {
+        if key != Self::KEY {
+            return None;
+        }
+
+        let directive = (|| {
+            let attrs_raw = value_as_string(value)?;
+
+            Self::parse(attrs_raw).map_err(|_| {
+                ErrorKind::UnsupportedDirective(UnsupportedDirectiveKind::Attributes(
+                    error::Attributes::InvalidAttributes(attrs_raw),
+                ))
+            })
+        })();
+        Some(directive)
+    }
+}
+
+/// Whether to use `Nullable<T>` rather than `Option<T>` for an argument or input object field.
+#[derive(Debug, Default)]
+pub struct TriState {
+    pub value: bool,
+}
+
+impl FromDirectiveArguments for TriState {
+    const KEY: &'static str = "tri_state";
+
+    fn from_directive_args((key, value): &(String, Value)) -> Option<Result<Self, ErrorKind<'_>>> {
+        if key != Self::KEY {
+            return None;
+        }
+
+        let directive = (|| {
+            let value = value_as_bool(value)?;
+            Ok(Self { value })
+        })();
+
+        Some(directive)
+    }
+}
+
+#[derive(Debug, Default, Clone)]
+pub struct Min {
+    pub value: Option<f64>,
+}
+
+impl FromDirectiveArguments for Min {
+    const KEY: &'static str = "min";
+
+    fn from_directive_args((key, value): &(String, Value)) -> Option<Result<Self, ErrorKind<'_>>> {
+        if key != Self::KEY {
+            return None;
+        }
+
+        let directive = value_as_number(value).map(|value| Self { value: Some(value) });
+        Some(directive)
+    }
+}
+
+#[derive(Debug, Default, Clone)]
+pub struct Max {
+    pub value: Option<f64>,
+}
+
+impl FromDirectiveArguments for Max {
+    const KEY: &'static str = "max";
+
+    fn from_directive_args((key, value): &(String, Value)) -> Option<Result<Self, ErrorKind<'_>>> {
+        if key != Self::KEY {
+            return None;
+        }
+
+        let directive = value_as_number(value).map(|value| Self { value: Some(value) });
+        Some(directive)
+    }
+}
+
+#[derive(Debug, Default, Clone)]
+pub struct MaxLength {
+    pub value: Option<usize>,
+}
+
+impl FromDirectiveArguments for MaxLength {
+    const KEY: &'static str = "max_length";
+
+    fn from_directive_args((key, value): &(String, Value)) -> Option<Result<Self, ErrorKind<'_>>> {
+        if key != Self::KEY {
+            return None;
+        }
+
+        let directive = (|| {
+            let value = value_as_int(value)?;
+            if value < 0 {
+                return Err(ErrorKind::UnsupportedDirective(
+                    UnsupportedDirectiveKind::Validation(error::Validation::NegativeMaxLength(
+                        value,
+                    )),
+                ));
+            }
+            Ok(Self {
+                value: Some(value as usize),
+            })
+        })();
+
+        Some(directive)
+    }
+}
+
+#[derive(Debug, Default, Clone)]
+pub struct Pattern {
+    pub value: Option<String>,
+}
+
+impl FromDirectiveArguments for Pattern {
+    const KEY: &'static str = "pattern";
+
+    fn from_directive_args((key, value): &(String, Value)) -> Option<Result<Self, ErrorKind<'_>>> {
+        if key != Self::KEY {
+            return None;
+        }
+
+        let directive = (|| {
+            let pattern = value_as_string(value)?;
+            check_pattern(pattern)?;
+            Ok(Self {
+                value: Some(pattern.to_string()),
+            })
+        })();
+
+        Some(directive)
+    }
+}
+
+// Check the pattern now so invalid ones are compile errors rather than runtime errors
+#[cfg(feature = "regex")]
+fn check_pattern(pattern: &str) -> Result<(), ErrorKind<'_>> {
+    regex::Regex::new(pattern).map(|_| ()).map_err(|err| {
+        ErrorKind::UnsupportedDirective(UnsupportedDirectiveKind::Validation(
+            error::Validation::InvalidPattern(err.to_string()),
+        ))
+    })
+}
+
+#[cfg(not(feature = "regex"))]
+fn check_pattern(_: &str) -> Result<(), ErrorKind> {
+    Err(ErrorKind::FeatureNotEnabled {
+        usage: "`@juniper(pattern: \"...\")`".to_string(),
+        feature: "regex",
+    })
+}
+
+/// Whether to generate a constructor and setters for an input object.
+#[derive(Debug, Default)]
+pub struct Builder {
+    pub value: bool,
+}
+
+impl FromDirectiveArguments for Builder {
+    const KEY: &'static str = "builder";
+
+    fn from_directive_args((key, value): &(String, Value)) -> Option<Result<Self, ErrorKind<'_>>> {
         if key != Self::KEY {
             return None;
         }
@@ -220,37 +632,234 @@ impl FromDirectiveArguments for Infallible {
     }
 }
 
+/// Path to an existing Rust type that should be used instead of generating one.
+#[derive(Debug, Default, Clone)]
+pub struct RustType {
+    pub path: Option<syn::Path>,
+}
+
+impl FromDirectiveArguments for RustType {
+    const KEY: &'static str = "rust_type";
+
+    fn from_directive_args((key, value): &(String, Value)) -> Option<Result<Self, ErrorKind<'_>>> {
+        if key != Self::KEY {
+            return None;
+        }
+
+        let directive = (|| {
+            let path_raw = value_as_string(value)?;
+
+            let path = syn::parse_str::<syn::Path>(path_raw).map_err(|_| {
+                ErrorKind::UnsupportedDirective(UnsupportedDirectiveKind::RustType(
+                    error::RustType::InvalidPath(path_raw),
+                ))
+            })?;
+
+            Ok(Self { path: Some(path) })
+        })();
+        Some(directive)
+    }
+}
+
+/// Name of the Rust enum variant for a GraphQL enum value.
+#[derive(Debug, Default)]
+pub struct RustName {
+    pub name: Option<String>,
+}
+
+impl FromDirectiveArguments for RustName {
+    const KEY: &'static str = "rust_name";
+
+    fn from_directive_args((key, value): &(String, Value)) -> Option<Result<Self, ErrorKind<'_>>> {
+        if key != Self::KEY {
+            return None;
+        }
+
+        let directive = (|| {
+            let name = value_as_string(value)?;
+            Ok(Self {
+                name: Some(name.to_string()),
+            })
+        })();
+        Some(directive)
+    }
+}
+
 #[derive(Debug)]
 pub struct DateTimeScalarArguments {
     pub with_time_zone: bool,
+    pub backend: DateBackend,
 }
 
 impl Default for DateTimeScalarArguments {
     fn default() -> Self {
         DateTimeScalarArguments {
             with_time_zone: true,
+            backend: DateBackend::default(),
         }
     }
 }
 
-impl FromDirectiveArguments for DateTimeScalarArguments {
+#[derive(Debug)]
+pub struct WithTimeZone {
+    pub value: bool,
+}
+
+impl Default for WithTimeZone {
+    fn default() -> Self {
+        WithTimeZone { value: true }
+    }
+}
+
+impl FromDirectiveArguments for WithTimeZone {
     const KEY: &'static str = "with_time_zone";
 
-    fn from_directive_args((key, value): &(String, Value)) -> Option<Result<Self, ErrorKind>> {
+    fn from_directive_args((key, value): &(String, Value)) -> Option<Result<Self, ErrorKind<'_>>> {
         if key != Self::KEY {
             return None;
         }
 
         let directive = (|| {
-            let with_time_zone = value_as_bool(value)?;
-            Ok(Self { with_time_zone })
+            let value = value_as_bool(value)?;
+            Ok(Self { value })
         })();
 
         Some(directive)
     }
 }
 
-fn value_as_string(value: &Value) -> Result<&str, ErrorKind> {
+/// The crate providing the Rust types for the `Date` and `DateTimeUtc` scalars.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum DateBackend {
+    #[default]
+    Chrono,
+    Time,
+}
+
+impl DateBackend {
+    pub fn from_name(name: &str) -> Option<Self> {
+        match name {
+            "chrono" => Some(DateBackend::Chrono),
+            "time" => Some(DateBackend::Time),
+            _ => None,
+        }
+    }
+
+    pub fn required_feature(self) -> &'static str {
+        match self {
+            DateBackend::Chrono => "chrono",
+            DateBackend::Time => "time",
+        }
+    }
+}
+
+impl FromDirectiveArguments for DateBackend {
+    const KEY: &'static str = "backend";
+
+    fn from_directive_args((key, value): &(String, Value)) -> Option<Result<Self, ErrorKind<'_>>> {
+        if key != Self::KEY {
+            return None;
+        }
+
+        let directive = (|| {
+            let backend_raw = value_as_string(value)?;
+
+            DateBackend::from_name(backend_raw).ok_or(ErrorKind::UnsupportedDirective(
+                UnsupportedDirectiveKind::DateBackend(error::DateBackend::InvalidValue(
+                    backend_raw,
+                )),
+            ))
+        })();
+        Some(directive)
+    }
+}
+
+/// The Rust type a custom scalar wraps.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ScalarRepr {
+    #[default]
+    String,
+    I64,
+    BigInt,
+    F64,
+    Bool,
+    Json,
+    Decimal,
+    Duration,
+    NaiveTime,
+}
+
+impl ScalarRepr {
+    pub fn from_name(name: &str) -> Option<Self> {
+        match name {
+            "string" => Some(ScalarRepr::String),
+            "i64" => Some(ScalarRepr::I64),
+            "bigint" => Some(ScalarRepr::BigInt),
+            "f64" => Some(ScalarRepr::F64),
+            "bool" => Some(ScalarRepr::Bool),
+            "json" => Some(ScalarRepr::Json),
+            "decimal" => Some(ScalarRepr::Decimal),
+            "duration" => Some(ScalarRepr::Duration),
+            "naive_time" => Some(ScalarRepr::NaiveTime),
+            _ => None,
+        }
+    }
+
+    pub fn as_str(self) -> &'static str {
+        match self {
+            ScalarRepr::String => "string",
+            ScalarRepr::I64 => "i64",
+            ScalarRepr::BigInt => "bigint",
+            ScalarRepr::F64 => "f64",
+            ScalarRepr::Bool => "bool",
+            ScalarRepr::Json => "json",
+            ScalarRepr::Decimal => "decimal",
+            ScalarRepr::Duration => "duration",
+            ScalarRepr::NaiveTime => "naive_time",
+        }
+    }
+
+    /// The feature that pulls in the crate defining the Rust type, if it needs one.
+    pub fn required_feature(self) -> Option<&'static str> {
+        match self {
+            ScalarRepr::BigInt => Some("bigint"),
+            ScalarRepr::Json => Some("json"),
+            ScalarRepr::Decimal => Some("decimal"),
+            ScalarRepr::Duration => Some("duration"),
+            ScalarRepr::NaiveTime => Some("naive-time"),
+            _ => None,
+        }
+    }
+}
+
+impl FromDirectiveArguments for ScalarRepr {
+    const KEY: &'static str = "repr";
+
+    fn from_directive_args((key, value): &(String, Value)) -> Option<Result<Self, ErrorKind<'_>>> {
+        if key != Self::KEY {
+            return None;
+        }
+
+        let directive = (|| {
+            let repr_raw = value_as_string(value)?;
+
+            ScalarRepr::from_name(repr_raw).ok_or(ErrorKind::UnsupportedDirective(
+                UnsupportedDirectiveKind::ScalarRepr(error::ScalarRepr::InvalidValue(repr_raw)),
+            ))
+        })();
+        Some(directive)
+    }
+}
+
+#[derive(Debug, Default)]
+pub struct ScalarArguments {
+    /// `None` if the scalar doesn't specify a `repr`, meaning it depends on the name of the scalar.
+    pub repr: Option<ScalarRepr>,
+    /// Only supported on the `Date` scalar.
+    pub backend: Option<DateBackend>,
+}
+
+fn value_as_string(value: &Value) -> Result<&str, ErrorKind<'_>> {
     match value {
         Value::String(x) => Ok(x),
         other => Err(ErrorKind::UnsupportedDirective(
@@ -262,7 +871,33 @@ fn value_as_string(value: &Value) -> Result<&str, ErrorKind> {
     }
 }
 
-fn value_as_bool(value: &Value) -> Result<bool, ErrorKind> {
+fn value_as_int(value: &Value) -> Result<i64, ErrorKind<'_>> {
+    match value {
+        Value::Int(x) => Ok(x.as_i64().expect("GraphQL ints always fit in i64")),
+        other => Err(ErrorKind::UnsupportedDirective(
+            UnsupportedDirectiveKind::InvalidType {
+                expected: ValueType::Int,
+                actual: ValueType::from(other),
+            },
+        )),
+    }
+}
+
+// Ints are accepted as well since GraphQL allows using them where floats are expected.
+fn value_as_number(value: &Value) -> Result<f64, ErrorKind<'_>> {
+    match value {
+        Value::Int(x) => Ok(x.as_i64().expect("GraphQL ints always fit in i64") as f64),
+        Value::Float(x) => Ok(*x),
+        other => Err(ErrorKind::UnsupportedDirective(
+            UnsupportedDirectiveKind::InvalidType {
+                expected: ValueType::Float,
+                actual: ValueType::from(other),
+            },
+        )),
+    }
+}
+
+fn value_as_bool(value: &Value) -> Result<bool, ErrorKind<'_>> {
     match value {
         Value::Boolean(x) => Ok(*x),
         other => Err(ErrorKind::UnsupportedDirective(
@@ -284,45 +919,83 @@ impl<'doc> ParseDirective<&'doc Field> for CodeGenPass<'doc> {
     type Output = FieldArguments;
 
     fn parse_directives(&mut self, input: &'doc Field) -> Self::Output {
-        let mut ownership = Ownership::default();
+        let mut ownership = None::<Ownership>;
         let mut deprecated = None::<Deprecation>;
         let mut infallible = Infallible::default();
+        let mut guard = Guard::default();
 
         for dir in &input.directives {
-            if let Ok(juniper_directive) =
-                JuniperDirective::<(Ownership, Infallible)>::from_directive(dir)
-            {
-                ownership = juniper_directive.args.0;
-                infallible = juniper_directive.args.1;
+            if is_custom_directive(self, dir, &[DirectiveLocation::FieldDefinition]) {
                 continue;
             }
 
-            if let Ok(x) = Deprecation::from_directive(dir) {
-                deprecated = Some(x);
-                continue;
+            match &*dir.name {
+                "juniper" => {
+                    match JuniperDirective::<(Option<Ownership>, Infallible, Guard)>::from_directive(
+                        dir,
+                    ) {
+                        Ok(juniper_directive) => {
+                            ownership = juniper_directive.args.0;
+                            infallible = juniper_directive.args.1;
+                            guard = juniper_directive.args.2;
+                        }
+                        Err(err) => {
+                            self.emit_non_fatal_error(dir.position, err);
+                        }
+                    }
+                }
+                "deprecated" => match Deprecation::from_directive(dir) {
+                    Ok(x) => {
+                        deprecated = Some(x);
+                    }
+                    Err(err) => {
+                        self.emit_non_fatal_error(dir.position, err);
+                    }
+                },
+                _ => {
+                    self.emit_non_fatal_error(dir.position, ErrorKind::UnknownDirective(vec![]));
+                }
             }
-
-            self.emit_non_fatal_error(dir.position, ErrorKind::UnknownDirective(vec![]));
         }
 
         FieldArguments {
             ownership,
             deprecated,
             infallible,
+            guard,
         }
     }
 }
 
-impl<'doc> ParseDirective<&'doc EnumValue> for CodeGenPass<'doc> {
-    type Output = Deprecation;
+impl<'doc, T> ParseDirective<&'doc EnumValue> for T
+where
+    T: EmitError<'doc> + CustomDirectives<'doc>,
+{
+    type Output = EnumValueArguments;
 
     fn parse_directives(&mut self, input: &'doc EnumValue) -> Self::Output {
-        let mut deprecated = Deprecation::default();
+        let mut args = EnumValueArguments::default();
 
         for dir in &input.directives {
+            if is_custom_directive(self, dir, &[DirectiveLocation::EnumValue]) {
+                continue;
+            }
+
+            if dir.name == "juniper" {
+                match JuniperDirective::<RustName>::from_directive(dir) {
+                    Ok(x) => {
+                        args.rust_name = x.args;
+                    }
+                    Err(err) => {
+                        self.emit_non_fatal_error(dir.position, err);
+                    }
+                }
+                continue;
+            }
+
             match Deprecation::from_directive(dir) {
                 Ok(x) => {
-                    deprecated = x;
+                    args.deprecated = x;
                 }
                 Err(err) => {
                     self.emit_non_fatal_error(dir.position, err);
@@ -330,7 +1003,7 @@ impl<'doc> ParseDirective<&'doc EnumValue> for CodeGenPass<'doc> {
             }
         }
 
-        deprecated
+        args
     }
 }
 
@@ -339,7 +1012,7 @@ pub struct DateTimeScalarType<'a>(pub &'a ScalarType);
 
 impl<'doc, T> ParseDirective<DateTimeScalarType<'doc>> for T
 where
-    T: EmitError<'doc>,
+    T: EmitError<'doc> + CustomDirectives<'doc>,
 {
     type Output = DateTimeScalarArguments;
 
@@ -347,9 +1020,286 @@ where
         let mut args = DateTimeScalarArguments::default();
 
         for dir in &input.0.directives {
-            match JuniperDirective::<DateTimeScalarArguments>::from_directive(dir) {
+            if is_custom_directive(self, dir, &[DirectiveLocation::Scalar]) {
+                continue;
+            }
+
+            match JuniperDirective::<(WithTimeZone, DateBackend)>::from_directive(dir) {
                 Ok(x) => {
-                    args = x.args;
+                    let (with_time_zone, backend) = x.args;
+                    args.with_time_zone = with_time_zone.value;
+                    args.backend = backend;
+                }
+                Err(err) => {
+                    self.emit_non_fatal_error(dir.position, err);
+                }
+            }
+        }
+
+        args
+    }
+}
+
+impl<'doc, T> ParseDirective<&'doc ScalarType> for T
+where
+    T: EmitError<'doc> + CustomDirectives<'doc>,
+{
+    type Output = ScalarArguments;
+
+    fn parse_directives(&mut self, input: &'doc ScalarType) -> Self::Output {
+        let mut args = ScalarArguments::default();
+
+        for dir in &input.directives {
+            if is_custom_directive(self, dir, &[DirectiveLocation::Scalar]) {
+                continue;
+            }
+
+            match JuniperDirective::<(Option<ScalarRepr>, Option<DateBackend>)>::from_directive(dir)
+            {
+                Ok(x) => {
+                    let (repr, backend) = x.args;
+                    args.repr = repr;
+                    args.backend = backend;
+                }
+                Err(err) => {
+                    self.emit_non_fatal_error(dir.position, err);
+                }
+            }
+        }
+
+        args
+    }
+}
+
+// Directives declared in the schema are left alone, as long as they're used where their declaration
+// allows.
+fn is_custom_directive<'doc, T>(
+    pass: &mut T,
+    dir: &'doc Directive,
+    locations: &[DirectiveLocation],
+) -> bool
+where
+    T: EmitError<'doc> + CustomDirectives<'doc>,
+{
+    let definition = if let Some(definition) = pass.custom_directive(&dir.name) {
+        definition
+    } else {
+        return false;
+    };
+
+    if !locations
+        .iter()
+        .any(|location| definition.locations.contains(location))
+    {
+        pass.emit_non_fatal_error(
+            dir.position,
+            ErrorKind::DirectiveNotAllowedHere {
+                name: &dir.name,
+                location: locations
+                    .iter()
+                    .map(|location| location.as_str())
+                    .collect::<Vec<_>>()
+                    .join(" or "),
+                allowed: definition
+                    .locations
+                    .iter()
+                    .map(|location| location.as_str())
+                    .collect(),
+            },
+        );
+    }
+
+    true
+}
+
+fn parse_type_directives<'doc, T>(pass: &mut T, directives: &'doc [Directive]) -> TypeArguments
+where
+    T: EmitError<'doc> + CustomDirectives<'doc>,
+{
+    let mut args = TypeArguments::default();
+
+    for dir in directives {
+        if is_custom_directive(pass, dir, &[DirectiveLocation::Object]) {
+            continue;
+        }
+
+        match JuniperDirective::<(Guard, Option<Ownership>)>::from_directive(dir) {
+            Ok(x) => {
+                args.guard = x.args.0;
+                args.ownership = x.args.1;
+            }
+            Err(err) => {
+                pass.emit_non_fatal_error(dir.position, err);
+            }
+        }
+    }
+
+    args
+}
+
+impl<'doc, T> ParseDirective<&'doc ObjectType> for T
+where
+    T: EmitError<'doc> + CustomDirectives<'doc>,
+{
+    type Output = TypeArguments;
+
+    fn parse_directives(&mut self, input: &'doc ObjectType) -> Self::Output {
+        parse_type_directives(self, &input.directives)
+    }
+}
+
+impl<'doc, T> ParseDirective<&'doc InterfaceType> for T
+where
+    T: EmitError<'doc> + CustomDirectives<'doc>,
+{
+    type Output = TypeArguments;
+
+    fn parse_directives(&mut self, input: &'doc InterfaceType) -> Self::Output {
+        let mut args = TypeArguments::default();
+
+        for dir in &input.directives {
+            if is_custom_directive(self, dir, &[DirectiveLocation::Interface]) {
+                continue;
+            }
+
+            match JuniperDirective::<(Guard, Option<Ownership>, Payload)>::from_directive(dir) {
+                Ok(x) => {
+                    args.guard = x.args.0;
+                    args.ownership = x.args.1;
+                    args.payload = x.args.2;
+                }
+                Err(err) => {
+                    self.emit_non_fatal_error(dir.position, err);
+                }
+            }
+        }
+
+        args
+    }
+}
+
+impl<'doc, T> ParseDirective<&'doc EnumType> for T
+where
+    T: EmitError<'doc> + CustomDirectives<'doc>,
+{
+    type Output = EnumArguments;
+
+    fn parse_directives(&mut self, input: &'doc EnumType) -> Self::Output {
+        let mut args = EnumArguments::default();
+
+        for dir in &input.directives {
+            if is_custom_directive(self, dir, &[DirectiveLocation::Enum]) {
+                continue;
+            }
+
+            match JuniperDirective::<(Derive, Attributes, RustType)>::from_directive(dir) {
+                Ok(x) => {
+                    args.data_type.derive = x.args.0;
+                    args.data_type.attributes = x.args.1;
+                    args.rust_type = x.args.2;
+                }
+                Err(err) => {
+                    self.emit_non_fatal_error(dir.position, err);
+                }
+            }
+        }
+
+        args
+    }
+}
+
+impl<'doc, T> ParseDirective<&'doc UnionType> for T
+where
+    T: EmitError<'doc> + CustomDirectives<'doc>,
+{
+    type Output = UnionArguments;
+
+    fn parse_directives(&mut self, input: &'doc UnionType) -> Self::Output {
+        let mut args = UnionArguments::default();
+
+        for dir in &input.directives {
+            if is_custom_directive(self, dir, &[DirectiveLocation::Union]) {
+                continue;
+            }
+
+            match JuniperDirective::<(Payload, RustType)>::from_directive(dir) {
+                Ok(x) => {
+                    args.payload = x.args.0;
+                    args.rust_type = x.args.1;
+                }
+                Err(err) => {
+                    self.emit_non_fatal_error(dir.position, err);
+                }
+            }
+        }
+
+        if args.payload != Payload::Owned && args.rust_type.path.is_some() {
+            self.emit_non_fatal_error(input.position, ErrorKind::PayloadWithRustType);
+        }
+
+        args
+    }
+}
+
+impl<'doc> ParseDirective<&'doc InputObjectType> for CodeGenPass<'doc> {
+    type Output = InputObjectArguments;
+
+    fn parse_directives(&mut self, input: &'doc InputObjectType) -> Self::Output {
+        let mut args = InputObjectArguments::default();
+
+        for dir in &input.directives {
+            if is_custom_directive(self, dir, &[DirectiveLocation::InputObject]) {
+                continue;
+            }
+
+            match JuniperDirective::<(Derive, Attributes, Builder)>::from_directive(dir) {
+                Ok(x) => {
+                    args.data_type.derive = x.args.0;
+                    args.data_type.attributes = x.args.1;
+                    args.builder = x.args.2;
+                }
+                Err(err) => {
+                    self.emit_non_fatal_error(dir.position, err);
+                }
+            }
+        }
+
+        args
+    }
+}
+
+impl<'doc, T> ParseDirective<&'doc InputValue> for T
+where
+    T: EmitError<'doc> + CustomDirectives<'doc>,
+{
+    type Output = InputValueArguments;
+
+    fn parse_directives(&mut self, input: &'doc InputValue) -> Self::Output {
+        let mut args = InputValueArguments::default();
+
+        for dir in &input.directives {
+            if is_custom_directive(
+                self,
+                dir,
+                &[
+                    DirectiveLocation::ArgumentDefinition,
+                    DirectiveLocation::InputFieldDefinition,
+                ],
+            ) {
+                continue;
+            }
+
+            match JuniperDirective::<(TriState, Min, Max, MaxLength, Pattern)>::from_directive(dir)
+            {
+                Ok(x) => {
+                    let (tri_state, min, max, max_length, pattern) = x.args;
+                    args.tri_state = tri_state;
+                    args.validations = Validations {
+                        min,
+                        max,
+                        max_length,
+                        pattern,
+                    };
                 }
                 Err(err) => {
                     self.emit_non_fatal_error(dir.position, err);
@@ -362,12 +1312,16 @@ where
 }
 
 macro_rules! supports_no_directives {
-    ($ty:ty) => {
+    ($ty:ty, $location:expr) => {
         impl<'doc> ParseDirective<&'doc $ty> for CodeGenPass<'doc> {
             type Output = ();
 
             fn parse_directives(&mut self, input: &'doc $ty) -> Self::Output {
                 for directive in &input.directives {
+                    if is_custom_directive(self, directive, &[$location]) {
+                        continue;
+                    }
+
                     self.emit_non_fatal_error(
                         directive.position,
                         ErrorKind::UnknownDirective(vec![]),
@@ -378,11 +1332,42 @@ macro_rules! supports_no_directives {
     };
 }
 
-supports_no_directives!(SchemaDefinition);
-supports_no_directives!(ScalarType);
-supports_no_directives!(ObjectType);
-supports_no_directives!(InterfaceType);
-supports_no_directives!(UnionType);
-supports_no_directives!(EnumType);
-supports_no_directives!(InputObjectType);
-supports_no_directives!(InputValue);
+supports_no_directives!(SchemaDefinition, DirectiveLocation::Schema);
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn pattern_arg(pattern: &str) -> (String, Value) {
+        ("pattern".to_string(), Value::String(pattern.to_string()))
+    }
+
+    #[cfg(feature = "regex")]
+    #[test]
+    fn test_pattern() {
+        let arg = pattern_arg("^[a-z]+$");
+        let pattern = Pattern::from_directive_args(&arg).unwrap().unwrap();
+        assert_eq!(Some("^[a-z]+$".to_string()), pattern.value);
+
+        let arg = pattern_arg("[a-z");
+        match Pattern::from_directive_args(&arg).unwrap() {
+            Err(ErrorKind::UnsupportedDirective(UnsupportedDirectiveKind::Validation(
+                error::Validation::InvalidPattern(_),
+            ))) => {}
+            other => panic!("expected an invalid pattern error, got {:?}", other),
+        }
+    }
+
+    #[cfg(not(feature = "regex"))]
+    #[test]
+    fn test_pattern_without_regex_feature() {
+        let arg = pattern_arg("^[a-z]+$");
+        match Pattern::from_directive_args(&arg).unwrap() {
+            Err(ErrorKind::FeatureNotEnabled { usage, feature }) => {
+                assert_eq!("`@juniper(pattern: \"...\")`", usage);
+                assert_eq!("regex", feature);
+            }
+            other => panic!("expected a missing feature error, got {:?}", other),
+        }
+    }
+}
@@ -1,3 +1,4 @@
+use super::lints::Lint;
 use colored::*;
 use graphql_parser::{query::Value, Pos};
 use std::fmt::{self, Write};
@@ -135,13 +136,153 @@ impl<'doc> fmt::Display for Ownership<'doc> {
         match self {
             Self::InvalidValue(name) => write!(
                 f,
-                "Invalid value. Expected `owned`, `borrowed`, or `as_ref`, got `{}`",
+                "Invalid value. Expected `owned`, `borrowed`, `as_ref`, `arc`, `boxed`, or `cow`, got `{}`",
                 name
             ),
         }
     }
 }
 
+#[derive(Debug, Clone, Eq, PartialEq, Ord, PartialOrd)]
+pub enum Payload<'doc> {
+    InvalidValue(&'doc str),
+}
+
+impl<'doc> fmt::Display for Payload<'doc> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Self::InvalidValue(name) => write!(
+                f,
+                "Invalid value. Expected `owned`, `boxed`, `arc`, `rc`, or `cow`, got `{}`",
+                name
+            ),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Eq, PartialEq, Ord, PartialOrd)]
+pub enum DateBackend<'doc> {
+    InvalidValue(&'doc str),
+}
+
+impl<'doc> fmt::Display for DateBackend<'doc> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Self::InvalidValue(name) => write!(
+                f,
+                "Invalid value. Expected `chrono` or `time`, got `{}`",
+                name
+            ),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Eq, PartialEq, Ord, PartialOrd)]
+pub enum ScalarRepr<'doc> {
+    InvalidValue(&'doc str),
+}
+
+impl<'doc> fmt::Display for ScalarRepr<'doc> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Self::InvalidValue(name) => write!(
+                f,
+                "Invalid value. Expected `string`, `i64`, `bigint`, `f64`, `bool`, `json`, `decimal`, `duration`, or `naive_time`, got `{}`",
+                name
+            ),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Eq, PartialEq, Ord, PartialOrd)]
+pub enum Guard<'doc> {
+    InvalidExpression(&'doc str),
+}
+
+impl<'doc> fmt::Display for Guard<'doc> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Self::InvalidExpression(expr) => write!(
+                f,
+                "Invalid value. Expected a Rust expression, got `{}`",
+                expr
+            ),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Eq, PartialEq, Ord, PartialOrd)]
+pub enum Derive<'doc> {
+    InvalidPaths(&'doc str),
+}
+
+impl<'doc> fmt::Display for Derive<'doc> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Self::InvalidPaths(paths) => write!(
+                f,
+                "Invalid value. Expected a comma separated list of Rust paths, got `{}`",
+                paths
+            ),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Eq, PartialEq, Ord, PartialOrd)]
+pub enum Attributes<'doc> {
+    InvalidAttributes(&'doc str),
+}
+
+impl<'doc> fmt::Display for Attributes<'doc> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Self::InvalidAttributes(attrs) => write!(
+                f,
+                "Invalid value. Expected Rust outer attributes such as `#[foo]`, got `{}`",
+                attrs
+            ),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Eq, PartialEq, Ord, PartialOrd)]
+pub enum RustType<'doc> {
+    InvalidPath(&'doc str),
+}
+
+impl<'doc> fmt::Display for RustType<'doc> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Self::InvalidPath(path) => write!(
+                f,
+                "Invalid value. Expected a path to a Rust type, got `{}`",
+                path
+            ),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Eq, PartialEq, Ord, PartialOrd)]
+pub enum Validation {
+    NegativeMaxLength(i64),
+    #[cfg(feature = "regex")]
+    InvalidPattern(String),
+}
+
+impl fmt::Display for Validation {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Self::NegativeMaxLength(value) => write!(
+                f,
+                "Invalid value. Expected a non-negative `max_length`, got `{}`",
+                value
+            ),
+            #[cfg(feature = "regex")]
+            Self::InvalidPattern(err) => write!(f, "Invalid regular expression.\n{}", err),
+        }
+    }
+}
+
 #[derive(Debug, Clone, Eq, PartialEq, Ord, PartialOrd)]
 pub enum Juniper<'doc> {
     InvalidName(&'doc str),
@@ -155,10 +296,147 @@ impl<'doc> fmt::Display for Juniper<'doc> {
     }
 }
 
+#[derive(Debug, Clone, Eq, PartialEq, Ord, PartialOrd)]
+pub enum InvalidDefaultValue<'doc> {
+    TypeMismatch {
+        expected: String,
+        actual: ValueType,
+    },
+    IntOutOfRange(i64),
+    UnknownEnumValue {
+        enum_name: &'doc str,
+        value: &'doc str,
+    },
+    UnknownField {
+        input_object: &'doc str,
+        field: &'doc str,
+    },
+    MissingField {
+        input_object: &'doc str,
+        field: &'doc str,
+    },
+    Null,
+}
+
+impl<'doc> fmt::Display for InvalidDefaultValue<'doc> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Self::TypeMismatch { expected, actual } => {
+                write!(
+                    f,
+                    "Expected a value of type `{}`, got `{}`",
+                    expected, actual
+                )
+            }
+            Self::IntOutOfRange(number) => write!(
+                f,
+                "`{}` doesn't fit in an `Int`, which is a signed 32 bit integer",
+                number
+            ),
+            Self::UnknownEnumValue { enum_name, value } => {
+                write!(f, "`{}` isn't a value of the enum `{}`", value, enum_name)
+            }
+            Self::UnknownField {
+                input_object,
+                field,
+            } => write!(
+                f,
+                "`{}` isn't a field of the input object `{}`",
+                field, input_object
+            ),
+            Self::MissingField {
+                input_object,
+                field,
+            } => write!(
+                f,
+                "The non-null field `{}` of the input object `{}` is missing",
+                field, input_object
+            ),
+            Self::Null => write!(
+                f,
+                "Arguments are already `null` when they aren't given, so remove the default value"
+            ),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Eq, PartialEq, Ord, PartialOrd)]
+pub enum InvalidInterfaceImplementation<'doc> {
+    NotAnInterface(&'static str),
+    MissingField(&'doc str),
+    FieldTypeMismatch {
+        field: &'doc str,
+        expected: String,
+        actual: String,
+    },
+    MissingArgument {
+        field: &'doc str,
+        argument: &'doc str,
+    },
+    ArgumentTypeMismatch {
+        field: &'doc str,
+        argument: &'doc str,
+        expected: String,
+        actual: String,
+    },
+    ExtraArgument {
+        field: &'doc str,
+        argument: &'doc str,
+    },
+}
+
+impl<'doc> fmt::Display for InvalidInterfaceImplementation<'doc> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Self::NotAnInterface(kind) => {
+                write!(f, "Only interfaces can be implemented, not {}s", kind)
+            }
+            Self::MissingField(field) => write!(f, "The field `{}` is missing", field),
+            Self::FieldTypeMismatch {
+                field,
+                expected,
+                actual,
+            } => write!(
+                f,
+                "The field `{}` must be of type `{}`, not `{}`",
+                field, expected, actual
+            ),
+            Self::MissingArgument { field, argument } => write!(
+                f,
+                "The field `{}` is missing the argument `{}`",
+                field, argument
+            ),
+            Self::ArgumentTypeMismatch {
+                field,
+                argument,
+                expected,
+                actual,
+            } => write!(
+                f,
+                "The argument `{}` of the field `{}` must be of type `{}`, not `{}`",
+                argument, field, expected, actual
+            ),
+            Self::ExtraArgument { field, argument } => write!(
+                f,
+                "The field `{}` has the argument `{}` which the interface doesn't have",
+                field, argument
+            ),
+        }
+    }
+}
+
 #[derive(Debug, Clone, Eq, PartialEq, Ord, PartialOrd)]
 pub enum UnsupportedDirectiveKind<'doc> {
     Deprecation(Deprecation<'doc>),
     Ownership(Ownership<'doc>),
+    Payload(Payload<'doc>),
+    ScalarRepr(ScalarRepr<'doc>),
+    DateBackend(DateBackend<'doc>),
+    Guard(Guard<'doc>),
+    Derive(Derive<'doc>),
+    Attributes(Attributes<'doc>),
+    RustType(RustType<'doc>),
+    Validation(Validation),
     Juniper(Juniper<'doc>),
     InvalidType {
         actual: ValueType,
@@ -171,6 +449,14 @@ impl<'doc> fmt::Display for UnsupportedDirectiveKind<'doc> {
         match self {
             Self::Deprecation(inner) => write!(f, "{}", inner),
             Self::Ownership(inner) => write!(f, "{}", inner),
+            Self::Payload(inner) => write!(f, "{}", inner),
+            Self::ScalarRepr(inner) => write!(f, "{}", inner),
+            Self::DateBackend(inner) => write!(f, "{}", inner),
+            Self::Guard(inner) => write!(f, "{}", inner),
+            Self::Derive(inner) => write!(f, "{}", inner),
+            Self::Attributes(inner) => write!(f, "{}", inner),
+            Self::RustType(inner) => write!(f, "{}", inner),
+            Self::Validation(inner) => write!(f, "{}", inner),
             Self::Juniper(inner) => write!(f, "{}", inner),
             Self::InvalidType { expected, actual } => {
                 write!(f, "Invalid type. Expected `{}`, got `{}`", expected, actual)
@@ -186,6 +472,12 @@ pub enum ErrorKind<'doc> {
     UuidScalarNotDefined,
     UrlScalarNotDefined,
     SpecialCaseScalarWithDescription,
+    SpecialCaseScalarWithRepr,
+    BackendOnNonDateScalar,
+    FeatureNotEnabled {
+        usage: String,
+        feature: &'static str,
+    },
     UnsupportedDirective(UnsupportedDirectiveKind<'doc>),
     UnknownDirective(Vec<String>),
     NoQueryType,
@@ -201,10 +493,63 @@ pub enum ErrorKind<'doc> {
         field_type_b: &'doc str,
     },
     VariableDefaultValue,
+    InvalidDefaultValue(InvalidDefaultValue<'doc>),
+    VariableDirectiveArgument,
     InputTypeFieldWithDefaultValue,
     AsRefOwnershipForNamedType,
     FieldNameInSnakeCase,
     UppercaseUuidScalar,
+    InvalidEnumVariantName(String),
+    EnumVariantNameCollision {
+        name: String,
+        value_a: &'doc str,
+        value_b: &'doc str,
+    },
+    TriStateOnNonNullType,
+    TriStateWithDefaultValue,
+    UnsupportedValidation {
+        constraint: &'static str,
+        type_name: &'doc str,
+    },
+    PayloadWithRustType,
+    DirectiveNotAllowedHere {
+        name: &'doc str,
+        location: String,
+        allowed: Vec<&'static str>,
+    },
+    DirectiveDefinedMoreThanOnce(&'doc str),
+    TypeDefinedMoreThanOnce(&'doc str),
+    FieldDefinedMoreThanOnce(&'doc str),
+    ArgumentDefinedMoreThanOnce(&'doc str),
+    UnionMemberListedMoreThanOnce(&'doc str),
+    ReservedName(&'doc str),
+    UndefinedType(&'doc str),
+    InputObjectAsOutputType(&'doc str),
+    OutputTypeAsInputType {
+        name: &'doc str,
+        kind: &'static str,
+    },
+    UnionMemberNotObject {
+        name: &'doc str,
+        kind: &'static str,
+    },
+    RootTypeNotObject {
+        operation: &'static str,
+        kind: &'static str,
+    },
+    EmptyType {
+        kind: &'static str,
+        name: &'doc str,
+    },
+    InvalidInterfaceImplementation {
+        interface: &'doc str,
+        reason: InvalidInterfaceImplementation<'doc>,
+    },
+    UnsupportedInterfaceImplementation {
+        interface: &'doc str,
+        reason: InvalidInterfaceImplementation<'doc>,
+    },
+    DeniedLint(Lint<'doc>),
 }
 
 impl<'doc> ErrorKind<'doc> {
@@ -225,6 +570,15 @@ impl<'doc> ErrorKind<'doc> {
             ErrorKind::SpecialCaseScalarWithDescription => {
                 "Special case scalars don't support having descriptions because the Rust types are defined in external crates".to_string()
             }
+            ErrorKind::SpecialCaseScalarWithRepr => {
+                "Special case scalars don't support `repr` because the Rust types are defined in external crates".to_string()
+            }
+            ErrorKind::BackendOnNonDateScalar => {
+                "`backend` is only supported on the `Date` and `DateTimeUtc` scalars".to_string()
+            }
+            ErrorKind::FeatureNotEnabled { usage, feature } => {
+                format!("{} requires the `{}` feature", usage, feature)
+            }
             ErrorKind::UnsupportedDirective(_) => {
                 "Unsupported directive.".to_string()
             }
@@ -241,6 +595,12 @@ impl<'doc> ErrorKind<'doc> {
             ErrorKind::VariableDefaultValue => {
                 "Default arguments cannot refer to variables".to_string()
             }
+            ErrorKind::InvalidDefaultValue(_) => {
+                "Default value doesn't match the type of the argument".to_string()
+            }
+            ErrorKind::VariableDirectiveArgument => {
+                "Directive arguments cannot refer to variables".to_string()
+            }
             ErrorKind::TypeExtensionNotSupported => "Type extentions are not supported".to_string(),
             ErrorKind::UnionFieldTypeMismatch { union_name, .. } => format!(
                 "Error while generating `QueryTrail` for union `{}`",
@@ -259,6 +619,75 @@ impl<'doc> ErrorKind<'doc> {
             ErrorKind::UppercaseUuidScalar => {
                 "The UUID must be named `Uuid`".to_string()
             }
+            ErrorKind::InvalidEnumVariantName(name) => {
+                format!("`{}` is not a valid name for a Rust enum variant", name)
+            }
+            ErrorKind::EnumVariantNameCollision { name, .. } => {
+                format!("Multiple enum values would become the Rust variant `{}`", name)
+            }
+            ErrorKind::TriStateOnNonNullType => {
+                "@juniper(tri_state: true) is only supported on nullable types".to_string()
+            }
+            ErrorKind::TriStateWithDefaultValue => {
+                "@juniper(tri_state: true) is not supported on arguments with default values".to_string()
+            }
+            ErrorKind::UnsupportedValidation { constraint, type_name } => {
+                format!("`{}` is not supported on values of type `{}`", constraint, type_name)
+            }
+            ErrorKind::PayloadWithRustType => {
+                "Unions with a `rust_type` cannot have a `payload`".to_string()
+            }
+            ErrorKind::DirectiveNotAllowedHere { name, location, .. } => {
+                format!("`@{}` cannot be used on {}", name, location)
+            }
+            ErrorKind::DirectiveDefinedMoreThanOnce(name) => {
+                format!("`@{}` is defined more than once", name)
+            }
+            ErrorKind::TypeDefinedMoreThanOnce(name) => {
+                format!("The type `{}` is defined more than once", name)
+            }
+            ErrorKind::FieldDefinedMoreThanOnce(name) => {
+                format!("The field `{}` is defined more than once", name)
+            }
+            ErrorKind::ArgumentDefinedMoreThanOnce(name) => {
+                format!("The argument `{}` is defined more than once", name)
+            }
+            ErrorKind::UnionMemberListedMoreThanOnce(name) => {
+                format!("`{}` is listed more than once", name)
+            }
+            ErrorKind::ReservedName(name) => {
+                format!("The name `{}` is reserved", name)
+            }
+            ErrorKind::UndefinedType(name) => {
+                format!("Unknown type `{}`", name)
+            }
+            ErrorKind::InputObjectAsOutputType(name) => {
+                format!("The input object `{}` cannot be used as the type of a field", name)
+            }
+            ErrorKind::OutputTypeAsInputType { name, kind } => {
+                format!("The {} `{}` cannot be used as the type of an argument or input field", kind, name)
+            }
+            ErrorKind::UnionMemberNotObject { name, kind } => {
+                format!("Union members must be object types but `{}` is {} {}", name, article(kind), kind)
+            }
+            ErrorKind::RootTypeNotObject { operation, kind } => {
+                format!("The {} type must be an object type, not {} {}", operation, article(kind), kind)
+            }
+            ErrorKind::EmptyType { kind, name } => {
+                let item = match *kind {
+                    "union" => "member",
+                    "enum" => "value",
+                    _ => "field",
+                };
+                format!("The {} `{}` must have at least one {}", kind, name, item)
+            }
+            ErrorKind::InvalidInterfaceImplementation { interface, .. } => {
+                format!("Invalid implementation of `{}`", interface)
+            }
+            ErrorKind::UnsupportedInterfaceImplementation { interface, .. } => {
+                format!("Unsupported implementation of `{}`", interface)
+            }
+            ErrorKind::DeniedLint(lint) => format!("{}", lint),
         }
     }
 
@@ -280,6 +709,10 @@ impl<'doc> ErrorKind<'doc> {
 
                 Some(f)
             }
+            ErrorKind::FeatureNotEnabled { feature, .. } => Some(format!(
+                "Enable it with `features = [\"{}\"]` for juniper-from-schema in your Cargo.toml",
+                feature
+            )),
             ErrorKind::DateTimeScalarNotDefined => {
                 Some("Insert `scalar DateTimeUtc` into your schema".to_string())
             }
@@ -303,6 +736,9 @@ impl<'doc> ErrorKind<'doc> {
             ErrorKind::UnsupportedDirective(reason) => {
                 Some(format!("{}", reason))
             }
+            ErrorKind::InvalidDefaultValue(reason) => {
+                Some(format!("{}", reason))
+            }
             ErrorKind::UnknownDirective(suggestions) => {
                 if suggestions.is_empty() {
                     None
@@ -313,11 +749,58 @@ impl<'doc> ErrorKind<'doc> {
             ErrorKind::UppercaseUuidScalar => {
                 Some("This is to be consistent with the naming the \"uuid\" crate".to_string())
             }
+            ErrorKind::InvalidEnumVariantName(_) => {
+                Some("It might be a Rust keyword. Use `@juniper(rust_name: \"...\")` to pick another name".to_string())
+            }
+            ErrorKind::EnumVariantNameCollision { value_a, value_b, .. } => {
+                Some(format!("`{}` and `{}` collide. Use `@juniper(rust_name: \"...\")` to rename one of them", value_a, value_b))
+            }
+            ErrorKind::TriStateWithDefaultValue => {
+                Some("Arguments with default values are never absent".to_string())
+            }
+            ErrorKind::PayloadWithRustType => {
+                Some("The Rust type isn't generated so set how its variants are stored in its definition instead".to_string())
+            }
+            ErrorKind::DirectiveNotAllowedHere { allowed, .. } => {
+                Some(format!("It is declared for {}", allowed.join(", ")))
+            }
+            ErrorKind::TypeDefinedMoreThanOnce(name) if BUILT_IN_SCALARS.contains(name) => {
+                Some(format!("`{}` is a built in scalar", name))
+            }
+            ErrorKind::ReservedName(_) => {
+                Some("Names starting with `__` are reserved for introspection".to_string())
+            }
+            ErrorKind::InvalidInterfaceImplementation { reason, .. } => {
+                Some(format!("{}", reason))
+            }
+            ErrorKind::UnsupportedInterfaceImplementation { reason, .. } => {
+                let mut f = String::new();
+                writeln!(f, "{}", reason);
+                writeln!(f);
+                writeln!(f, "This is valid GraphQL but interface fields are resolved by calling the method");
+                writeln!(f, "on the object, so the types and arguments must be exactly the same");
+                Some(f)
+            }
+            ErrorKind::DeniedLint(lint) => {
+                Some(format!("`{}` is set to deny with `lints: [{}: deny]`", lint.name(), lint.name()))
+            }
+            ErrorKind::UnsupportedValidation { .. } => {
+                Some("`min` and `max` are supported on `Int` and `Float`.\n`max_length` and `pattern` are supported on `String` and `ID`".to_string())
+            }
             _ => None,
         }
     }
 }
 
+pub(super) const BUILT_IN_SCALARS: &[&str] = &["Int", "Float", "String", "Boolean", "ID"];
+
+fn article(word: &str) -> &'static str {
+    match word.chars().next() {
+        Some('a') | Some('e') | Some('i') | Some('o') | Some('u') => "an",
+        _ => "a",
+    }
+}
+
 trait Indent {
     fn indent(&self, size: usize) -> String;
 }
@@ -0,0 +1,97 @@
+use std::{collections::HashMap, fmt};
+
+/// Every lint that can be configured with `lints: [name: level]`.
+pub const LINT_NAMES: &[&str] = &[
+    "missing_descriptions",
+    "deprecated_without_reason",
+    "nullable_list_of_nullable_items",
+    "non_screaming_case_enum_values",
+    "unreachable_types",
+];
+
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub enum LintLevel {
+    Allow,
+    Warn,
+    Deny,
+}
+
+impl LintLevel {
+    pub fn from_name(name: &str) -> Option<Self> {
+        match name {
+            "allow" => Some(LintLevel::Allow),
+            "warn" => Some(LintLevel::Warn),
+            "deny" => Some(LintLevel::Deny),
+            _ => None,
+        }
+    }
+}
+
+/// The levels set with `lints: [...]`. Lints that aren't set are allowed.
+#[derive(Debug, Clone, Default)]
+pub struct LintLevels {
+    levels: HashMap<&'static str, LintLevel>,
+}
+
+impl LintLevels {
+    /// Unknown names are ignored since they're rejected when parsing the config.
+    pub fn set(&mut self, name: &str, level: LintLevel) {
+        if let Some(name) = LINT_NAMES.iter().find(|lint_name| **lint_name == name) {
+            self.levels.insert(name, level);
+        }
+    }
+
+    pub fn level(&self, lint: &Lint<'_>) -> LintLevel {
+        self.levels
+            .get(lint.name())
+            .copied()
+            .unwrap_or(LintLevel::Allow)
+    }
+}
+
+#[derive(Debug, Clone, Eq, PartialEq, Ord, PartialOrd)]
+pub enum Lint<'doc> {
+    MissingDescription { kind: &'static str, name: String },
+    DeprecatedWithoutReason(String),
+    NullableListOfNullableItems(String),
+    NonScreamingCaseEnumValue(&'doc str),
+    UnreachableType(&'doc str),
+}
+
+impl<'doc> Lint<'doc> {
+    pub fn name(&self) -> &'static str {
+        match self {
+            Lint::MissingDescription { .. } => "missing_descriptions",
+            Lint::DeprecatedWithoutReason(_) => "deprecated_without_reason",
+            Lint::NullableListOfNullableItems(_) => "nullable_list_of_nullable_items",
+            Lint::NonScreamingCaseEnumValue(_) => "non_screaming_case_enum_values",
+            Lint::UnreachableType(_) => "unreachable_types",
+        }
+    }
+}
+
+impl<'doc> fmt::Display for Lint<'doc> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Lint::MissingDescription { kind, name } => {
+                write!(f, "The {} `{}` doesn't have a description", kind, name)
+            }
+            Lint::DeprecatedWithoutReason(name) => {
+                write!(f, "`{}` is deprecated without a reason", name)
+            }
+            Lint::NullableListOfNullableItems(type_) => write!(
+                f,
+                "`{}` is a nullable list of nullable items. Consider making one of them non-null",
+                type_
+            ),
+            Lint::NonScreamingCaseEnumValue(value) => {
+                write!(f, "The enum value `{}` should be in SCREAMING_CASE", value)
+            }
+            Lint::UnreachableType(name) => write!(
+                f,
+                "The type `{}` can't be reached from the query or mutation type",
+                name
+            ),
+        }
+    }
+}
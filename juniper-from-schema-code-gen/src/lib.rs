@@ -19,7 +19,13 @@ mod parse_input;
 mod pretty_print;
 
 use self::{
-    ast_pass::{ast_data_pass::AstData, error::Error, CodeGenPass},
+    ast_pass::{
+        ast_data_pass::AstData,
+        directive_parsing::{DataTypeArguments, Ownership},
+        error::Error,
+        lints::LintLevels,
+        CodeGenPass,
+    },
     parse_input::{default_context_type, default_error_type, GraphqlSchemaFromFileInput},
 };
 use graphql_parser::parse_schema;
@@ -45,7 +51,15 @@ pub fn graphql_schema_from_file(input: proc_macro::TokenStream) -> proc_macro::T
 
     match std::fs::read_to_string(&parsed.schema_path) {
         Ok(schema) => {
-            let mut tokens = parse_and_gen_schema(&schema, parsed.error_type, parsed.context_type);
+            let mut tokens = parse_and_gen_schema(
+                &schema,
+                parsed.error_type,
+                parsed.context_type,
+                parsed.middleware,
+                parsed.default_ownership,
+                parsed.data_type_arguments,
+                parsed.lint_levels,
+            );
             include_literal_schema(&mut tokens, &parsed.schema_path);
             tokens
         }
@@ -103,13 +117,25 @@ fn include_literal_schema(tokens: &mut proc_macro::TokenStream, schema_path: &Pa
 pub fn graphql_schema(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
     let input: TokenStream = input.into();
     let schema = input.to_string();
-    parse_and_gen_schema(&schema, default_error_type(), default_context_type())
+    parse_and_gen_schema(
+        &schema,
+        default_error_type(),
+        default_context_type(),
+        None,
+        Ownership::default(),
+        DataTypeArguments::default(),
+        LintLevels::default(),
+    )
 }
 
 fn parse_and_gen_schema(
     schema: &str,
     error_type: Type,
     context_type: Type,
+    middleware: Option<Type>,
+    default_ownership: Ownership,
+    data_type_arguments: DataTypeArguments,
+    lint_levels: LintLevels,
 ) -> proc_macro::TokenStream {
     let doc = match parse_schema(&schema) {
         Ok(doc) => doc,
@@ -121,7 +147,16 @@ fn parse_and_gen_schema(
         Err(errors) => print_and_panic_if_errors(errors),
     };
 
-    let output = CodeGenPass::new(schema, error_type, context_type, ast_data);
+    let output = CodeGenPass::new(
+        schema,
+        error_type,
+        context_type,
+        middleware,
+        default_ownership,
+        data_type_arguments,
+        lint_levels,
+        ast_data,
+    );
 
     match output.gen_juniper_code(&doc) {
         Ok(tokens) => {
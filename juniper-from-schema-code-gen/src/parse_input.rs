@@ -1,8 +1,15 @@
+use crate::ast_pass::{
+    directive_parsing::{Attributes, DataTypeArguments, Derive, Ownership},
+    lints::{LintLevel, LintLevels, LINT_NAMES},
+};
 use proc_macro2::Span;
+use quote::ToTokens;
 use std::{collections::HashMap, fmt::Write, path::PathBuf};
 use syn::{
-    self,
+    self, bracketed,
     parse::{Parse, ParseStream},
+    punctuated::Punctuated,
+    spanned::Spanned,
     Ident, Token, Type,
 };
 
@@ -11,6 +18,10 @@ pub struct GraphqlSchemaFromFileInput {
     pub schema_path: PathBuf,
     pub error_type: Type,
     pub context_type: Type,
+    pub middleware: Option<Type>,
+    pub default_ownership: Ownership,
+    pub data_type_arguments: DataTypeArguments,
+    pub lint_levels: LintLevels,
 }
 
 impl Parse for GraphqlSchemaFromFileInput {
@@ -26,32 +37,57 @@ impl Parse for GraphqlSchemaFromFileInput {
         }
 
         let mut configs = input
-            .parse_terminated::<_, Token![,]>(TypeConfig::parse)?
+            .parse_terminated::<_, Token![,]>(Config::parse)?
             .into_pairs()
             .map(|pair| {
                 let config = pair.into_value();
                 (
                     config.ident.to_string(),
-                    (config.type_, config.ident.span()),
+                    (config.value, config.ident.span()),
                 )
             })
-            .collect::<HashMap<String, (Type, Span)>>();
+            .collect::<HashMap<String, (ConfigValue, Span)>>();
+
+        let mut remove_type = |name: &str| {
+            configs.remove(name).map(|(value, _)| match value {
+                ConfigValue::Type(t) => *t,
+                _ => unreachable!("`{}` is always parsed as a type", name),
+            })
+        };
+
+        let error_type = remove_type("error_type").unwrap_or_else(default_error_type);
+
+        let context_type = remove_type("context_type").unwrap_or_else(default_context_type);
+
+        let middleware = remove_type("middleware");
+
+        let default_ownership = match remove_type("default_ownership") {
+            Some(t) => parse_ownership(&t)?,
+            None => Ownership::default(),
+        };
 
-        let error_type = configs
-            .remove("error_type")
-            .map(|(t, _)| t)
-            .unwrap_or_else(default_error_type);
+        let mut data_type_arguments = DataTypeArguments::default();
+        if let Some((ConfigValue::Derive(derive), _)) = configs.remove("derive") {
+            data_type_arguments.derive = derive;
+        }
+        if let Some((ConfigValue::Attributes(attributes), _)) = configs.remove("attributes") {
+            data_type_arguments.attributes = attributes;
+        }
 
-        let context_type = configs
-            .remove("context_type")
-            .map(|(t, _)| t)
-            .unwrap_or_else(default_context_type);
+        let lint_levels = match configs.remove("lints") {
+            Some((ConfigValue::Lints(lint_levels), _)) => lint_levels,
+            _ => LintLevels::default(),
+        };
 
         #[allow(clippy::never_loop)]
         for (name, (_, span)) in configs {
             let mut msg = String::new();
             writeln!(msg, "Unknown `graphql_schema_from_file` config `{}`", name).unwrap();
-            writeln!(msg, "Supported configs are `error_type` and `context_type`").unwrap();
+            writeln!(
+                msg,
+                "Supported configs are `error_type`, `context_type`, `middleware`, `default_ownership`, `derive`, `attributes`, and `lints`"
+            )
+            .unwrap();
             return Err(syn::parse::Error::new(span, msg));
         }
 
@@ -59,10 +95,26 @@ impl Parse for GraphqlSchemaFromFileInput {
             schema_path,
             error_type,
             context_type,
+            middleware,
+            default_ownership,
+            data_type_arguments,
+            lint_levels,
         })
     }
 }
 
+fn parse_ownership(type_: &Type) -> syn::Result<Ownership> {
+    let name = type_.to_token_stream().to_string();
+
+    Ownership::from_name(&name).ok_or_else(|| {
+        let msg = format!(
+            "Invalid `default_ownership` `{}`. Expected `owned`, `borrowed`, `as_ref`, `arc`, `boxed`, or `cow`",
+            name
+        );
+        syn::parse::Error::new(type_.span(), msg)
+    })
+}
+
 pub fn default_error_type() -> Type {
     syn::parse_str("juniper::FieldError").expect("Failed to parse default error type")
 }
@@ -71,16 +123,84 @@ pub fn default_context_type() -> Type {
     syn::parse_str("Context").expect("Failed to parse default context type")
 }
 
-struct TypeConfig {
+struct Config {
     ident: Ident,
-    type_: Type,
+    value: ConfigValue,
+}
+
+enum ConfigValue {
+    Type(Box<Type>),
+    Derive(Derive),
+    Attributes(Attributes),
+    Lints(LintLevels),
 }
 
-impl Parse for TypeConfig {
+impl Parse for Config {
     fn parse(input: ParseStream) -> syn::Result<Self> {
         let ident = input.parse::<syn::Ident>()?;
         input.parse::<Token![:]>()?;
-        let type_ = input.parse::<Type>()?;
-        Ok(TypeConfig { ident, type_ })
+
+        let value = if ident == "derive" {
+            let content;
+            bracketed!(content in input);
+            let paths = Punctuated::<syn::Path, Token![,]>::parse_terminated(&content)?;
+            ConfigValue::Derive(Derive {
+                paths: paths.into_iter().collect(),
+            })
+        } else if ident == "attributes" {
+            let content;
+            bracketed!(content in input);
+            let attrs = content.call(syn::Attribute::parse_outer)?;
+            ConfigValue::Attributes(Attributes { attrs })
+        } else if ident == "lints" {
+            let content;
+            bracketed!(content in input);
+            let lints = Punctuated::<LintConfig, Token![,]>::parse_terminated(&content)?;
+            let mut lint_levels = LintLevels::default();
+            for lint in lints {
+                lint_levels.set(&lint.name.to_string(), lint.level);
+            }
+            ConfigValue::Lints(lint_levels)
+        } else {
+            ConfigValue::Type(Box::new(input.parse::<Type>()?))
+        };
+
+        Ok(Config { ident, value })
+    }
+}
+
+struct LintConfig {
+    name: Ident,
+    level: LintLevel,
+}
+
+impl Parse for LintConfig {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let name = input.parse::<Ident>()?;
+        if !LINT_NAMES.contains(&&*name.to_string()) {
+            let msg = format!(
+                "Unknown lint `{}`. Expected one of {}",
+                name,
+                LINT_NAMES
+                    .iter()
+                    .map(|name| format!("`{}`", name))
+                    .collect::<Vec<_>>()
+                    .join(", ")
+            );
+            return Err(syn::parse::Error::new(name.span(), msg));
+        }
+
+        input.parse::<Token![:]>()?;
+
+        let level_ident = input.parse::<Ident>()?;
+        let level = LintLevel::from_name(&level_ident.to_string()).ok_or_else(|| {
+            let msg = format!(
+                "Invalid lint level `{}`. Expected `allow`, `warn`, or `deny`",
+                level_ident
+            );
+            syn::parse::Error::new(level_ident.span(), msg)
+        })?;
+
+        Ok(LintConfig { name, level })
     }
 }
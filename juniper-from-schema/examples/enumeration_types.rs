@@ -17,7 +17,7 @@ graphql_schema! {
     }
 
     type Query {
-        allPosts(status: STATUS!): [Post!]! @juniper(ownership: "owned")
+        allPosts(status: Status!): [Post!]! @juniper(ownership: "owned")
     }
 
     type Post {
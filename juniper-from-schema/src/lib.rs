@@ -21,6 +21,11 @@
 //! - [Supported schema directives](#supported-schema-directives)
 //!     - [Customizing ownership](#customizing-ownership)
 //!     - [Infallible fields](#infallible-fields)
+//!     - [Guarding fields](#guarding-fields)
+//!     - [Derives and attributes](#derives-and-attributes)
+//!     - [Distinguishing null from absent values](#distinguishing-null-from-absent-values)
+//!     - [Validating input](#validating-input)
+//!     - [Custom directives](#custom-directives)
 //! - [GraphQL to Rust types](#graphql-to-rust-types)
 //! - [Query trails](#query-trails)
 //!     - [Abbreviated example](#abbreviated-example)
@@ -29,6 +34,10 @@
 //!     - [`QueryTrail`s for fields that take arguments](#querytrails-for-fields-that-take-arguments)
 //! - [Customizing the error type](#customizing-the-error-type)
 //! - [Customizing the context type](#customizing-the-context-type)
+//! - [Field middleware](#field-middleware)
+//!     - [Tracing](#tracing)
+//! - [Serde support](#serde-support)
+//! - [Schema lints](#schema-lints)
 //! - [Inspecting the generated code](#inspecting-the-generated-code)
 //!
 //! # Example
@@ -221,11 +230,20 @@
 //! - Unions
 //! - Input objects
 //! - Enumeration types
+//! - Schemas without a `schema { ... }` definition. The object types named `Query` and `Mutation`
+//!   are used as the roots, like the spec says
 //!
 //! Not supported yet:
 //! - Subscriptions (will be supported once Juniper supports subscriptions)
 //! - Type extensions
 //!
+//! The schema is validated according to the [type system rules][] from the spec before any code
+//! is generated. Types or fields defined more than once, unknown types, input objects used as
+//! the type of fields, object types used as the type of arguments, empty unions, and objects that
+//! don't implement their interfaces are reported as errors pointing at the schema.
+//!
+//! [type system rules]: https://spec.graphql.org/June2018/#sec-Type-System
+//!
 //! ## The `ID` type
 //!
 //! The `ID` GraphQL type will be generated into [`juniper::ID`].
@@ -246,6 +264,33 @@
 //! pub struct Cursor(pub String);
 //! ```
 //!
+//! Scalars that aren't strings can wrap another type with `@juniper(repr: "...")`:
+//!
+//! ```graphql
+//! scalar Cents @juniper(repr: "i64")
+//! scalar Ratio @juniper(repr: "f64")
+//! scalar Enabled @juniper(repr: "bool")
+//! scalar Metadata @juniper(repr: "json")
+//! ```
+//!
+//! Would result in
+//!
+//! ```ignore
+//! pub struct Cents(pub i64);
+//! pub struct Ratio(pub f64);
+//! pub struct Enabled(pub bool);
+//! pub struct Metadata(pub serde_json::Value);
+//! ```
+//!
+//! GraphQL integers are 32 bits, so `i64` values that don't fit are sent as floats and accepted
+//! as input either way. Floats only represent integers up to 2<sup>53</sup> exactly, so larger
+//! input is an error and larger output loses precision. Use `bigint` if you need those.
+//! `json` requires the `json` feature. `bigint`, `decimal`, `duration`, and `naive_time` are also
+//! supported and require their features, as described in
+//! [special case scalars](#special-case-scalars). Juniper only accepts scalar values as
+//! input for custom scalars, so `json` scalars can return objects and lists but only take strings,
+//! numbers, and booleans as arguments.
+//!
 //! ## Special case scalars
 //!
 //! A couple of scalar names have special meaning. Those are:
@@ -259,6 +304,30 @@
 //! - `DateTimeUtc` becomes [`chrono::DateTime<chrono::offset::Utc>`] by default but if defined with
 //! `scalar DateTimeUtc @juniper(with_time_zone: false)` it will become [`chrono::naive::NaiveDateTime`].
 //!
+//! Those scalars require the `url`, `uuid`, and `chrono` features, which are enabled by default.
+//! If you don't use some of them you can turn off the default features so you don't have to
+//! depend on those crates:
+//!
+//! ```toml
+//! juniper-from-schema = { version = "0.5", default-features = false, features = ["uuid"] }
+//! ```
+//!
+//! Defining one of the scalars without its feature enabled is an error. Turning off a feature
+//! also turns off the matching Juniper feature.
+//!
+//! `Date` and `DateTimeUtc` can use the [time](https://docs.rs/time/0.3) crate instead of chrono
+//! with `@juniper(backend: "time")`, which requires the `time` feature:
+//!
+//! ```graphql
+//! scalar Date @juniper(backend: "time")
+//! scalar DateTimeUtc @juniper(backend: "time")
+//! ```
+//!
+//! Juniper doesn't support the time crate, so those scalars become newtypes that deref to the
+//! time types: `pub struct Date(pub time::Date)` and `pub struct DateTimeUtc(pub
+//! time::OffsetDateTime)`, or `time::PrimitiveDateTime` with `with_time_zone: false`. They use
+//! the same formats as the chrono types.
+//!
 //! Juniper doesn't support [`chrono::Date`](https://docs.rs/chrono/0.4.9/chrono/struct.Date.html)
 //! so therefore this library cannot support that either. You can read about Juniper's supported
 //! integrations [here](https://docs.rs/juniper/0.13.1/juniper/integrations/index.html).
@@ -266,6 +335,29 @@
 //! [`chrono::DateTime<chrono::offset::Utc>`]: https://docs.rs/chrono/0.4.9/chrono/struct.DateTime.html
 //! [`chrono::naive::NaiveDateTime`]: https://docs.rs/chrono/0.4.9/chrono/naive/struct.NaiveDateTime.html
 //!
+//! Some more `repr`s are available when their feature is enabled:
+//!
+//! | `repr`       | Feature      | Wraps                        | Sent as                      |
+//! |--------------|--------------|------------------------------|------------------------------|
+//! | `json`       | `json`       | `serde_json::Value`          | Any JSON value               |
+//! | `decimal`    | `decimal`    | `rust_decimal::Decimal`      | String, such as `"12.50"`    |
+//! | `bigint`     | `bigint`     | `i64`                        | String, such as `"9007199254740993"` |
+//! | `duration`   | `duration`   | `std::time::Duration`        | ISO 8601 string, such as `"PT1H30M"` |
+//! | `naive_time` | `naive-time` | `chrono::NaiveTime`          | String, such as `"09:30:00"` |
+//!
+//! Enabling a feature doesn't change any scalars by itself, so scalars have to opt in:
+//!
+//! ```graphql
+//! scalar Decimal @juniper(repr: "decimal")
+//! scalar BigInt @juniper(repr: "bigint")
+//! ```
+//!
+//! Since these are custom scalars the Rust type is a newtype named after the scalar, for example
+//! `pub struct Decimal(pub rust_decimal::Decimal)`, which derefs to the wrapped type. A scalar
+//! named `JSON` becomes `Json`, like any other type name. `decimal` and `bigint` accept numbers as
+//! input as well. `duration` supports weeks, days, hours, minutes, and seconds, since years and
+//! months don't have a fixed length.
+//!
 //! ## Interfaces
 //!
 //! Juniper has several ways of representing GraphQL interfaces in Rust. They are listed
@@ -274,6 +366,11 @@
 //!
 //! For the generated code we use the `enum` pattern because we found it to be the most flexible.
 //!
+//! Fields on the interface are resolved by calling the corresponding method on the object, so the
+//! object's fields must have exactly the same types and arguments as the interface's. This is
+//! stricter than the spec, which allows objects to return more specific types and to take extra
+//! optional arguments. Such implementations are reported as unsupported rather than invalid.
+//!
 //! Abbreviated example (find [complete example here](https://github.com/davidpdrsn/juniper-from-schema/blob/master/examples/interface.rs)):
 //!
 //! ```
@@ -357,6 +454,9 @@
 //! The enum that gets generated has variants for each type that implements the interface and also
 //! implements `From<T>` for each type.
 //!
+//! Like [union types](#union-types) the variants can store their types in other ways with
+//! `@juniper(payload: "...")`.
+//!
 //! ## Union types
 //!
 //! Union types are basically just interfaces so they work in very much the same way.
@@ -438,6 +538,44 @@
 //! }
 //! ```
 //!
+//! Each variant stores its type by value. Use `@juniper(payload: "...")` to store them
+//! differently. This also works for interfaces:
+//!
+//! - `owned`: `Tree::Leaf(Leaf)`. This is the default.
+//! - `boxed`: `Tree::Leaf(Box<Leaf>)`. Keeps the enum small and allows types that contain the union
+//!   itself.
+//! - `arc`: `Tree::Leaf(Arc<Leaf>)`. For sharing values between threads.
+//! - `rc`: `Tree::Leaf(Rc<Leaf>)`. For sharing values within a thread.
+//! - `cow`: `Tree::Leaf(Cow<'a, Leaf>)`. For returning data borrowed from elsewhere, such as
+//!   `&self`. The enum takes a lifetime, as in `Tree<'a>`, and every type in it must implement
+//!   `Clone`.
+//!
+//! ```graphql
+//! union Tree @juniper(payload: "boxed") = Leaf | Branch
+//!
+//! type Branch {
+//!     left: Tree!
+//!     right: Tree!
+//! }
+//! ```
+//!
+//! Besides `From<Leaf>` a `From<Box<Leaf>>` implementation is generated for each variant, and
+//! likewise for `Arc` and `Rc`. `cow` payloads implement `From<&'a Leaf>`. The lifetime can be
+//! left out of `QueryTrail`s, such as `QueryTrail<'_, Tree, Walked>`.
+//!
+//! You can also use an existing Rust enum with `@juniper(rust_type: "...")`:
+//!
+//! ```graphql
+//! union SearchResult @juniper(rust_type: "domain::SearchResult") = Article | Tweet
+//! ```
+//!
+//! The enum must have one variant for each type in the union, named like the type, such as
+//! `SearchResult::Article(_)`. The variants can hold the type itself or anything that implements
+//! [`Borrow`](https://doc.rust-lang.org/std/borrow/trait.Borrow.html) for it, such as
+//! `Box<Article>` or `Arc<Article>`. If the path isn't just the name of the union a type alias
+//! with that name is generated. Since Juniper's traits are implemented for it the enum must be
+//! defined in the same crate as the schema.
+//!
 //! ## Input objects
 //!
 //! Input objects will be converted into Rust structs with public fields.
@@ -522,11 +660,38 @@
 //! }
 //! ```
 //!
+//! Add `@juniper(builder: true)` to an input object to also generate a `new` constructor that
+//! takes the non-null fields, and a `with_*` method for setting each nullable field. That makes
+//! it easier to create input objects in tests:
+//!
+//! ```graphql
+//! input CreatePost @juniper(builder: true) {
+//!     title: String!
+//!     body: String
+//! }
+//! ```
+//!
+//! ```ignore
+//! let input = CreatePost::new("Hello".to_string()).with_body("World".to_string());
+//! ```
+//!
 //! ## Enumeration types
 //!
 //! GraphQL enumeration types will be converted into normal Rust enums. The name of each variant
 //! will be camel cased.
 //!
+//! You can pick another name for a variant with `@juniper(rust_name: "...")`. That is necessary
+//! if a value would otherwise become a Rust keyword, such as `SELF`, or if two values would get
+//! the same name, such as `DARK_RED` and `darkRed`. Both cases are reported as errors in your
+//! schema.
+//!
+//! ```graphql
+//! enum Target {
+//!     SELF @juniper(rust_name: "Myself")
+//!     OTHER
+//! }
+//! ```
+//!
 //! Abbreviated example (find [complete example here](https://github.com/davidpdrsn/juniper-from-schema/blob/master/examples/enumeration_types.rs)):
 //!
 //! ```
@@ -583,6 +748,62 @@
 //! }
 //! ```
 //!
+//! If you already have a Rust enum, for example in another crate, you can convert to and from it
+//! with `@juniper(rust_type: "...")`:
+//!
+//! ```
+//! # #[macro_use]
+//! # extern crate juniper;
+//! # use juniper::*;
+//! # use juniper_from_schema::graphql_schema;
+//! # fn main() {}
+//! # pub struct Context;
+//! # impl juniper::Context for Context {}
+//! mod domain {
+//!     #[derive(Debug, Clone, Copy)]
+//!     pub enum Status {
+//!         Published,
+//!         Unpublished,
+//!     }
+//! }
+//!
+//! graphql_schema! {
+//!     schema {
+//!         query: Query
+//!     }
+//!
+//!     enum Status @juniper(rust_type: "domain::Status") {
+//!         PUBLISHED
+//!         UNPUBLISHED
+//!     }
+//!
+//!     type Query {
+//!         postCount(status: Status!): Int! @juniper(ownership: "owned")
+//!     }
+//! }
+//!
+//! pub struct Query;
+//!
+//! impl QueryFields for Query {
+//!     fn field_post_count(
+//!         &self,
+//!         executor: &Executor<'_, Context>,
+//!         status: Status,
+//!     ) -> FieldResult<i32> {
+//!         let status = domain::Status::from(status);
+//!         // ...
+//!         # unimplemented!()
+//!     }
+//! }
+//! ```
+//!
+//! The `Status` enum is still generated and used by the field methods, but `From` is implemented
+//! in both directions between it and `domain::Status`. The Rust enum must have exactly one variant
+//! for each value in the schema, named like the generated variants, including any `rust_name`s.
+//! Otherwise you'll get a compile error. Since Juniper's traits are only implemented for the
+//! generated enum the Rust enum can be defined in any crate, but the path can't be the name of the
+//! GraphQL enum itself.
+//!
 //! ## Default argument values
 //!
 //! In GraphQL you are able to provide default values for field arguments, provided the argument is
@@ -593,10 +814,17 @@
 //! - `Int`
 //! - `String`
 //! - `Boolean`
+//! - `ID`
+//! - Custom scalars, from `String`, `Int`, `Float`, or `Boolean` defaults
 //! - Enumerations
 //! - Input objects (as field arguments, see below)
 //! - Lists containing some other supported type
 //!
+//! Default values are checked against the type of their argument at compile time, following the
+//! input coercion rules from the spec. So `Float = 1` and `[Int!] = 1` are accepted, while
+//! `Int = 3000000000`, unknown enum values, and input objects with unknown or missing fields are
+//! reported as schema errors.
+//!
 //! Abbreviated example (find [complete example here](https://github.com/davidpdrsn/juniper-from-schema/blob/master/examples/default_argument_values.rs)):
 //!
 //! ```
@@ -707,13 +935,37 @@
 //!
 //! A number of [schema directives][] are supported that lets you customize the generated code:
 //!
-//! - `@juniper(ownership: "owned|borrowed|as_ref")`. For customizing ownership of returned data.
-//! More info [here](#customizing-ownership).
+//! - `@juniper(ownership: "owned|borrowed|as_ref|arc|boxed|cow")`. For customizing ownership of
+//!   returned data.
+//!   More info [here](#customizing-ownership).
 //! - `@juniper(infallible: true|false)`. Customize if a field should return `Result<T, _>` or
-//! just `T`. More info
-//! [here](http://localhost:4000/juniper_from_schema/index.html#infallible-fields).
+//!   just `T`. More info
+//!   [here](http://localhost:4000/juniper_from_schema/index.html#infallible-fields).
+//! - `@juniper(guard: "...")`. Require a guard to pass before a field is resolved. More info
+//!   [here](#guarding-fields).
+//! - `@juniper(rust_type: "...")`. Convert a GraphQL enum to and from an existing Rust enum, or
+//!   use an existing Rust enum for a union. More info [here](#enumeration-types) and
+//!   [here](#union-types).
+//! - `@juniper(repr: "string|i64|bigint|f64|bool|json|decimal|duration|naive_time")`. Customize the Rust type wrapped by a custom
+//!   scalar. More info [here](#custom-scalar-types).
+//! - `@juniper(backend: "chrono|time")`. Choose the crate used for the `Date` and `DateTimeUtc`
+//!   scalars. More info [here](#special-case-scalars).
+//! - `@juniper(payload: "owned|boxed|arc|rc|cow")`. Customize how the variants of an interface or
+//!   union store their types. More info [here](#union-types).
+//! - `@juniper(rust_name: "...")`. Customize the name of the Rust variant for an enum value. More
+//!   info [here](#enumeration-types).
+//! - `@juniper(builder: true|false)`. Generate a constructor and setters for an input object.
+//!   More info [here](#input-objects).
+//! - `@juniper(derive: "...", attributes: "...")`. Add derives and attributes to generated enums
+//!   and input objects. More info [here](#derives-and-attributes).
+//! - `@juniper(tri_state: true|false)`. Tell explicit `null` apart from a missing argument or
+//!   input object field. More info [here](#distinguishing-null-from-absent-values).
+//! - `@juniper(min: 1, max: 10, max_length: 255, pattern: "...")`. Validate arguments and input
+//!   object fields before fields are resolved. More info [here](#validating-input).
 //! - `@deprecated`. For deprecating types in your schema. Also supports supplying a reason with
-//! `@deprecated(reason: "...")`
+//!   `@deprecated(reason: "...")`
+//! - Any directive declared in the schema with `directive @name on ...`. More info
+//!   [here](#custom-directives).
 //!
 //! [schema directives]: https://www.apollographql.com/docs/apollo-server/schema/directives/
 //!
@@ -730,10 +982,18 @@
 //! It takes the following arguments:
 //!
 //! - `@juniper(ownership: "borrowed")`: The data returned will be borrowed from `self`
-//! (`FieldResult<&T>`).
+//!   (`FieldResult<&T>`).
 //! - `@juniper(ownership: "owned")`: The return type will be owned (`FieldResult<T>`).
 //! - `@juniper(ownership: "as_ref")`: Only applicable for `Option` and `Vec` return types. Changes
-//! the inner type to be borrowed (`FieldResult<Option<&T>>` or `FieldResult<Vec<&T>>`).
+//!   the inner type to be borrowed (`FieldResult<Option<&T>>` or `FieldResult<Vec<&T>>`).
+//! - `@juniper(ownership: "arc")`: The data returned will be shared (`FieldResult<Arc<T>>`).
+//!   Useful for data kept in `Arc`s across requests, which would otherwise have to be cloned.
+//! - `@juniper(ownership: "boxed")`: The data returned will be boxed (`FieldResult<Box<T>>`).
+//! - `@juniper(ownership: "cow")`: The data returned can be either borrowed from `self` or owned
+//!   (`FieldResult<Cow<'a, T>>`). `T` must implement `Clone`.
+//!
+//! The pointer types wrap the whole type, so a nullable list with `arc` ownership returns
+//! `FieldResult<Arc<Option<Vec<T>>>>`.
 //!
 //! Example:
 //!
@@ -754,6 +1014,9 @@
 //!         borrowed: String!
 //!         owned: String! @juniper(ownership: "owned")
 //!         asRef: String @juniper(ownership: "as_ref")
+//!         shared: String! @juniper(ownership: "arc")
+//!         boxed: String! @juniper(ownership: "boxed")
+//!         cow: String! @juniper(ownership: "cow")
 //!     }
 //! }
 //!
@@ -774,11 +1037,60 @@
 //!         // ...
 //!         # unimplemented!()
 //!     }
+//!
+//!     fn field_shared(&self, _: &Executor<'_, Context>) -> FieldResult<std::sync::Arc<String>> {
+//!         // ...
+//!         # unimplemented!()
+//!     }
+//!
+//!     fn field_boxed(&self, _: &Executor<'_, Context>) -> FieldResult<Box<String>> {
+//!         // ...
+//!         # unimplemented!()
+//!     }
+//!
+//!     fn field_cow(
+//!         &self,
+//!         _: &Executor<'_, Context>,
+//!     ) -> FieldResult<std::borrow::Cow<'_, String>> {
+//!         // ...
+//!         # unimplemented!()
+//!     }
 //! }
 //! ```
 //!
 //! All field arguments will be owned.
 //!
+//! The default ownership can also be changed for all fields of a type by adding the directive to
+//! the type itself, or for every field in the schema by passing `default_ownership` to
+//! `graphql_schema_from_file!`. Ownership set on a field always wins over ownership set on its
+//! type, which wins over the global default:
+//!
+//! ```ignore
+//! graphql_schema_from_file!("schema.graphql", default_ownership: owned);
+//! ```
+//!
+//! ```graphql
+//! type Query {
+//!   # Owned because of `default_ownership: owned`
+//!   name: String!
+//!   # Borrowed because the field says so
+//!   title: String! @juniper(ownership: "borrowed")
+//! }
+//!
+//! type User @juniper(ownership: "borrowed") {
+//!   # Borrowed because of the type-level directive
+//!   name: String!
+//! }
+//! ```
+//!
+//! Ownership set on an interface also applies to the types that implement it, unless they set
+//! their own, so the fields of the interface and its implementors have the same return types.
+//!
+//! The documentation of each generated field method says which GraphQL field it resolves, its
+//! ownership, and the exact return type that follows from that. So if the compiler complains
+//! that your method has an incompatible type for the trait, running `cargo doc` will tell you
+//! what it should be.
+//!
 //! ## Infallible fields
 //!
 //! By default the generated resolvers are fallible, meaining they return a `Result<T, _>` rather
@@ -826,145 +1138,496 @@
 //! }
 //! ```
 //!
-//! # GraphQL to Rust types
+//! ## Guarding fields
 //!
-//! This is how the standard GraphQL types will be mapped to Rust:
+//! Fields can be protected by adding `@juniper(guard: "...")` where the value is any Rust
+//! expression. Before calling your field method the generated code calls [`Guard::check`] on your
+//! context type with that value. If the check returns an error the field method won't be called
+//! and the error is returned instead.
 //!
-//! - `Int` -> `i32`
-//! - `Float` -> `f64`
-//! - `String` -> `String`
-//! - `Boolean` -> `bool`
-//! - `ID` -> [`juniper::ID`](https://docs.rs/juniper/latest/juniper/struct.ID.html)
+//! Guards can also be placed on object and interface types, in which case they apply to every
+//! field of the type. If both the type and the field have a guard then both must pass. A guard on
+//! an interface also applies to every field of the types that implement it, so it can't be
+//! bypassed with fragments such as `... on User { secret }`.
 //!
-//! # Query trails
+//! Example:
 //!
-//! If you're not careful about preloading associations for deeply nested queries you risk getting
-//! lots of [N+1 query bugs][]. Juniper provides a [look ahead API][] which lets you inspect things
-//! coming up further down a query. However the API is string based, so you risk making typos and
-//! checking for fields that don't exist.
+//! ```
+//! # #[macro_use]
+//! # extern crate juniper;
+//! # use juniper_from_schema::*;
+//! # use juniper::*;
+//! # fn main() {}
+//! graphql_schema! {
+//!     schema {
+//!         query: Query
+//!     }
 //!
-//! `QueryTrail` is a thin wrapper around Juniper look aheads with generated methods for each field
-//! on all your types. This means the compiler will reject your code if you're checking for invalid
-//! fields.
+//!     type Query {
+//!         public: String!
+//!         secret: String! @juniper(guard: "Role::Admin")
+//!     }
+//! }
 //!
-//! Resolver methods (`field_*`) that return object types (non scalar values) will also get a
-//! `QueryTrail` argument besides the executor.
+//! pub enum Role {
+//!     Admin,
+//! }
 //!
-//! Since the `QueryTrail` type itself is defined in this crate (rather than being inserted into
-//! your code) we cannot directly add methods for your GraphQL fields. Those methods have to be
-//! added through ["extension traits"](http://xion.io/post/code/rust-extension-traits.html). So if
-//! you see an error like
+//! pub struct Context {
+//!     is_admin: bool,
+//! }
 //!
-//! ```text
-//!    |  trail.foo();
-//!    |        ^^^ method not found in `&juniper_from_schema::QueryTrail<'a, User, juniper_from_schema::Walked>`
-//!    |
-//!    = help: items from traits can only be used if the trait is in scope
-//! help: the following trait is implemented but not in scope, perhaps add a `use` for it:
-//!    |
-//! 2  | use crate::graphql_schema::query_trails::QueryTrailUserExtensions;
-//!    |
-//! ```
+//! impl juniper::Context for Context {}
 //!
-//! Then adding `use crate::graphql_schema::query_trails::*` to you module should fix it. This is
-//! necessary because all the extention traits are generated inside a module called `query_trails`.
-//! This is done so you can glob import the `QueryTrail` extension traits without glob importing
-//! everything from your GraphQL schema.
+//! impl Guard<Role> for Context {
+//!     fn check(&self, role: &Role) -> FieldResult<()> {
+//!         match role {
+//!             Role::Admin if self.is_admin => Ok(()),
+//!             Role::Admin => Err(FieldError::from("Must be an admin")),
+//!         }
+//!     }
+//! }
 //!
-//! If you just want everything from the schema `use crate::graphql_schema::*` will also bring in
-//! the extension traits.
+//! pub struct Query;
 //!
-//! [N+1 query bugs]: https://secure.phabricator.com/book/phabcontrib/article/n_plus_one/
-//! [look ahead API]: https://docs.rs/juniper/0.11.1/juniper/struct.LookAheadSelection.html
+//! impl QueryFields for Query {
+//!     fn field_public(&self, _: &Executor<'_, Context>) -> FieldResult<&String> {
+//!         // ...
+//!         # unimplemented!()
+//!     }
 //!
-//! ## Abbreviated example
+//!     // Only called if `Guard::<Role>::check` returned `Ok(())`
+//!     fn field_secret(&self, _: &Executor<'_, Context>) -> FieldResult<&String> {
+//!         // ...
+//!         # unimplemented!()
+//!     }
+//! }
+//! ```
 //!
-//! Find [complete example here](https://github.com/davidpdrsn/juniper-from-schema/blob/master/examples/query_trails.rs)
+//! Since a guard can always fail, guarded fields return `juniper::FieldResult<T>` to Juniper even
+//! if they're infallible or use a [custom error type](#customizing-the-error-type). The signature
+//! of your field method doesn't change.
+//!
+//! [`Guard::check`]: trait.Guard.html#tymethod.check
+//!
+//! ## Derives and attributes
+//!
+//! Generated enums always derive `Debug`, `Eq`, `PartialEq`, `Copy`, `Clone`, and `Hash`, and
+//! generated input objects always derive `Debug` and `Clone`. You can add more derives and outer
+//! attributes to an enum or input object with `@juniper(derive: "...", attributes: "...")`:
 //!
 //! ```
 //! # #[macro_use]
 //! # extern crate juniper;
+//! # use juniper_from_schema::*;
 //! # use juniper::*;
-//! # use juniper_from_schema::graphql_schema;
-//! # fn main() {}
 //! # pub struct Context;
 //! # impl juniper::Context for Context {}
-//! #
+//! # fn main() {}
 //! graphql_schema! {
 //!     schema {
 //!         query: Query
 //!     }
 //!
 //!     type Query {
-//!         allPosts: [Post!]! @juniper(ownership: "owned")
+//!         users(filter: UserFilter!, order: Order!): Boolean!
 //!     }
 //!
-//!     type Post {
-//!         id: Int!
-//!         author: User!
+//!     input UserFilter @juniper(derive: "PartialEq, Default") {
+//!         name: String
 //!     }
 //!
-//!     type User {
-//!         id: Int!
+//!     enum Order @juniper(derive: "PartialOrd, Ord", attributes: "#[allow(missing_docs)]") {
+//!         ASC
+//!         DESC
 //!     }
 //! }
 //!
 //! pub struct Query;
 //!
 //! impl QueryFields for Query {
-//!     fn field_all_posts(
+//!     fn field_users(
 //!         &self,
-//!         executor: &Executor<'_, Context>,
-//!         trail: &QueryTrail<'_, Post, juniper_from_schema::Walked>,
-//!     ) -> FieldResult<Vec<Post>> {
-//!         // Check if the query includes the author
-//!         if let Some(_) = trail.author().walk() {
-//!             // Somehow preload the users to avoid N+1 query bugs
-//!             // Exactly how to do this depends on your setup
+//!         _: &Executor<'_, Context>,
+//!         filter: UserFilter,
+//!         order: Order,
+//!     ) -> FieldResult<&bool> {
+//!         if filter == UserFilter::default() && order > Order::Asc {
+//!             // ...
 //!         }
-//!
-//!         // Normally this would come from the database
-//!         let post = Post {
-//!             id: 1,
-//!             author: User { id: 1 },
-//!         };
-//!
-//!         Ok(vec![post])
+//!         # unimplemented!()
 //!     }
 //! }
+//! ```
 //!
-//! pub struct Post {
-//!     id: i32,
-//!     author: User,
-//! }
+//! Derives and attributes that should apply to all enums and input objects can be given to
+//! `graphql_schema_from_file!` instead:
 //!
-//! impl PostFields for Post {
-//!     fn field_id(&self, executor: &Executor<'_, Context>) -> FieldResult<&i32> {
-//!         Ok(&self.id)
-//!     }
+//! ```ignore
+//! graphql_schema_from_file!(
+//!     "schema.graphql",
+//!     derive: [serde::Serialize, serde::Deserialize],
+//!     attributes: [#[serde(deny_unknown_fields)]]
+//! );
+//! ```
 //!
-//!     fn field_author(
-//!         &self,
-//!         executor: &Executor<'_, Context>,
-//!         trail: &QueryTrail<'_, User, juniper_from_schema::Walked>,
-//!     ) -> FieldResult<&User> {
-//!         Ok(&self.author)
-//!     }
-//! }
+//! Those are combined with the ones given to each type. Derives that are always added, such as
+//! `Clone`, are skipped if you ask for them again.
 //!
-//! pub struct User {
-//!     id: i32,
-//! }
+//! ## Distinguishing null from absent values
 //!
-//! impl UserFields for User {
-//!     fn field_id(
-//!         &self,
-//!         executor: &Executor<'_, Context>,
-//!     ) -> FieldResult<&i32> {
-//!         Ok(&self.id)
-//!     }
-//! }
-//! ```
+//! Nullable arguments and input object fields become `Option<T>`, so an explicit `null` and a
+//! value that wasn't given at all both become `None`. That makes it hard to write mutations that
+//! only update the fields they're given and clear fields set to `null`.
+//!
+//! Add `@juniper(tri_state: true)` to a nullable argument or input object field to use
+//! [`Nullable<T>`](enum.Nullable.html) instead:
+//!
+//! ```
+//! # #[macro_use]
+//! # extern crate juniper;
+//! # use juniper_from_schema::*;
+//! # use juniper::*;
+//! # pub struct Context;
+//! # impl juniper::Context for Context {}
+//! # fn main() {}
+//! graphql_schema! {
+//!     schema {
+//!         query: Query
+//!     }
+//!
+//!     type Query {
+//!         updateUser(
+//!             name: String @juniper(tri_state: true),
+//!             profile: ProfileInput,
+//!         ): Boolean!
+//!     }
+//!
+//!     input ProfileInput {
+//!         bio: String @juniper(tri_state: true)
+//!     }
+//! }
+//!
+//! pub struct Query;
+//!
+//! impl QueryFields for Query {
+//!     fn field_update_user(
+//!         &self,
+//!         _: &Executor<'_, Context>,
+//!         name: Nullable<String>,
+//!         profile: Option<ProfileInput>,
+//!     ) -> FieldResult<&bool> {
+//!         match name {
+//!             Nullable::Absent => { /* leave the name alone */ }
+//!             Nullable::Null => { /* clear the name */ }
+//!             Nullable::Value(name) => { /* set the name */ }
+//!         }
+//!
+//!         if let Some(profile) = profile {
+//!             let _: Nullable<String> = profile.bio;
+//!         }
+//!         # unimplemented!()
+//!     }
+//! }
+//! ```
+//!
+//! Query trails also return `Nullable<T>` for such arguments.
+//!
+//! `tri_state` isn't supported on non-null types or on arguments with default values, since those
+//! can never be absent. Input objects with tri-state fields implement juniper's traits directly
+//! rather than through `#[derive(GraphQLInputObject)]`, which treats missing fields as `null`.
+//!
+//! Note that juniper replaces variables that are declared but not sent with `null` before the
+//! arguments reach your code. So `name(name: $name)` gives `Nullable::Null` rather than
+//! `Nullable::Absent` if `$name` isn't part of the variables. The same goes for input object fields
+//! such as `{ bio: $bio }`. Send the input object itself as a variable if clients should be able to
+//! leave fields out.
+//!
+//! ## Validating input
+//!
+//! Arguments and input object fields can be constrained with these directives:
+//!
+//! - `@juniper(min: 1)` and `@juniper(max: 100)` for `Int` and `Float` values.
+//! - `@juniper(max_length: 255)` for `String` and `ID` values. The length is counted in
+//!   characters.
+//! - `@juniper(pattern: "^[a-z]+$")` for `String` and `ID` values. The pattern is a
+//!   [`regex`](https://docs.rs/regex) and is checked when the schema is compiled. It requires the
+//!   `regex` feature, which is enabled by default.
+//!
+//! ```
+//! # #[macro_use]
+//! # extern crate juniper;
+//! # use juniper_from_schema::*;
+//! # use juniper::*;
+//! # pub struct Context;
+//! # impl juniper::Context for Context {}
+//! # fn main() {}
+//! graphql_schema! {
+//!     schema {
+//!         query: Query
+//!     }
+//!
+//!     type Query {
+//!         users(
+//!             first: Int! @juniper(min: 1, max: 100),
+//!             filter: UserFilter,
+//!         ): [String!]! @juniper(ownership: "owned")
+//!     }
+//!
+//!     input UserFilter {
+//!         name: String @juniper(max_length: 50)
+//!     }
+//! }
+//!
+//! pub struct Query;
+//!
+//! impl QueryFields for Query {
+//!     fn field_users(
+//!         &self,
+//!         _: &Executor<'_, Context>,
+//!         first: i32,
+//!         filter: Option<UserFilter>,
+//!     ) -> FieldResult<Vec<String>> {
+//!         // `first` is guaranteed to be between 1 and 100 here
+//!         # unimplemented!()
+//!     }
+//! }
+//! ```
+//!
+//! The constraints are checked before your field method is called, after any guards. Nullable
+//! values are only checked if they're given and constraints on lists apply to each item. Input
+//! objects given as arguments are checked as well, including nested ones.
+//!
+//! If any value is invalid the field returns an error with the message `Invalid input` and an
+//! extension listing every [`Violation`](struct.Violation.html):
+//!
+//! ```json
+//! {
+//!   "message": "Invalid input",
+//!   "extensions": {
+//!     "violations": [
+//!       { "path": "first", "constraint": "max", "message": "must be at most 100" },
+//!       { "path": "filter.name", "constraint": "max_length", "message": "must be at most 50 characters long" }
+//!     ]
+//!   }
+//! }
+//! ```
+//!
+//! Like guarded fields, validated fields return `juniper::FieldResult` from the generated
+//! resolver, so the error is returned even for infallible fields.
+//!
+//! ## Custom directives
+//!
+//! Schemas shared with other tools often use directives of their own. Those are allowed as long
+//! as they're declared in the schema:
+//!
+//! ```
+//! # use juniper_from_schema::graphql_schema;
+//! # use juniper::*;
+//! # pub struct Context;
+//! # impl juniper::Context for Context {}
+//! graphql_schema! {
+//!     schema {
+//!         query: Query
+//!     }
+//!
+//!     type Query @key(fields: "id") {
+//!         hello: String! @cacheControl(maxAge: 60)
+//!     }
+//!
+//!     directive @cacheControl(maxAge: Int) on FIELD_DEFINITION | OBJECT
+//!     directive @key(fields: String!) on OBJECT | INTERFACE
+//! }
+//!
+//! pub struct Query;
+//!
+//! impl QueryFields for Query {
+//!     fn field_hello(&self, executor: &Executor<'_, Context>) -> FieldResult<&String> {
+//!         # unimplemented!()
+//!     }
+//! }
+//! # fn main() {}
+//! ```
+//!
+//! Custom directives don't change how the types are generated. Using one that isn't declared, or
+//! using it somewhere its declaration doesn't list, is a compile error. Declarations of `@juniper`
+//! and the built-in directives are accepted but ignored. `repeatable` directives aren't supported.
+//!
+//! Where custom directives are used on types and fields, including input object fields, is
+//! available at runtime through these generated items. They're only generated when the schema
+//! declares custom directives:
+//!
+//! - `directives_for(type_name, field_name)` returns the directives used on a field.
+//! - `directives_for_type(type_name)` returns the directives used on a type.
+//! - `FIELD_DIRECTIVES` and `TYPE_DIRECTIVES` list every field and type that uses any.
+//!
+//! Each use is a [`DirectiveUse`](struct.DirectiveUse.html) with the arguments it was given. This
+//! lets [field middleware](#field-middleware) react to directives such as `@cacheControl` above:
+//!
+//! ```ignore
+//! impl FieldMiddleware<Context> for CacheControl {
+//!     fn around<T, F>(
+//!         type_name: &'static str,
+//!         field_name: &'static str,
+//!         executor: &Executor<'_, Context>,
+//!         resolve: F,
+//!     ) -> FieldResult<T>
+//!     where
+//!         F: FnOnce() -> FieldResult<T>,
+//!     {
+//!         let max_age = directives_for(type_name, field_name)
+//!             .iter()
+//!             .find(|directive| directive.name == "cacheControl")
+//!             .and_then(|directive| directive.argument("maxAge"));
+//!
+//!         if let Some(DirectiveValue::Int(max_age)) = max_age {
+//!             // record `max_age` somewhere
+//!         }
+//!
+//!         resolve()
+//!     }
+//! }
+//! ```
+//!
+//! # GraphQL to Rust types
+//!
+//! This is how the standard GraphQL types will be mapped to Rust:
+//!
+//! - `Int` -> `i32`
+//! - `Float` -> `f64`
+//! - `String` -> `String`
+//! - `Boolean` -> `bool`
+//! - `ID` -> [`juniper::ID`](https://docs.rs/juniper/latest/juniper/struct.ID.html)
+//!
+//! # Query trails
+//!
+//! If you're not careful about preloading associations for deeply nested queries you risk getting
+//! lots of [N+1 query bugs][]. Juniper provides a [look ahead API][] which lets you inspect things
+//! coming up further down a query. However the API is string based, so you risk making typos and
+//! checking for fields that don't exist.
+//!
+//! `QueryTrail` is a thin wrapper around Juniper look aheads with generated methods for each field
+//! on all your types. This means the compiler will reject your code if you're checking for invalid
+//! fields.
+//!
+//! Resolver methods (`field_*`) that return object types (non scalar values) will also get a
+//! `QueryTrail` argument besides the executor.
+//!
+//! Since the `QueryTrail` type itself is defined in this crate (rather than being inserted into
+//! your code) we cannot directly add methods for your GraphQL fields. Those methods have to be
+//! added through ["extension traits"](http://xion.io/post/code/rust-extension-traits.html). So if
+//! you see an error like
+//!
+//! ```text
+//!    |  trail.foo();
+//!    |        ^^^ method not found in `&juniper_from_schema::QueryTrail<'a, User, juniper_from_schema::Walked>`
+//!    |
+//!    = help: items from traits can only be used if the trait is in scope
+//! help: the following trait is implemented but not in scope, perhaps add a `use` for it:
+//!    |
+//! 2  | use crate::graphql_schema::query_trails::QueryTrailUserExtensions;
+//!    |
+//! ```
+//!
+//! Then adding `use crate::graphql_schema::query_trails::*` to you module should fix it. This is
+//! necessary because all the extention traits are generated inside a module called `query_trails`.
+//! This is done so you can glob import the `QueryTrail` extension traits without glob importing
+//! everything from your GraphQL schema.
+//!
+//! If you just want everything from the schema `use crate::graphql_schema::*` will also bring in
+//! the extension traits.
+//!
+//! [N+1 query bugs]: https://secure.phabricator.com/book/phabcontrib/article/n_plus_one/
+//! [look ahead API]: https://docs.rs/juniper/0.11.1/juniper/struct.LookAheadSelection.html
+//!
+//! ## Abbreviated example
+//!
+//! Find [complete example here](https://github.com/davidpdrsn/juniper-from-schema/blob/master/examples/query_trails.rs)
+//!
+//! ```
+//! # #[macro_use]
+//! # extern crate juniper;
+//! # use juniper::*;
+//! # use juniper_from_schema::graphql_schema;
+//! # fn main() {}
+//! # pub struct Context;
+//! # impl juniper::Context for Context {}
+//! #
+//! graphql_schema! {
+//!     schema {
+//!         query: Query
+//!     }
+//!
+//!     type Query {
+//!         allPosts: [Post!]! @juniper(ownership: "owned")
+//!     }
+//!
+//!     type Post {
+//!         id: Int!
+//!         author: User!
+//!     }
+//!
+//!     type User {
+//!         id: Int!
+//!     }
+//! }
+//!
+//! pub struct Query;
+//!
+//! impl QueryFields for Query {
+//!     fn field_all_posts(
+//!         &self,
+//!         executor: &Executor<'_, Context>,
+//!         trail: &QueryTrail<'_, Post, juniper_from_schema::Walked>,
+//!     ) -> FieldResult<Vec<Post>> {
+//!         // Check if the query includes the author
+//!         if let Some(_) = trail.author().walk() {
+//!             // Somehow preload the users to avoid N+1 query bugs
+//!             // Exactly how to do this depends on your setup
+//!         }
+//!
+//!         // Normally this would come from the database
+//!         let post = Post {
+//!             id: 1,
+//!             author: User { id: 1 },
+//!         };
+//!
+//!         Ok(vec![post])
+//!     }
+//! }
+//!
+//! pub struct Post {
+//!     id: i32,
+//!     author: User,
+//! }
+//!
+//! impl PostFields for Post {
+//!     fn field_id(&self, executor: &Executor<'_, Context>) -> FieldResult<&i32> {
+//!         Ok(&self.id)
+//!     }
+//!
+//!     fn field_author(
+//!         &self,
+//!         executor: &Executor<'_, Context>,
+//!         trail: &QueryTrail<'_, User, juniper_from_schema::Walked>,
+//!     ) -> FieldResult<&User> {
+//!         Ok(&self.author)
+//!     }
+//! }
+//!
+//! pub struct User {
+//!     id: i32,
+//! }
+//!
+//! impl UserFields for User {
+//!     fn field_id(
+//!         &self,
+//!         executor: &Executor<'_, Context>,
+//!     ) -> FieldResult<&i32> {
+//!         Ok(&self.id)
+//!     }
+//! }
+//! ```
 //!
 //! ## Types
 //!
@@ -1147,6 +1810,9 @@
 //! # pub struct Context;
 //! # impl juniper::Context for Context {}
 //! # fn main() {}
+//! # #[cfg(feature = "chrono")]
+//! # mod example {
+//! # use super::*;
 //! # pub struct Country {}
 //! # impl CountryFields for Country {
 //! #     fn field_users<'a>(
@@ -1210,6 +1876,7 @@
 //!         // ...
 //!     }
 //! }
+//! # }
 //! ```
 //!
 //! You can also elide the `'a` lifetime:
@@ -1221,6 +1888,9 @@
 //! # pub struct Context;
 //! # impl juniper::Context for Context {}
 //! # fn main() {}
+//! # #[cfg(feature = "chrono")]
+//! # mod example {
+//! # use super::*;
 //! # pub struct Country {}
 //! # impl CountryFields for Country {
 //! #     fn field_users<'a>(
@@ -1271,6 +1941,7 @@
 //!         // ...
 //!     }
 //! }
+//! # }
 //! ```
 //!
 //! The name of the arguments struct will always be `{name of type}{name of field}Args` (e.g.
@@ -1395,12 +2066,181 @@
 //! [`graphql_schema!`]: macro.graphql_schema.html
 //! [`graphql_schema_from_file!`]: macro.graphql_schema_from_file.html
 //!
-//! # Inspecting the generated code
+//! # Field middleware
 //!
-//! If you wish to see exactly what code gets generated you can set the env var
-//! `JUNIPER_FROM_SCHEMA_DEBUG` to `1` when compiling. For example:
+//! If you want to run the same code around every field, for example for logging, metrics, or
+//! timing, you can call [`graphql_schema_from_file!`] with `middleware: YourType`. `YourType` must
+//! implement [`FieldMiddleware`] and the generated code will call [`FieldMiddleware::around`]
+//! every time a field is resolved. `around` is given the result of the field so it can also log
+//! errors. Because of that every field returns `FieldResult` to Juniper when middleware is used,
+//! and errors of a custom `error_type` are converted with `IntoFieldError` first.
 //!
-//! ```bash
+//! Example:
+//!
+//! ```
+//! # #[macro_use]
+//! # extern crate juniper;
+//! # use juniper::*;
+//! # use juniper_from_schema::{graphql_schema_from_file, FieldMiddleware};
+//! # fn main() {}
+//! # pub struct Context;
+//! # impl juniper::Context for Context {}
+//! # pub struct Mutation;
+//! # impl MutationFields for Mutation {
+//! #     fn field_noop(&self, executor: &Executor<'_, Context>) -> FieldResult<&bool> {
+//! #         Ok(&true)
+//! #     }
+//! # }
+//! graphql_schema_from_file!("tests/schemas/doc_schema.graphql", middleware: Timing);
+//!
+//! pub struct Timing;
+//!
+//! impl FieldMiddleware<Context> for Timing {
+//!     fn around<T, F>(
+//!         type_name: &'static str,
+//!         field_name: &'static str,
+//!         executor: &Executor<'_, Context>,
+//!         resolve: F,
+//!     ) -> FieldResult<T>
+//!     where
+//!         F: FnOnce() -> FieldResult<T>,
+//!     {
+//!         let start = std::time::Instant::now();
+//!         let result = resolve();
+//!         println!("{}.{} took {:?}", type_name, field_name, start.elapsed());
+//!         if let Err(error) = &result {
+//!             eprintln!("{}.{} failed: {}", type_name, field_name, error.message());
+//!         }
+//!         result
+//!     }
+//! }
+//!
+//! pub struct Query;
+//!
+//! impl QueryFields for Query {
+//!     fn field_hello_world(
+//!         &self,
+//!         executor: &Executor<'_, Context>,
+//!         name: String,
+//!     ) -> FieldResult<String> {
+//!         Ok(format!("Hello, {}!", name))
+//!     }
+//! }
+//! ```
+//!
+//! Fields resolved through an interface use the name of the type implementing the interface.
+//!
+//! [`graphql_schema!`] does not support middleware.
+//!
+//! [`graphql_schema!`]: macro.graphql_schema.html
+//! [`graphql_schema_from_file!`]: macro.graphql_schema_from_file.html
+//! [`FieldMiddleware`]: trait.FieldMiddleware.html
+//! [`FieldMiddleware::around`]: trait.FieldMiddleware.html#tymethod.around
+//!
+//! ## Tracing
+//!
+//! If you enable the [feature] called `"tracing"` every generated field will be resolved inside
+//! a [`tracing`] span. The span is named after the type and field, for example `User.name`, and
+//! records the fields `type_name`, `field_name`, and `arguments` (the names of the field's
+//! arguments, separated by commas).
+//!
+//! ```toml
+//! [dependencies]
+//! juniper-from-schema = { version = "x.y.z", features = ["tracing"] }
+//! ```
+//!
+//! Spans are opened at the `INFO` level and wrap the field middleware, if any. This works with
+//! both [`graphql_schema!`] and [`graphql_schema_from_file!`].
+//!
+//! [`tracing`]: https://crates.io/crates/tracing
+//!
+//! # Serde support
+//!
+//! If you enable the [feature] called `"serde"` all generated enums, input objects, and custom
+//! scalars implement [`serde`]'s `Serialize` and `Deserialize`.
+//!
+//! ```toml
+//! [dependencies]
+//! juniper-from-schema = { version = "x.y.z", features = ["serde"] }
+//! ```
+//!
+//! The GraphQL names are used, so enum values are serialized as written in the schema (`ASC`
+//! rather than `Asc`) and input object fields use camelCase (`firstName` rather than
+//! `first_name`). Custom scalars are serialized as plain strings.
+//!
+//! You don't need to depend on `serde` yourself to use this, and the `serde` features of `chrono`,
+//! `uuid`, and `url` are enabled for you so fields using the [special case
+//! scalars](#special-case-scalars) work as well. Don't also add `serde::Serialize` or
+//! `serde::Deserialize` using [`derive`](#derives-and-attributes) as that would lead to
+//! conflicting implementations.
+//!
+//! [`serde`]: https://crates.io/crates/serde
+//!
+//! # Schema lints
+//!
+//! Besides errors, the schema is checked for things that are valid but probably not what you
+//! want. Like Rust's lints, each lint can be set to `allow`, `warn`, or `deny`. Every lint is
+//! `allow` by default:
+//!
+//! | Lint | Checks for |
+//! |---|---|
+//! | `missing_descriptions` | Types and fields without a description |
+//! | `deprecated_without_reason` | `@deprecated` without a `reason` |
+//! | `nullable_list_of_nullable_items` | Types like `[T]` where both the list and its items are nullable |
+//! | `non_screaming_case_enum_values` | Enum values that aren't `SCREAMING_CASE` |
+//! | `unreachable_types` | Types that can't be reached from the query or mutation type |
+//!
+//! Change the levels by calling [`graphql_schema_from_file!`] with `lints: [...]`:
+//!
+//! ```
+//! # #[macro_use]
+//! # extern crate juniper;
+//! # use juniper::*;
+//! # use juniper_from_schema::graphql_schema_from_file;
+//! # fn main() {}
+//! # pub struct Context;
+//! # impl juniper::Context for Context {}
+//! # pub struct Query;
+//! # impl QueryFields for Query {
+//! #     fn field_hello_world(
+//! #         &self,
+//! #         executor: &Executor<'_, Context>,
+//! #         name: String,
+//! #     ) -> juniper::FieldResult<String> {
+//! #         Ok(format!("Hello, {}!", name))
+//! #     }
+//! # }
+//! # pub struct Mutation;
+//! # impl MutationFields for Mutation {
+//! #     fn field_noop(&self, executor: &Executor<'_, Context>) -> juniper::FieldResult<&bool> {
+//! #         Ok(&true)
+//! #     }
+//! # }
+//! graphql_schema_from_file!(
+//!     "tests/schemas/doc_schema.graphql",
+//!     lints: [non_screaming_case_enum_values: warn, unreachable_types: deny],
+//! );
+//! ```
+//!
+//! Denied lints are reported like other schema errors. Proc macros can't emit warnings on stable
+//! Rust, so warnings are reported through uses of deprecated constants and look like this:
+//!
+//! ```text
+//! warning: use of deprecated constant `_::unreachable_types`: The type `Orphan` can't be reached from the query or mutation type
+//!           --> schema:26:1
+//!          `unreachable_types` is set to warn. Change it with `lints: [unreachable_types: allow]`
+//! ```
+//!
+//! That also means `#![deny(deprecated)]` turns them into errors.
+//!
+//! [`graphql_schema!`] doesn't take a config so it never reports lints.
+//!
+//! # Inspecting the generated code
+//!
+//! If you wish to see exactly what code gets generated you can set the env var
+//! `JUNIPER_FROM_SCHEMA_DEBUG` to `1` when compiling. For example:
+//!
+//! ```bash
 //! JUNIPER_FROM_SCHEMA_DEBUG=1 cargo build
 //! ```
 //!
@@ -1438,6 +2278,26 @@ use std::marker::PhantomData;
 
 pub use juniper_from_schema_code_gen::{graphql_schema, graphql_schema_from_file};
 
+#[cfg(feature = "tracing")]
+#[doc(hidden)]
+pub use tracing;
+
+#[cfg(feature = "serde")]
+#[doc(hidden)]
+pub use serde;
+
+#[cfg(feature = "json")]
+#[doc(hidden)]
+pub use serde_json;
+
+#[cfg(feature = "decimal")]
+#[doc(hidden)]
+pub use rust_decimal;
+
+#[cfg(feature = "time")]
+#[doc(hidden)]
+pub use time;
+
 /// A type used to parameterize `QueryTrail` to know that `walk` has been called.
 pub struct Walked;
 
@@ -1481,12 +2341,1000 @@ impl<'a, T, K> QueryTrail<'a, T, K> {
     }
 }
 
+/// Hook that wraps every generated field resolver.
+///
+/// Enable it by passing `middleware: YourType` to [`graphql_schema_from_file!`]. See the [crate
+/// level docs](index.html#field-middleware) for more info.
+///
+/// [`graphql_schema_from_file!`]: macro.graphql_schema_from_file.html
+pub trait FieldMiddleware<C> {
+    /// Called every time a field is resolved. `resolve` calls your field method and returns its
+    /// result, including any error from the field method, a guard, or argument validation.
+    ///
+    /// `type_name` and `field_name` are the names used in the GraphQL schema.
+    fn around<T, F>(
+        type_name: &'static str,
+        field_name: &'static str,
+        executor: &juniper::Executor<'_, C>,
+        resolve: F,
+    ) -> juniper::FieldResult<T>
+    where
+        F: FnOnce() -> juniper::FieldResult<T>;
+}
+
+/// Trait used to check `@juniper(guard: "...")` directives.
+///
+/// Implement this for your context type, once for each type of guard used in your schema. See
+/// the [crate level docs](index.html#guarding-fields) for more info.
+pub trait Guard<G> {
+    /// Check if the current request is allowed to access a field protected by `guard`.
+    ///
+    /// Returning an error denies access and the error will be returned from the field.
+    fn check(&self, guard: &G) -> juniper::FieldResult<()>;
+}
+
+/// A custom directive used in the schema, such as `@cacheControl(maxAge: 60)`.
+///
+/// Look them up with the generated `directives_for` and `directives_for_type` functions. See the
+/// [crate level docs](index.html#custom-directives) for more info.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct DirectiveUse {
+    /// The name of the directive, without the `@`.
+    pub name: &'static str,
+    /// The arguments given to the directive, in the order they're written in the schema.
+    pub arguments: &'static [(&'static str, DirectiveValue)],
+}
+
+impl DirectiveUse {
+    /// The value given for an argument, if it was given.
+    pub fn argument(&self, name: &str) -> Option<&'static DirectiveValue> {
+        self.arguments
+            .iter()
+            .find(|(key, _)| *key == name)
+            .map(|(_, value)| value)
+    }
+}
+
+/// The value of an argument given to a custom directive.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum DirectiveValue {
+    /// An integer such as `60`.
+    Int(i64),
+    /// A float such as `1.5`.
+    Float(f64),
+    /// A string such as `"id"`.
+    String(&'static str),
+    /// `true` or `false`.
+    Boolean(bool),
+    /// An enum value such as `PUBLIC`.
+    Enum(&'static str),
+    /// `null`.
+    Null,
+    /// A list such as `[1, 2]`.
+    List(&'static [DirectiveValue]),
+    /// An object such as `{ scope: PUBLIC }`. Keys are sorted.
+    Object(&'static [(&'static str, DirectiveValue)]),
+}
+
+/// A nullable argument or input object field that distinguishes between explicitly being set to
+/// `null` and not being given at all.
+///
+/// Used for arguments and input object fields marked with `@juniper(tri_state: true)`. See the
+/// [crate level docs](index.html#distinguishing-null-from-absent-values) for more info.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum Nullable<T> {
+    /// The value wasn't given.
+    #[default]
+    Absent,
+    /// The value was explicitly set to `null`.
+    Null,
+    /// The value was set.
+    Value(T),
+}
+
+impl<T> Nullable<T> {
+    /// Returns `true` if the value wasn't given.
+    pub fn is_absent(&self) -> bool {
+        matches!(self, Nullable::Absent)
+    }
+
+    /// Returns `true` if the value was explicitly set to `null`.
+    pub fn is_null(&self) -> bool {
+        matches!(self, Nullable::Null)
+    }
+
+    /// Returns `true` if the value was set.
+    pub fn is_value(&self) -> bool {
+        matches!(self, Nullable::Value(_))
+    }
+
+    /// Convert from `&Nullable<T>` to `Nullable<&T>`.
+    pub fn as_ref(&self) -> Nullable<&T> {
+        match self {
+            Nullable::Absent => Nullable::Absent,
+            Nullable::Null => Nullable::Null,
+            Nullable::Value(value) => Nullable::Value(value),
+        }
+    }
+
+    /// Map the value, if it was set.
+    pub fn map<U, F>(self, f: F) -> Nullable<U>
+    where
+        F: FnOnce(T) -> U,
+    {
+        match self {
+            Nullable::Absent => Nullable::Absent,
+            Nullable::Null => Nullable::Null,
+            Nullable::Value(value) => Nullable::Value(f(value)),
+        }
+    }
+
+    /// Convert into an `Option`, which is `None` unless the value was set.
+    ///
+    /// The returned option is itself wrapped in an `Option` which is `None` if the value wasn't
+    /// given. This matches how you'd typically apply a partial update:
+    ///
+    /// ```
+    /// # use juniper_from_schema::Nullable;
+    /// let mut bio = Some("Old bio".to_string());
+    ///
+    /// if let Some(new_bio) = Nullable::Null.into_update() {
+    ///     bio = new_bio;
+    /// }
+    ///
+    /// assert_eq!(bio, None);
+    /// ```
+    pub fn into_update(self) -> Option<Option<T>> {
+        match self {
+            Nullable::Absent => None,
+            Nullable::Null => Some(None),
+            Nullable::Value(value) => Some(Some(value)),
+        }
+    }
+
+    #[doc(hidden)]
+    pub fn from_argument<C>(
+        value: Option<T>,
+        executor: &juniper::Executor<'_, C>,
+        name: &str,
+    ) -> Self {
+        use juniper::LookAheadMethods;
+
+        match value {
+            Some(value) => Nullable::Value(value),
+            // Juniper sets arguments that aren't given to `null` so we have to check the query
+            None if executor.look_ahead().argument(name).is_some() => Nullable::Null,
+            None => Nullable::Absent,
+        }
+    }
+}
+
+impl<T> From<Option<T>> for Nullable<T> {
+    fn from(value: Option<T>) -> Self {
+        match value {
+            Some(value) => Nullable::Value(value),
+            None => Nullable::Null,
+        }
+    }
+}
+
+impl<S, T> juniper::GraphQLType<S> for Nullable<T>
+where
+    S: juniper::ScalarValue,
+    T: juniper::GraphQLType<S>,
+    for<'b> &'b S: juniper::ScalarRefValue<'b>,
+{
+    type Context = T::Context;
+    type TypeInfo = T::TypeInfo;
+
+    fn name(_: &T::TypeInfo) -> Option<&str> {
+        None
+    }
+
+    fn meta<'r>(
+        info: &T::TypeInfo,
+        registry: &mut juniper::Registry<'r, S>,
+    ) -> juniper::meta::MetaType<'r, S>
+    where
+        S: 'r,
+    {
+        registry.build_nullable_type::<T>(info).into_meta()
+    }
+
+    fn resolve(
+        &self,
+        info: &T::TypeInfo,
+        _: Option<&[juniper::Selection<S>]>,
+        executor: &juniper::Executor<Self::Context, S>,
+    ) -> juniper::Value<S> {
+        match self {
+            Nullable::Value(value) => executor.resolve_into_value(info, value),
+            Nullable::Absent | Nullable::Null => juniper::Value::null(),
+        }
+    }
+}
+
+impl<S, T> juniper::FromInputValue<S> for Nullable<T>
+where
+    S: juniper::ScalarValue,
+    T: juniper::FromInputValue<S>,
+{
+    fn from_input_value(value: &juniper::InputValue<S>) -> Option<Self>
+    where
+        for<'b> &'b S: juniper::ScalarRefValue<'b>,
+    {
+        match value {
+            juniper::InputValue::Null => Some(Nullable::Null),
+            value => value.convert().map(Nullable::Value),
+        }
+    }
+}
+
+impl<S, T> juniper::ToInputValue<S> for Nullable<T>
+where
+    S: juniper::ScalarValue,
+    T: juniper::ToInputValue<S>,
+{
+    fn to_input_value(&self) -> juniper::InputValue<S> {
+        match self {
+            Nullable::Value(value) => value.to_input_value(),
+            Nullable::Absent | Nullable::Null => juniper::InputValue::null(),
+        }
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<T: serde::Serialize> serde::Serialize for Nullable<T> {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        match self {
+            Nullable::Value(value) => serializer.serialize_some(value),
+            Nullable::Absent | Nullable::Null => serializer.serialize_none(),
+        }
+    }
+}
+
+// Fields that are missing are handled with `#[serde(default)]` so we only have to care about
+// `null` and values here.
+#[cfg(feature = "serde")]
+impl<'de, T: serde::Deserialize<'de>> serde::Deserialize<'de> for Nullable<T> {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        Option::<T>::deserialize(deserializer).map(Nullable::from)
+    }
+}
+
+/// An input value that didn't satisfy a validation directive such as `@juniper(max: 100)`.
+///
+/// Fields with invalid arguments return a `juniper::FieldError` listing every violation. See the
+/// [crate level docs](index.html#validating-input) for more info.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Violation {
+    /// Where the value is, such as `limit`, `input.name`, or `tags[2]`.
+    pub path: String,
+    /// The constraint that failed. One of `min`, `max`, `max_length`, or `pattern`.
+    pub constraint: &'static str,
+    /// Human readable description of what went wrong.
+    pub message: String,
+}
+
+/// Collects violations of validation directives. Used by the generated code.
+#[doc(hidden)]
+#[derive(Debug, Default)]
+pub struct Validator {
+    violations: Vec<Violation>,
+}
+
+impl Validator {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn min(&mut self, path: &str, value: f64, min: f64) {
+        if value < min {
+            self.violation(path, "min", format!("must be at least {}", min));
+        }
+    }
+
+    pub fn max(&mut self, path: &str, value: f64, max: f64) {
+        if value > max {
+            self.violation(path, "max", format!("must be at most {}", max));
+        }
+    }
+
+    pub fn max_length(&mut self, path: &str, value: &str, max_length: usize) {
+        if value.chars().count() > max_length {
+            self.violation(
+                path,
+                "max_length",
+                format!("must be at most {} characters long", max_length),
+            );
+        }
+    }
+
+    #[cfg(feature = "regex")]
+    pub fn pattern(&mut self, path: &str, value: &str, pattern: &'static str) {
+        thread_local! {
+            static REGEXES: std::cell::RefCell<std::collections::HashMap<&'static str, regex::Regex>> =
+                Default::default();
+        }
+
+        let is_match = REGEXES.with(|regexes| {
+            regexes
+                .borrow_mut()
+                .entry(pattern)
+                // The pattern was checked when the schema was compiled
+                .or_insert_with(|| regex::Regex::new(pattern).expect("invalid pattern"))
+                .is_match(value)
+        });
+
+        if !is_match {
+            self.violation(path, "pattern", format!("must match `{}`", pattern));
+        }
+    }
+
+    fn violation(&mut self, path: &str, constraint: &'static str, message: String) {
+        self.violations.push(Violation {
+            path: path.to_string(),
+            constraint,
+            message,
+        });
+    }
+
+    /// Return an error listing every violation, if there were any.
+    pub fn finish(self) -> juniper::FieldResult<()> {
+        if self.violations.is_empty() {
+            return Ok(());
+        }
+
+        let violations = self
+            .violations
+            .iter()
+            .map(|violation| {
+                let mut object = juniper::Object::with_capacity(3);
+                object.add_field("path", juniper::Value::scalar(violation.path.clone()));
+                object.add_field(
+                    "constraint",
+                    juniper::Value::scalar(violation.constraint.to_string()),
+                );
+                object.add_field("message", juniper::Value::scalar(violation.message.clone()));
+                juniper::Value::object(object)
+            })
+            .collect::<Vec<_>>();
+
+        let mut extensions = juniper::Object::with_capacity(1);
+        extensions.add_field("violations", juniper::Value::list(violations));
+
+        Err(juniper::FieldError::new(
+            "Invalid input",
+            juniper::Value::object(extensions),
+        ))
+    }
+}
+
+/// Implemented for input objects with fields that have validation directives.
+#[doc(hidden)]
+pub trait Validate {
+    fn validate(&self, path: &str, validator: &mut Validator);
+}
+
+/// Resolves fields with `cow` ownership since Juniper doesn't implement `GraphQLType` for `Cow`.
+#[doc(hidden)]
+pub struct CowValue<'a, T: Clone>(pub std::borrow::Cow<'a, T>);
+
+impl<'a, S, T, CtxT> juniper::GraphQLType<S> for CowValue<'a, T>
+where
+    S: juniper::ScalarValue,
+    T: juniper::GraphQLType<S, Context = CtxT> + Clone,
+    for<'b> &'b S: juniper::ScalarRefValue<'b>,
+{
+    type Context = CtxT;
+    type TypeInfo = T::TypeInfo;
+
+    fn name(info: &T::TypeInfo) -> Option<&str> {
+        T::name(info)
+    }
+
+    fn meta<'r>(
+        info: &T::TypeInfo,
+        registry: &mut juniper::Registry<'r, S>,
+    ) -> juniper::meta::MetaType<'r, S>
+    where
+        S: 'r,
+    {
+        T::meta(info, registry)
+    }
+
+    fn resolve_into_type(
+        &self,
+        info: &T::TypeInfo,
+        name: &str,
+        selection_set: Option<&[juniper::Selection<S>]>,
+        executor: &juniper::Executor<CtxT, S>,
+    ) -> juniper::ExecutionResult<S> {
+        (*self.0).resolve_into_type(info, name, selection_set, executor)
+    }
+
+    fn resolve_field(
+        &self,
+        info: &T::TypeInfo,
+        field: &str,
+        args: &juniper::Arguments<S>,
+        executor: &juniper::Executor<CtxT, S>,
+    ) -> juniper::ExecutionResult<S> {
+        (*self.0).resolve_field(info, field, args, executor)
+    }
+
+    fn resolve(
+        &self,
+        info: &T::TypeInfo,
+        selection_set: Option<&[juniper::Selection<S>]>,
+        executor: &juniper::Executor<CtxT, S>,
+    ) -> juniper::Value<S> {
+        (*self.0).resolve(info, selection_set, executor)
+    }
+}
+
+/// Conversions used by custom scalars with `@juniper(repr: "...")`.
+#[doc(hidden)]
+pub mod scalar_repr {
+    use juniper::{
+        parser::{ParseError, ScalarToken, Token},
+        DefaultScalarValue, InputValue, LookAheadValue, ParseScalarResult, Value,
+    };
+    use std::convert::TryFrom;
+
+    // Integers larger than this can't be represented exactly by a float.
+    const MAX_SAFE_INTEGER: i64 = 1 << 53;
+
+    // GraphQL integers are 32 bits so larger values are sent as floats.
+    pub fn i64_to_value(value: i64) -> Value {
+        match i32::try_from(value) {
+            Ok(value) => Value::scalar(value),
+            Err(_) => Value::scalar(value as f64),
+        }
+    }
+
+    pub fn i64_from_input_value(value: &InputValue) -> Option<i64> {
+        value.as_scalar_value().and_then(i64_from_scalar)
+    }
+
+    pub fn i64_from_str(value: ScalarToken<'_>) -> ParseScalarResult<'_> {
+        match value {
+            ScalarToken::Int(int) => match int.parse::<i64>() {
+                Ok(int) => match i32::try_from(int) {
+                    Ok(int) => Ok(DefaultScalarValue::Int(int)),
+                    Err(_) if int.abs() <= MAX_SAFE_INTEGER => {
+                        Ok(DefaultScalarValue::Float(int as f64))
+                    }
+                    Err(_) => Err(ParseError::UnexpectedToken(Token::Scalar(value))),
+                },
+                Err(_) => Err(ParseError::UnexpectedToken(Token::Scalar(value))),
+            },
+            _ => Err(ParseError::UnexpectedToken(Token::Scalar(value))),
+        }
+    }
+
+    pub fn i64_from_look_ahead_value(value: &LookAheadValue<'_, DefaultScalarValue>) -> i64 {
+        match value {
+            LookAheadValue::Scalar(scalar) => i64_from_scalar(scalar)
+                .expect("Failed converting look ahead value. Expected an integer"),
+            _ => panic!("Failed converting look ahead value. Expected scalar type"),
+        }
+    }
+
+    fn i64_from_scalar(scalar: &DefaultScalarValue) -> Option<i64> {
+        match scalar {
+            DefaultScalarValue::Int(int) => Some(i64::from(*int)),
+            DefaultScalarValue::Float(float) => i64_from_float(*float),
+            _ => None,
+        }
+    }
+
+    // Floats further from zero than `MAX_SAFE_INTEGER` might have been rounded, so they're
+    // rejected rather than silently giving a different number.
+    fn i64_from_float(float: f64) -> Option<i64> {
+        if float.fract() == 0.0 && float.abs() <= MAX_SAFE_INTEGER as f64 {
+            Some(float as i64)
+        } else {
+            None
+        }
+    }
+
+    // Big integers are sent as strings so no precision is lost. Integers are accepted as input as
+    // well.
+    #[cfg(feature = "bigint")]
+    pub fn bigint_to_value(value: i64) -> Value {
+        Value::scalar(value.to_string())
+    }
+
+    #[cfg(feature = "bigint")]
+    pub fn bigint_from_input_value(value: &InputValue) -> Option<i64> {
+        value.as_scalar_value().and_then(bigint_from_scalar)
+    }
+
+    // Integer literals that don't fit in 32 bits are kept as strings rather than floats.
+    #[cfg(feature = "bigint")]
+    pub fn bigint_from_str(value: ScalarToken<'_>) -> ParseScalarResult<'_> {
+        match value {
+            ScalarToken::Int(int) => match int.parse::<i64>() {
+                Ok(parsed) => match i32::try_from(parsed) {
+                    Ok(parsed) => Ok(DefaultScalarValue::Int(parsed)),
+                    Err(_) => Ok(DefaultScalarValue::String(int.to_string())),
+                },
+                Err(_) => Err(ParseError::UnexpectedToken(Token::Scalar(value))),
+            },
+            ScalarToken::String(_) => <String as juniper::ParseScalarValue>::from_str(value),
+            ScalarToken::Float(_) => Err(ParseError::UnexpectedToken(Token::Scalar(value))),
+        }
+    }
+
+    #[cfg(feature = "bigint")]
+    pub fn bigint_from_look_ahead_value(value: &LookAheadValue<'_, DefaultScalarValue>) -> i64 {
+        match value {
+            LookAheadValue::Scalar(scalar) => bigint_from_scalar(scalar)
+                .expect("Failed converting look ahead value. Expected an integer"),
+            _ => panic!("Failed converting look ahead value. Expected scalar type"),
+        }
+    }
+
+    // Variables are parsed from JSON, where integers that don't fit in 32 bits become floats.
+    #[cfg(feature = "bigint")]
+    fn bigint_from_scalar(scalar: &DefaultScalarValue) -> Option<i64> {
+        match scalar {
+            DefaultScalarValue::Int(int) => Some(i64::from(*int)),
+            DefaultScalarValue::Float(float) => i64_from_float(*float),
+            DefaultScalarValue::String(string) => string.parse().ok(),
+            DefaultScalarValue::Boolean(_) => None,
+        }
+    }
+
+    pub fn f64_from_input_value(value: &InputValue) -> Option<f64> {
+        match value.as_scalar_value() {
+            Some(DefaultScalarValue::Float(float)) => Some(*float),
+            Some(DefaultScalarValue::Int(int)) => Some(f64::from(*int)),
+            _ => None,
+        }
+    }
+
+    pub fn bool_from_input_value(value: &InputValue) -> Option<bool> {
+        match value.as_scalar_value() {
+            Some(DefaultScalarValue::Boolean(boolean)) => Some(*boolean),
+            _ => None,
+        }
+    }
+
+    pub fn string_from_input_value(value: &InputValue) -> Option<&str> {
+        match value.as_scalar_value() {
+            Some(DefaultScalarValue::String(string)) => Some(string),
+            _ => None,
+        }
+    }
+
+    // Decimals are sent as strings so no precision is lost, but numbers are accepted as input.
+    #[cfg(feature = "decimal")]
+    pub fn decimal_to_value(decimal: &rust_decimal::Decimal) -> Value {
+        Value::scalar(decimal.to_string())
+    }
+
+    #[cfg(feature = "decimal")]
+    pub fn decimal_from_input_value(value: &InputValue) -> Option<rust_decimal::Decimal> {
+        value.as_scalar_value().and_then(decimal_from_scalar)
+    }
+
+    #[cfg(feature = "decimal")]
+    pub fn decimal_from_str(value: ScalarToken<'_>) -> ParseScalarResult<'_> {
+        match value {
+            ScalarToken::Int(_) => <i32 as juniper::ParseScalarValue>::from_str(value)
+                .or_else(|_| <f64 as juniper::ParseScalarValue>::from_str(value)),
+            ScalarToken::Float(_) => <f64 as juniper::ParseScalarValue>::from_str(value),
+            ScalarToken::String(_) => <String as juniper::ParseScalarValue>::from_str(value),
+        }
+    }
+
+    #[cfg(feature = "decimal")]
+    pub fn decimal_from_look_ahead_value(
+        value: &LookAheadValue<'_, DefaultScalarValue>,
+    ) -> rust_decimal::Decimal {
+        match value {
+            LookAheadValue::Scalar(scalar) => decimal_from_scalar(scalar)
+                .expect("Failed converting look ahead value. Expected a decimal"),
+            _ => panic!("Failed converting look ahead value. Expected scalar type"),
+        }
+    }
+
+    #[cfg(feature = "decimal")]
+    fn decimal_from_scalar(scalar: &DefaultScalarValue) -> Option<rust_decimal::Decimal> {
+        use std::str::FromStr;
+
+        match scalar {
+            DefaultScalarValue::Int(int) => Some(rust_decimal::Decimal::from(*int)),
+            DefaultScalarValue::Float(float) => {
+                rust_decimal::Decimal::from_str(&float.to_string()).ok()
+            }
+            DefaultScalarValue::String(string) => rust_decimal::Decimal::from_str(string).ok(),
+            DefaultScalarValue::Boolean(_) => None,
+        }
+    }
+
+    // Durations are sent as ISO 8601 strings such as `PT90.5S`.
+    #[cfg(feature = "duration")]
+    pub fn duration_to_value(duration: &std::time::Duration) -> Value {
+        Value::scalar(format_duration(duration))
+    }
+
+    #[cfg(feature = "duration")]
+    pub fn duration_from_input_value(value: &InputValue) -> Option<std::time::Duration> {
+        string_from_input_value(value).and_then(parse_duration)
+    }
+
+    #[cfg(feature = "duration")]
+    pub fn duration_from_look_ahead_value(
+        value: &LookAheadValue<'_, DefaultScalarValue>,
+    ) -> std::time::Duration {
+        match value {
+            LookAheadValue::Scalar(DefaultScalarValue::String(string)) => parse_duration(string)
+                .unwrap_or_else(|| {
+                    panic!(
+                        "Error parsing Duration. Expected an ISO 8601 duration such as `PT1H30M`, got `{}`",
+                        string
+                    )
+                }),
+            _ => panic!("Failed converting look ahead value. Expected a string"),
+        }
+    }
+
+    #[cfg(feature = "naive-time")]
+    const NAIVE_TIME_FORMAT: &str = "%H:%M:%S%.f";
+
+    #[cfg(feature = "naive-time")]
+    pub fn naive_time_to_value(time: &chrono::NaiveTime) -> Value {
+        Value::scalar(time.format(NAIVE_TIME_FORMAT).to_string())
+    }
+
+    #[cfg(feature = "naive-time")]
+    pub fn naive_time_from_input_value(value: &InputValue) -> Option<chrono::NaiveTime> {
+        string_from_input_value(value)
+            .and_then(|s| chrono::NaiveTime::parse_from_str(s, NAIVE_TIME_FORMAT).ok())
+    }
+
+    #[cfg(feature = "naive-time")]
+    pub fn naive_time_from_look_ahead_value(
+        value: &LookAheadValue<'_, DefaultScalarValue>,
+    ) -> chrono::NaiveTime {
+        match value {
+            LookAheadValue::Scalar(DefaultScalarValue::String(string)) => {
+                chrono::NaiveTime::parse_from_str(string, NAIVE_TIME_FORMAT).unwrap_or_else(|e| {
+                    panic!(
+                        "Error parsing NaiveTime. Format used is `{}`\n{}",
+                        NAIVE_TIME_FORMAT, e
+                    )
+                })
+            }
+            _ => panic!("Failed converting look ahead value. Expected a string"),
+        }
+    }
+
+    #[cfg(feature = "duration")]
+    fn format_duration(duration: &std::time::Duration) -> String {
+        let nanos = duration.subsec_nanos();
+        if nanos == 0 {
+            format!("PT{}S", duration.as_secs())
+        } else {
+            let fraction = format!("{:09}", nanos);
+            format!(
+                "PT{}.{}S",
+                duration.as_secs(),
+                fraction.trim_end_matches('0')
+            )
+        }
+    }
+
+    // Supports `P[nW][nD][T[nH][nM][n[.f]S]]`. Years and months don't have a fixed length so they
+    // are rejected.
+    #[cfg(feature = "duration")]
+    fn parse_duration(input: &str) -> Option<std::time::Duration> {
+        let mut rest = input.strip_prefix('P')?;
+        let mut seconds = 0_u64;
+        let mut nanos = 0_u32;
+        let mut in_time = false;
+        let mut any_component = false;
+
+        while !rest.is_empty() {
+            if !in_time && rest.starts_with('T') {
+                in_time = true;
+                rest = &rest[1..];
+                if rest.is_empty() {
+                    return None;
+                }
+                continue;
+            }
+
+            let end = rest.find(|c: char| !c.is_ascii_digit() && c != '.')?;
+            let (number, unit) = rest.split_at(end);
+            let unit_char = unit.chars().next()?;
+            rest = &unit[unit_char.len_utf8()..];
+
+            let (whole, fraction) = match number.find('.') {
+                Some(idx) => (&number[..idx], Some(&number[idx + 1..])),
+                None => (number, None),
+            };
+            let whole = whole.parse::<u64>().ok()?;
+
+            let multiplier = match (in_time, unit_char) {
+                (false, 'W') => 7 * 24 * 60 * 60,
+                (false, 'D') => 24 * 60 * 60,
+                (true, 'H') => 60 * 60,
+                (true, 'M') => 60,
+                (true, 'S') => 1,
+                _ => return None,
+            };
+
+            // Only seconds can have a fraction.
+            if let Some(fraction) = fraction {
+                if unit_char != 'S'
+                    || fraction.is_empty()
+                    || fraction.len() > 9
+                    || !fraction.chars().all(|c| c.is_ascii_digit())
+                {
+                    return None;
+                }
+                nanos = format!("{:0<9}", fraction).parse().ok()?;
+            }
+
+            seconds = seconds.checked_add(whole.checked_mul(multiplier)?)?;
+            any_component = true;
+        }
+
+        if any_component {
+            Some(std::time::Duration::new(seconds, nanos))
+        } else {
+            None
+        }
+    }
+
+    // The same formats as the chrono backend uses.
+    #[cfg(feature = "time")]
+    pub fn time_date_to_value(date: &time::Date) -> Value {
+        let format = time::macros::format_description!("[year]-[month]-[day]");
+        Value::scalar(date.format(&format).expect("Failed to format Date"))
+    }
+
+    #[cfg(feature = "time")]
+    pub fn time_date_from_input_value(value: &InputValue) -> Option<time::Date> {
+        string_from_input_value(value).and_then(parse_time_date)
+    }
+
+    #[cfg(feature = "time")]
+    pub fn time_date_from_look_ahead_value(
+        value: &LookAheadValue<'_, DefaultScalarValue>,
+    ) -> time::Date {
+        time_from_look_ahead_value(value, parse_time_date, "Date. Format used is `%Y-%m-%d`")
+    }
+
+    #[cfg(feature = "time")]
+    fn parse_time_date(s: &str) -> Option<time::Date> {
+        let format = time::macros::format_description!("[year]-[month]-[day]");
+        time::Date::parse(s, &format).ok()
+    }
+
+    #[cfg(feature = "time")]
+    pub fn offset_date_time_to_value(date_time: &time::OffsetDateTime) -> Value {
+        Value::scalar(
+            date_time
+                .format(&time::format_description::well_known::Rfc3339)
+                .expect("Failed to format OffsetDateTime"),
+        )
+    }
+
+    #[cfg(feature = "time")]
+    pub fn offset_date_time_from_input_value(value: &InputValue) -> Option<time::OffsetDateTime> {
+        string_from_input_value(value).and_then(parse_offset_date_time)
+    }
+
+    #[cfg(feature = "time")]
+    pub fn offset_date_time_from_look_ahead_value(
+        value: &LookAheadValue<'_, DefaultScalarValue>,
+    ) -> time::OffsetDateTime {
+        time_from_look_ahead_value(
+            value,
+            parse_offset_date_time,
+            "OffsetDateTime. Format used is RFC 3339 (aka ISO 8601)",
+        )
+    }
+
+    #[cfg(feature = "time")]
+    fn parse_offset_date_time(s: &str) -> Option<time::OffsetDateTime> {
+        time::OffsetDateTime::parse(s, &time::format_description::well_known::Rfc3339).ok()
+    }
+
+    #[cfg(feature = "time")]
+    pub fn primitive_date_time_to_value(date_time: &time::PrimitiveDateTime) -> Value {
+        let format =
+            time::macros::format_description!("[year]-[month]-[day] [hour]:[minute]:[second]");
+        Value::scalar(
+            date_time
+                .format(&format)
+                .expect("Failed to format PrimitiveDateTime"),
+        )
+    }
+
+    #[cfg(feature = "time")]
+    pub fn primitive_date_time_from_input_value(
+        value: &InputValue,
+    ) -> Option<time::PrimitiveDateTime> {
+        string_from_input_value(value).and_then(parse_primitive_date_time)
+    }
+
+    #[cfg(feature = "time")]
+    pub fn primitive_date_time_from_look_ahead_value(
+        value: &LookAheadValue<'_, DefaultScalarValue>,
+    ) -> time::PrimitiveDateTime {
+        time_from_look_ahead_value(
+            value,
+            parse_primitive_date_time,
+            "PrimitiveDateTime. Format used is `%Y-%m-%d %H:%M:%S`",
+        )
+    }
+
+    #[cfg(feature = "time")]
+    fn parse_primitive_date_time(s: &str) -> Option<time::PrimitiveDateTime> {
+        let format =
+            time::macros::format_description!("[year]-[month]-[day] [hour]:[minute]:[second]");
+        time::PrimitiveDateTime::parse(s, &format).ok()
+    }
+
+    #[cfg(feature = "time")]
+    fn time_from_look_ahead_value<T>(
+        value: &LookAheadValue<'_, DefaultScalarValue>,
+        parse: fn(&str) -> Option<T>,
+        what: &str,
+    ) -> T {
+        match value {
+            LookAheadValue::Scalar(DefaultScalarValue::String(s)) => {
+                parse(s).unwrap_or_else(|| panic!("Error parsing {}, got `{}`", what, s))
+            }
+            _ => panic!("Failed converting look ahead value. Expected a string"),
+        }
+    }
+
+    #[cfg(feature = "json")]
+    pub fn json_to_value(json: &serde_json::Value) -> Value {
+        match json {
+            serde_json::Value::Null => Value::null(),
+            serde_json::Value::Bool(boolean) => Value::scalar(*boolean),
+            serde_json::Value::Number(number) => match number.as_i64() {
+                Some(int) => i64_to_value(int),
+                None => Value::scalar(number.as_f64().unwrap_or_default()),
+            },
+            serde_json::Value::String(string) => Value::scalar(string.clone()),
+            serde_json::Value::Array(values) => {
+                Value::list(values.iter().map(json_to_value).collect())
+            }
+            serde_json::Value::Object(map) => {
+                let mut object = juniper::Object::with_capacity(map.len());
+                for (key, value) in map {
+                    object.add_field(key.clone(), json_to_value(value));
+                }
+                Value::object(object)
+            }
+        }
+    }
+
+    #[cfg(feature = "json")]
+    pub fn json_from_input_value(value: &InputValue) -> Option<serde_json::Value> {
+        match value {
+            InputValue::Null => Some(serde_json::Value::Null),
+            InputValue::Scalar(scalar) => Some(json_from_scalar(scalar)),
+            InputValue::Enum(name) => Some(serde_json::Value::String(name.clone())),
+            InputValue::Variable(_) => None,
+            InputValue::List(values) => values
+                .iter()
+                .map(|value| json_from_input_value(&value.item))
+                .collect::<Option<_>>()
+                .map(serde_json::Value::Array),
+            InputValue::Object(fields) => fields
+                .iter()
+                .map(|(key, value)| {
+                    json_from_input_value(&value.item).map(|value| (key.item.clone(), value))
+                })
+                .collect::<Option<_>>()
+                .map(serde_json::Value::Object),
+        }
+    }
+
+    #[cfg(feature = "json")]
+    pub fn json_from_str(value: ScalarToken<'_>) -> ParseScalarResult<'_> {
+        match value {
+            ScalarToken::Int(_) => i64_from_str(value),
+            ScalarToken::Float(_) => <f64 as juniper::ParseScalarValue>::from_str(value),
+            ScalarToken::String(_) => <String as juniper::ParseScalarValue>::from_str(value),
+        }
+    }
+
+    #[cfg(feature = "json")]
+    pub fn json_from_look_ahead_value(
+        value: &LookAheadValue<'_, DefaultScalarValue>,
+    ) -> serde_json::Value {
+        match value {
+            LookAheadValue::Null => serde_json::Value::Null,
+            LookAheadValue::Scalar(scalar) => json_from_scalar(scalar),
+            LookAheadValue::Enum(name) => serde_json::Value::String(name.to_string()),
+            LookAheadValue::List(values) => {
+                serde_json::Value::Array(values.iter().map(json_from_look_ahead_value).collect())
+            }
+            LookAheadValue::Object(fields) => serde_json::Value::Object(
+                fields
+                    .iter()
+                    .map(|(key, value)| (key.to_string(), json_from_look_ahead_value(value)))
+                    .collect(),
+            ),
+        }
+    }
+
+    #[cfg(feature = "json")]
+    fn json_from_scalar(scalar: &DefaultScalarValue) -> serde_json::Value {
+        match scalar {
+            DefaultScalarValue::Int(int) => serde_json::Value::from(*int),
+            DefaultScalarValue::Float(float) => match i64_from_scalar(scalar) {
+                Some(int) => serde_json::Value::from(int),
+                None => serde_json::Value::from(*float),
+            },
+            DefaultScalarValue::String(string) => serde_json::Value::String(string.clone()),
+            DefaultScalarValue::Boolean(boolean) => serde_json::Value::Bool(*boolean),
+        }
+    }
+
+    #[cfg(all(test, feature = "duration"))]
+    mod test {
+        use super::*;
+        use std::time::Duration;
+
+        #[test]
+        fn parsing_durations() {
+            assert_eq!(parse_duration("PT0S"), Some(Duration::from_secs(0)));
+            assert_eq!(parse_duration("PT1H30M"), Some(Duration::from_secs(5400)));
+            assert_eq!(parse_duration("P1DT1S"), Some(Duration::from_secs(86401)));
+            assert_eq!(parse_duration("P2W"), Some(Duration::from_secs(1_209_600)));
+            assert_eq!(
+                parse_duration("PT1.5S"),
+                Some(Duration::new(1, 500_000_000))
+            );
+
+            assert_eq!(parse_duration("P"), None);
+            assert_eq!(parse_duration("PT"), None);
+            assert_eq!(parse_duration("P1Y"), None);
+            assert_eq!(parse_duration("PT1.5M"), None);
+            assert_eq!(parse_duration("1H"), None);
+        }
+
+        #[test]
+        fn formatting_durations() {
+            assert_eq!(format_duration(&Duration::from_secs(90)), "PT90S");
+            assert_eq!(format_duration(&Duration::new(1, 250_000_000)), "PT1.25S");
+        }
+    }
+}
+
 #[cfg(test)]
 mod test {
     #[allow(unused_imports)]
     use super::*;
 
+    // Some of the expected errors are about features not being enabled, so these only pass with
+    // the default features.
     #[rustversion::nightly]
+    #[cfg(all(
+        feature = "chrono",
+        feature = "uuid",
+        feature = "url",
+        feature = "regex",
+        not(any(
+            feature = "json",
+            feature = "decimal",
+            feature = "bigint",
+            feature = "duration",
+            feature = "naive-time"
+        ))
+    ))]
     #[test]
     fn test_compile_pass() {
         let t = trybuild::TestCases::new();
@@ -0,0 +1,20 @@
+#![allow(dead_code, unused_variables, unused_must_use, unused_imports)]
+include!("../compile_pass/setup.rs");
+
+juniper_from_schema::graphql_schema! {
+    type Query {
+        cursor: Cursor!
+    }
+
+    scalar Cursor @juniper(backend: "time")
+
+    schema { query: Query }
+}
+
+pub struct Query;
+
+impl QueryFields for Query {
+    fn field_cursor<'a>(&self, executor: &Executor<'a, Context>) -> FieldResult<&Cursor> {
+        unimplemented!()
+    }
+}
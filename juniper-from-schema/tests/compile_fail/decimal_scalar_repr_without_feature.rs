@@ -0,0 +1,20 @@
+#![allow(dead_code, unused_variables, unused_must_use, unused_imports)]
+include!("../compile_pass/setup.rs");
+
+juniper_from_schema::graphql_schema! {
+    type Query {
+        price: Price!
+    }
+
+    scalar Price @juniper(repr: "decimal")
+
+    schema { query: Query }
+}
+
+pub struct Query;
+
+impl QueryFields for Query {
+    fn field_price<'a>(&self, executor: &Executor<'a, Context>) -> FieldResult<&Price> {
+        unimplemented!()
+    }
+}
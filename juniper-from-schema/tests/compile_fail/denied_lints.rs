@@ -0,0 +1,15 @@
+#![allow(dead_code, unused_variables, unused_must_use, unused_imports)]
+include!("../compile_pass/setup.rs");
+
+juniper_from_schema::graphql_schema_from_file!(
+    "../../../juniper-from-schema/tests/schemas/lints.graphql",
+    lints: [
+        missing_descriptions: deny,
+        deprecated_without_reason: deny,
+        nullable_list_of_nullable_items: deny,
+        non_screaming_case_enum_values: deny,
+        unreachable_types: deny,
+    ]
+);
+
+pub struct Query;
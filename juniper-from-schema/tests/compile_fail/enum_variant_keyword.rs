@@ -0,0 +1,22 @@
+#![allow(dead_code, unused_variables, unused_must_use, unused_imports)]
+include!("../compile_pass/setup.rs");
+
+juniper_from_schema::graphql_schema! {
+    schema {
+        query: Query
+    }
+
+    type Query {
+        target: Target! @juniper(ownership: "owned")
+    }
+
+    enum Target { SELF OTHER }
+}
+
+pub struct Query;
+
+impl QueryFields for Query {
+    fn field_target(&self, _: &Executor<'_, Context>) -> FieldResult<Target> {
+        unimplemented!()
+    }
+}
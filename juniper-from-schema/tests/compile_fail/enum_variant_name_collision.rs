@@ -0,0 +1,22 @@
+#![allow(dead_code, unused_variables, unused_must_use, unused_imports)]
+include!("../compile_pass/setup.rs");
+
+juniper_from_schema::graphql_schema! {
+    schema {
+        query: Query
+    }
+
+    type Query {
+        color: Color! @juniper(ownership: "owned")
+    }
+
+    enum Color { DARK_RED darkRed }
+}
+
+pub struct Query;
+
+impl QueryFields for Query {
+    fn field_color(&self, _: &Executor<'_, Context>) -> FieldResult<Color> {
+        unimplemented!()
+    }
+}
@@ -0,0 +1,24 @@
+#![allow(dead_code, unused_variables, unused_must_use, unused_imports)]
+include!("../compile_pass/setup.rs");
+
+juniper_from_schema::graphql_schema! {
+    type Query {
+        a(unit: Unit = "METER"): Boolean!
+        b(count: Int = 3000000000): Boolean!
+        c(unit: Unit = MILE): Boolean!
+        d(point: Point = { x: 1, z: 2 }): Boolean!
+        e(point: Point = { x: 1 }): Boolean!
+        f(count: Int = null): Boolean!
+    }
+
+    input Point {
+        x: Int!
+        y: Int!
+    }
+
+    enum Unit { METER FOOT }
+
+    schema { query: Query }
+}
+
+pub struct Query;
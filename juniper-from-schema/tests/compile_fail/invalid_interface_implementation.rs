@@ -0,0 +1,26 @@
+#![allow(dead_code, unused_variables, unused_must_use, unused_imports)]
+include!("../compile_pass/setup.rs");
+
+juniper_from_schema::graphql_schema! {
+    type Query {
+        node: Node!
+    }
+
+    interface Node {
+        id: ID!
+        parent(depth: Int): Node
+    }
+
+    type User implements Node {
+        id: String!
+        parent(levels: Int): Node
+    }
+
+    type Post implements Node & Query {
+        parent(depth: Int): Node
+    }
+
+    schema { query: Query }
+}
+
+pub struct Query;
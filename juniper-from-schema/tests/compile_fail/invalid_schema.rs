@@ -0,0 +1,33 @@
+#![allow(dead_code, unused_variables, unused_must_use, unused_imports)]
+include!("../compile_pass/setup.rs");
+
+juniper_from_schema::graphql_schema! {
+    type Query {
+        user(filter: UserFilter, other: User): User!
+        search: SearchResult!
+        owner: Owner
+        tags: [Tag!]!
+    }
+
+    type User {
+        id: ID!
+        id: ID!
+        filter: UserFilter
+    }
+
+    input UserFilter {
+        name: String
+    }
+
+    type User {
+        name: String!
+    }
+
+    union SearchResult = User | UserFilter
+
+    union Empty
+
+    schema { query: Query }
+}
+
+pub struct Query;
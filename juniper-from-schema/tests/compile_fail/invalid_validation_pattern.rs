@@ -0,0 +1,24 @@
+#![allow(dead_code, unused_variables, unused_must_use, unused_imports)]
+include!("../compile_pass/setup.rs");
+
+juniper_from_schema::graphql_schema! {
+    schema {
+        query: Query
+    }
+
+    type Query {
+        search(query: String @juniper(pattern: "[a-z")): Boolean!
+    }
+}
+
+pub struct Query;
+
+impl QueryFields for Query {
+    fn field_search(
+        &self,
+        _: &Executor<'_, Context>,
+        query: Option<String>,
+    ) -> FieldResult<&bool> {
+        unimplemented!()
+    }
+}
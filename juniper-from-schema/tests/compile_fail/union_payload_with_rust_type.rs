@@ -0,0 +1,44 @@
+#![allow(dead_code, unused_variables, unused_must_use, unused_imports)]
+include!("../compile_pass/setup.rs");
+
+juniper_from_schema::graphql_schema! {
+    schema {
+        query: Query
+    }
+
+    type Query {
+        search: [SearchResult!]! @juniper(ownership: "owned")
+    }
+
+    union SearchResult @juniper(payload: "boxed", rust_type: "SearchResult") = User
+
+    type User {
+        id: ID!
+    }
+}
+
+pub enum SearchResult {
+    User(Box<User>),
+}
+
+pub struct Query;
+
+impl QueryFields for Query {
+    fn field_search<'a>(
+        &self,
+        _: &Executor<'a, Context>,
+        _: &QueryTrail<'a, SearchResult, Walked>,
+    ) -> FieldResult<Vec<SearchResult>> {
+        unimplemented!()
+    }
+}
+
+pub struct User {
+    id: ID,
+}
+
+impl UserFields for User {
+    fn field_id(&self, _: &Executor<'_, Context>) -> FieldResult<&ID> {
+        unimplemented!()
+    }
+}
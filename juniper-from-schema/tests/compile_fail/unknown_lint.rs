@@ -0,0 +1,9 @@
+#![allow(dead_code, unused_variables, unused_must_use, unused_imports)]
+include!("../compile_pass/setup.rs");
+
+juniper_from_schema::graphql_schema_from_file!(
+    "../../../juniper-from-schema/tests/schemas/lints.graphql",
+    lints: [missing_docs: deny]
+);
+
+pub struct Query;
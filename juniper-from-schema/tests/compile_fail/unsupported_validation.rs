@@ -0,0 +1,25 @@
+#![allow(dead_code, unused_variables, unused_must_use, unused_imports)]
+include!("../compile_pass/setup.rs");
+
+juniper_from_schema::graphql_schema! {
+    schema {
+        query: Query
+    }
+
+    type Query {
+        search(query: String @juniper(min: 1), first: Int @juniper(pattern: "[0-9]+")): Boolean!
+    }
+}
+
+pub struct Query;
+
+impl QueryFields for Query {
+    fn field_search(
+        &self,
+        _: &Executor<'_, Context>,
+        query: Option<String>,
+        first: Option<i32>,
+    ) -> FieldResult<&bool> {
+        unimplemented!()
+    }
+}
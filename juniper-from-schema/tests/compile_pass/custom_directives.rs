@@ -0,0 +1,80 @@
+#![allow(dead_code, unused_variables, unused_must_use, unused_imports)]
+include!("setup.rs");
+
+juniper_from_schema::graphql_schema! {
+    schema @link(url: "https://example.com") {
+        query: Query
+    }
+
+    type Query @key(fields: "id") {
+        user(id: ID! @length(max: 10)): User @cacheControl(maxAge: 60) @juniper(ownership: "owned")
+        search(filter: Filter): [SearchResult!]! @juniper(ownership: "owned")
+    }
+
+    interface Node @key(fields: "id") {
+        id: ID!
+    }
+
+    type User implements Node @key(fields: "id") {
+        id: ID! @external
+        role: Role!
+    }
+
+    union SearchResult @tag(name: "search") = User
+
+    enum Role @tag(name: "role") {
+        ADMIN @tag(name: "admin")
+        USER
+    }
+
+    input Filter @tag(name: "filter") {
+        name: String @length(max: 10)
+    }
+
+    scalar Cursor @tag(name: "cursor")
+
+    directive @cacheControl(maxAge: Int) on FIELD_DEFINITION | OBJECT
+    directive @key(fields: String!) on OBJECT | INTERFACE
+    directive @external on FIELD_DEFINITION
+    directive @length(max: Int) on ARGUMENT_DEFINITION | INPUT_FIELD_DEFINITION
+    directive @tag(name: String!) on UNION | ENUM | ENUM_VALUE | INPUT_OBJECT | SCALAR
+    directive @link(url: String!) on SCHEMA
+    directive @juniper(ownership: String) on FIELD_DEFINITION
+}
+
+pub struct Query;
+
+impl QueryFields for Query {
+    fn field_user<'a>(
+        &self,
+        _: &Executor<'a, Context>,
+        _: &QueryTrail<'a, User, Walked>,
+        id: ID,
+    ) -> FieldResult<Option<User>> {
+        unimplemented!()
+    }
+
+    fn field_search<'a>(
+        &self,
+        _: &Executor<'a, Context>,
+        _: &QueryTrail<'a, SearchResult, Walked>,
+        filter: Option<Filter>,
+    ) -> FieldResult<Vec<SearchResult>> {
+        unimplemented!()
+    }
+}
+
+pub struct User {
+    id: ID,
+    role: Role,
+}
+
+impl UserFields for User {
+    fn field_id(&self, _: &Executor<'_, Context>) -> FieldResult<&ID> {
+        unimplemented!()
+    }
+
+    fn field_role(&self, _: &Executor<'_, Context>) -> FieldResult<&Role> {
+        unimplemented!()
+    }
+}
@@ -0,0 +1,42 @@
+#![allow(dead_code, unused_variables, unused_must_use, unused_imports)]
+include!("setup.rs");
+
+juniper_from_schema::graphql_schema_from_file!(
+    "../../../juniper-from-schema/tests/schemas/default_ownership.graphql",
+    default_ownership: owned
+);
+
+pub struct Query;
+
+impl QueryFields for Query {
+    fn field_name<'a>(&self, _: &Executor<'a, Context>) -> FieldResult<String> {
+        unimplemented!()
+    }
+
+    fn field_title<'a>(&self, _: &Executor<'a, Context>) -> FieldResult<&String> {
+        unimplemented!()
+    }
+
+    fn field_user<'a>(
+        &self,
+        _: &Executor<'a, Context>,
+        _: &QueryTrail<'a, User, Walked>,
+    ) -> FieldResult<User> {
+        unimplemented!()
+    }
+}
+
+pub struct User {
+    name: String,
+    email: Option<String>,
+}
+
+impl UserFields for User {
+    fn field_name<'a>(&self, _: &Executor<'a, Context>) -> FieldResult<&String> {
+        unimplemented!()
+    }
+
+    fn field_email<'a>(&self, _: &Executor<'a, Context>) -> FieldResult<Option<&String>> {
+        unimplemented!()
+    }
+}
@@ -0,0 +1,23 @@
+#![allow(dead_code, unused_variables, unused_must_use, unused_imports)]
+include!("setup.rs");
+
+juniper_from_schema::graphql_schema_from_file!(
+    "../../../juniper-from-schema/tests/schemas/derives.graphql",
+    derive: [PartialEq, Eq],
+    attributes: [#[allow(non_camel_case_types)]]
+);
+
+pub struct Query;
+
+impl QueryFields for Query {
+    fn field_users_at_location<'a>(
+        &self,
+        executor: &Executor<'a, Context>,
+        coordinate: Coordinate,
+        unit: Unit,
+    ) -> FieldResult<&bool> {
+        let _ = coordinate == Coordinate::default();
+        let _ = unit < Unit::Foot;
+        unimplemented!()
+    }
+}
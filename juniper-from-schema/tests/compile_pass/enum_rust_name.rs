@@ -0,0 +1,46 @@
+#![allow(dead_code, unused_variables, unused_must_use, unused_imports)]
+include!("setup.rs");
+
+juniper_from_schema::graphql_schema! {
+    schema {
+        query: Query
+    }
+
+    type Query {
+        target(target: Target = SELF): Target! @juniper(ownership: "owned")
+        color: Color! @juniper(ownership: "owned")
+    }
+
+    enum Target {
+        SELF @juniper(rust_name: "Myself")
+        OTHER
+    }
+
+    enum Color @juniper(rust_type: "domain::Color") {
+        DARK_RED
+        darkRed @juniper(rust_name: "AlsoDarkRed") @deprecated
+    }
+}
+
+mod domain {
+    #[derive(Debug, Clone, Copy)]
+    pub enum Color {
+        DarkRed,
+        AlsoDarkRed,
+    }
+}
+
+pub struct Query;
+
+impl QueryFields for Query {
+    fn field_target(&self, _: &Executor<'_, Context>, target: Target) -> FieldResult<Target> {
+        match target {
+            Target::Myself => Ok(Target::Other),
+            Target::Other => Ok(Target::Myself),
+        }
+    }
+
+    fn field_color(&self, _: &Executor<'_, Context>) -> FieldResult<Color> {
+        Ok(Color::from(domain::Color::AlsoDarkRed))
+    }
+}
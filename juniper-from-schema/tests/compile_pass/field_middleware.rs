@@ -0,0 +1,31 @@
+#![allow(dead_code, unused_variables, unused_must_use, unused_imports)]
+include!("setup.rs");
+
+juniper_from_schema::graphql_schema_from_file!(
+    "../../../juniper-from-schema/tests/schemas/very_simple_schema.graphql",
+    middleware: Noop
+);
+
+pub struct Noop;
+
+impl juniper_from_schema::FieldMiddleware<Context> for Noop {
+    fn around<T, F>(
+        _: &'static str,
+        _: &'static str,
+        _: &Executor<'_, Context>,
+        resolve: F,
+    ) -> FieldResult<T>
+    where
+        F: FnOnce() -> FieldResult<T>,
+    {
+        resolve()
+    }
+}
+
+pub struct Query;
+
+impl QueryFields for Query {
+    fn field_string<'a>(&self, _: &Executor<'a, Context>) -> FieldResult<&String> {
+        unimplemented!()
+    }
+}
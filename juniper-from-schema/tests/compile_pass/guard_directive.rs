@@ -0,0 +1,80 @@
+#![allow(dead_code, unused_variables, unused_must_use, unused_imports)]
+include!("setup.rs");
+
+juniper_from_schema::graphql_schema! {
+    schema {
+        query: Query
+    }
+
+    type Query {
+        public: String!
+        secret: String! @juniper(guard: "Role::Admin")
+        infallibleSecret: String! @juniper(guard: "Role::Admin", infallible: true)
+        user: User! @juniper(ownership: "owned")
+        entity: Entity! @juniper(ownership: "owned")
+    }
+
+    type User implements Entity @juniper(guard: "Role::User") {
+        id: ID!
+        email: String! @juniper(guard: "Role::Admin")
+    }
+
+    interface Entity {
+        id: ID!
+    }
+}
+
+pub enum Role {
+    Admin,
+    User,
+}
+
+impl juniper_from_schema::Guard<Role> for Context {
+    fn check(&self, role: &Role) -> FieldResult<()> {
+        unimplemented!()
+    }
+}
+
+pub struct Query;
+
+impl QueryFields for Query {
+    fn field_public(&self, _: &Executor<'_, Context>) -> FieldResult<&String> {
+        unimplemented!()
+    }
+
+    fn field_secret(&self, _: &Executor<'_, Context>) -> FieldResult<&String> {
+        unimplemented!()
+    }
+
+    fn field_infallible_secret(&self, _: &Executor<'_, Context>) -> &String {
+        unimplemented!()
+    }
+
+    fn field_user<'a>(
+        &self,
+        _: &Executor<'a, Context>,
+        _: &QueryTrail<'a, User, Walked>,
+    ) -> FieldResult<User> {
+        unimplemented!()
+    }
+
+    fn field_entity<'a>(
+        &self,
+        _: &Executor<'a, Context>,
+        _: &QueryTrail<'a, Entity, Walked>,
+    ) -> FieldResult<Entity> {
+        unimplemented!()
+    }
+}
+
+pub struct User;
+
+impl UserFields for User {
+    fn field_id(&self, _: &Executor<'_, Context>) -> FieldResult<&ID> {
+        unimplemented!()
+    }
+
+    fn field_email(&self, _: &Executor<'_, Context>) -> FieldResult<&String> {
+        unimplemented!()
+    }
+}
@@ -0,0 +1,32 @@
+#![allow(dead_code, unused_variables, unused_must_use, unused_imports)]
+include!("setup.rs");
+
+juniper_from_schema::graphql_schema! {
+    type Query {
+        string: String!
+    }
+
+    type Mutation {
+        noop: Boolean!
+    }
+}
+
+pub struct Query;
+
+impl QueryFields for Query {
+    fn field_string(&self, _: &Executor<'_, Context>) -> FieldResult<&String> {
+        unimplemented!()
+    }
+}
+
+pub struct Mutation;
+
+impl MutationFields for Mutation {
+    fn field_noop(&self, _: &Executor<'_, Context>) -> FieldResult<&bool> {
+        unimplemented!()
+    }
+}
+
+fn schema() -> Schema {
+    Schema::new(Query, Mutation)
+}
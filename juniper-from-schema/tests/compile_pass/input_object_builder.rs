@@ -0,0 +1,39 @@
+#![allow(dead_code, unused_variables, unused_must_use, unused_imports)]
+include!("setup.rs");
+
+juniper_from_schema::graphql_schema! {
+    type Query {
+        search(filter: SearchFilter!): Boolean!
+    }
+
+    input SearchFilter @juniper(builder: true) {
+        query: String!
+        userIds: [ID!]!
+        limit: Int
+        tags: [String!]
+    }
+
+    schema { query: Query }
+}
+
+pub struct Query;
+
+impl QueryFields for Query {
+    fn field_search<'a>(
+        &self,
+        executor: &Executor<'a, Context>,
+        filter: SearchFilter,
+    ) -> FieldResult<&bool> {
+        unimplemented!()
+    }
+}
+
+fn build_filters() {
+    let filter: SearchFilter = SearchFilter::new("rust".to_string(), vec![ID::new("1")]);
+    let _: Option<i32> = filter.limit;
+
+    let filter = SearchFilter::new("rust".to_string(), Vec::new())
+        .with_limit(10)
+        .with_tags(vec!["graphql".to_string()]);
+    let _: Option<Vec<String>> = filter.tags;
+}
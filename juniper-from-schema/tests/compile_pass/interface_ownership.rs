@@ -0,0 +1,61 @@
+#![allow(dead_code, unused_variables, unused_must_use, unused_imports)]
+include!("setup.rs");
+
+juniper_from_schema::graphql_schema! {
+    type Query {
+        node: Node! @juniper(ownership: "owned")
+    }
+
+    interface Node @juniper(ownership: "owned") {
+        id: ID!
+        name: String!
+    }
+
+    type User implements Node {
+        id: ID!
+        name: String! @juniper(ownership: "owned")
+    }
+
+    type Post implements Node @juniper(ownership: "owned") {
+        id: ID!
+        name: String!
+    }
+
+    schema { query: Query }
+}
+
+pub struct Query;
+
+impl QueryFields for Query {
+    fn field_node(
+        &self,
+        _: &Executor<'_, Context>,
+        _: &QueryTrail<'_, Node, Walked>,
+    ) -> FieldResult<Node> {
+        unimplemented!()
+    }
+}
+
+pub struct User;
+
+impl UserFields for User {
+    fn field_id(&self, _: &Executor<'_, Context>) -> FieldResult<ID> {
+        unimplemented!()
+    }
+
+    fn field_name(&self, _: &Executor<'_, Context>) -> FieldResult<String> {
+        unimplemented!()
+    }
+}
+
+pub struct Post;
+
+impl PostFields for Post {
+    fn field_id(&self, _: &Executor<'_, Context>) -> FieldResult<ID> {
+        unimplemented!()
+    }
+
+    fn field_name(&self, _: &Executor<'_, Context>) -> FieldResult<String> {
+        unimplemented!()
+    }
+}
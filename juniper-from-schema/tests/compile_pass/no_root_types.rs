@@ -0,0 +1,16 @@
+#![allow(dead_code, unused_variables, unused_must_use, unused_imports)]
+include!("setup.rs");
+
+juniper_from_schema::graphql_schema! {
+    type Root {
+        string: String!
+    }
+}
+
+pub struct Root;
+
+impl RootFields for Root {
+    fn field_string(&self, _: &Executor<'_, Context>) -> FieldResult<&String> {
+        unimplemented!()
+    }
+}
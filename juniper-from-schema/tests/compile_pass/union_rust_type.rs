@@ -0,0 +1,59 @@
+#![allow(dead_code, unused_variables, unused_must_use, unused_imports)]
+include!("setup.rs");
+
+juniper_from_schema::graphql_schema! {
+    schema {
+        query: Query
+    }
+
+    type Query {
+        search: [SearchResult!]! @juniper(ownership: "owned")
+    }
+
+    union SearchResult @juniper(rust_type: "SearchResult") = User | Post
+
+    type User {
+        id: ID!
+    }
+
+    type Post {
+        id: ID!
+    }
+}
+
+pub enum SearchResult {
+    User(std::rc::Rc<User>),
+    Post(Post),
+}
+
+pub struct Query;
+
+impl QueryFields for Query {
+    fn field_search<'a>(
+        &self,
+        _: &Executor<'a, Context>,
+        _: &QueryTrail<'a, SearchResult, Walked>,
+    ) -> FieldResult<Vec<SearchResult>> {
+        unimplemented!()
+    }
+}
+
+pub struct User {
+    id: ID,
+}
+
+impl UserFields for User {
+    fn field_id(&self, _: &Executor<'_, Context>) -> FieldResult<&ID> {
+        unimplemented!()
+    }
+}
+
+pub struct Post {
+    id: ID,
+}
+
+impl PostFields for Post {
+    fn field_id(&self, _: &Executor<'_, Context>) -> FieldResult<&ID> {
+        unimplemented!()
+    }
+}
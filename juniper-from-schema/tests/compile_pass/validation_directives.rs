@@ -0,0 +1,55 @@
+#![allow(dead_code, unused_variables, unused_must_use, unused_imports)]
+include!("setup.rs");
+
+juniper_from_schema::graphql_schema! {
+    schema {
+        query: Query
+    }
+
+    type Query {
+        search(query: String! @juniper(max_length: 100)): [Entity!]! @juniper(ownership: "owned")
+        find(ids: [ID!]! @juniper(pattern: "^[0-9]+$"), filter: Filter): Boolean!
+    }
+
+    interface Entity {
+        name(truncate: Int @juniper(min: 0)): String! @juniper(ownership: "owned")
+    }
+
+    type User implements Entity {
+        name(truncate: Int @juniper(min: 0)): String! @juniper(ownership: "owned")
+    }
+
+    input Filter {
+        score: Float @juniper(min: 0, max: 1.5)
+    }
+}
+
+pub struct Query;
+
+impl QueryFields for Query {
+    fn field_search<'a>(
+        &self,
+        _: &Executor<'a, Context>,
+        _: &QueryTrail<'a, Entity, Walked>,
+        query: String,
+    ) -> FieldResult<Vec<Entity>> {
+        unimplemented!()
+    }
+
+    fn field_find(
+        &self,
+        _: &Executor<'_, Context>,
+        ids: Vec<ID>,
+        filter: Option<Filter>,
+    ) -> FieldResult<&bool> {
+        unimplemented!()
+    }
+}
+
+pub struct User;
+
+impl UserFields for User {
+    fn field_name(&self, _: &Executor<'_, Context>, truncate: Option<i32>) -> FieldResult<String> {
+        unimplemented!()
+    }
+}
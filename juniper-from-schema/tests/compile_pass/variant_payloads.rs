@@ -0,0 +1,87 @@
+#![allow(dead_code, unused_variables, unused_must_use, unused_imports)]
+include!("setup.rs");
+
+juniper_from_schema::graphql_schema! {
+    schema {
+        query: Query
+    }
+
+    type Query {
+        entity(id: ID!): Entity! @juniper(ownership: "owned")
+        search: [SearchResult!]! @juniper(ownership: "owned")
+        shared: Shared @juniper(ownership: "owned")
+    }
+
+    interface Entity @juniper(payload: "cow") {
+        id(format: String): ID!
+    }
+
+    union SearchResult @juniper(payload: "rc") = User | Post
+
+    union Shared @juniper(payload: "arc") = User
+
+    type User implements Entity {
+        id(format: String): ID!
+    }
+
+    type Post implements Entity {
+        id(format: String): ID!
+    }
+}
+
+pub struct Query {
+    user: User,
+}
+
+impl QueryFields for Query {
+    fn field_entity<'a>(
+        &self,
+        _: &Executor<'a, Context>,
+        trail: &QueryTrail<'a, Entity, Walked>,
+        id: ID,
+    ) -> FieldResult<Entity> {
+        let format: Option<String> = trail.id_args().format();
+        Ok(Entity::from(&self.user))
+    }
+
+    fn field_search<'a>(
+        &self,
+        _: &Executor<'a, Context>,
+        _: &QueryTrail<'a, SearchResult, Walked>,
+    ) -> FieldResult<Vec<SearchResult>> {
+        Ok(vec![
+            SearchResult::from(std::rc::Rc::new(self.user.clone())),
+            SearchResult::from(Post { id: ID::new("1") }),
+        ])
+    }
+
+    fn field_shared<'a>(
+        &self,
+        _: &Executor<'a, Context>,
+        _: &QueryTrail<'a, Shared, Walked>,
+    ) -> FieldResult<Option<Shared>> {
+        Ok(Some(Shared::from(std::sync::Arc::new(self.user.clone()))))
+    }
+}
+
+#[derive(Clone)]
+pub struct User {
+    id: ID,
+}
+
+impl UserFields for User {
+    fn field_id(&self, _: &Executor<'_, Context>, _: Option<String>) -> FieldResult<&ID> {
+        Ok(&self.id)
+    }
+}
+
+#[derive(Clone)]
+pub struct Post {
+    id: ID,
+}
+
+impl PostFields for Post {
+    fn field_id(&self, _: &Executor<'_, Context>, _: Option<String>) -> FieldResult<&ID> {
+        Ok(&self.id)
+    }
+}
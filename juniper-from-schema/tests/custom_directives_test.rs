@@ -0,0 +1,169 @@
+#![allow(dead_code, unused_variables, unused_imports)]
+
+#[macro_use]
+extern crate juniper;
+
+use juniper::{Executor, FieldResult, Variables, ID};
+use juniper_from_schema::{
+    graphql_schema_from_file, DirectiveUse, DirectiveValue, FieldMiddleware,
+};
+use std::cell::RefCell;
+
+graphql_schema_from_file!(
+    "tests/schemas/custom_directives.graphql",
+    middleware: CacheControl
+);
+
+pub struct Context {
+    max_ages: RefCell<Vec<(String, i64)>>,
+}
+
+impl juniper::Context for Context {}
+
+pub struct CacheControl;
+
+impl FieldMiddleware<Context> for CacheControl {
+    fn around<T, F>(
+        type_name: &'static str,
+        field_name: &'static str,
+        executor: &Executor<'_, Context>,
+        resolve: F,
+    ) -> FieldResult<T>
+    where
+        F: FnOnce() -> FieldResult<T>,
+    {
+        let max_age = directives_for(type_name, field_name)
+            .iter()
+            .find(|directive| directive.name == "cacheControl")
+            .and_then(|directive| directive.argument("maxAge"));
+
+        if let Some(DirectiveValue::Int(max_age)) = max_age {
+            executor
+                .context()
+                .max_ages
+                .borrow_mut()
+                .push((format!("{}.{}", type_name, field_name), *max_age));
+        }
+
+        resolve()
+    }
+}
+
+pub struct Query;
+
+impl QueryFields for Query {
+    fn field_user(
+        &self,
+        _: &Executor<'_, Context>,
+        _: &QueryTrail<'_, User, Walked>,
+    ) -> FieldResult<User> {
+        Ok(User {
+            id: ID::new("1"),
+            name: "Bob".to_string(),
+        })
+    }
+
+    fn field_users(
+        &self,
+        _: &Executor<'_, Context>,
+        _: &QueryTrail<'_, User, Walked>,
+        _: Option<Filter>,
+    ) -> FieldResult<Vec<User>> {
+        Ok(vec![])
+    }
+}
+
+pub struct User {
+    id: ID,
+    name: String,
+}
+
+impl UserFields for User {
+    fn field_id(&self, _: &Executor<'_, Context>) -> FieldResult<&ID> {
+        Ok(&self.id)
+    }
+
+    fn field_name(&self, _: &Executor<'_, Context>) -> FieldResult<&String> {
+        Ok(&self.name)
+    }
+}
+
+#[test]
+fn test_middleware_can_read_custom_directives() {
+    let ctx = Context {
+        max_ages: RefCell::new(Vec::new()),
+    };
+
+    let (_, errors) = juniper::execute(
+        "query { user { id name } }",
+        None,
+        &Schema::new(Query, juniper::EmptyMutation::new()),
+        &Variables::new(),
+        &ctx,
+    )
+    .unwrap();
+    assert_eq!(errors.len(), 0);
+
+    assert_eq!(
+        ctx.max_ages.into_inner(),
+        vec![
+            ("Query.user".to_string(), 60),
+            ("User.name".to_string(), 30)
+        ]
+    );
+}
+
+#[test]
+fn test_field_directives() {
+    assert_eq!(
+        directives_for("User", "name"),
+        &[
+            DirectiveUse {
+                name: "cacheControl",
+                arguments: &[
+                    ("maxAge", DirectiveValue::Int(30)),
+                    ("scope", DirectiveValue::Enum("PRIVATE")),
+                ],
+            },
+            DirectiveUse {
+                name: "tag",
+                arguments: &[(
+                    "names",
+                    DirectiveValue::List(&[
+                        DirectiveValue::String("pii"),
+                        DirectiveValue::String("name"),
+                    ]),
+                )],
+            },
+        ]
+    );
+
+    assert_eq!(
+        directives_for("Filter", "name")[0].argument("value"),
+        Some(&DirectiveValue::Object(&[
+            ("after", DirectiveValue::Null),
+            ("exact", DirectiveValue::Boolean(true)),
+            ("first", DirectiveValue::String("Bob")),
+            ("limit", DirectiveValue::Float(1.5)),
+        ]))
+    );
+
+    assert!(directives_for("User", "id").is_empty());
+    assert!(directives_for("Query", "users").is_empty());
+    assert!(directives_for("Missing", "field").is_empty());
+}
+
+#[test]
+fn test_type_directives() {
+    assert_eq!(
+        directives_for_type("User"),
+        &[DirectiveUse {
+            name: "key",
+            arguments: &[("fields", DirectiveValue::String("id"))],
+        }]
+    );
+    assert_eq!(directives_for_type("Query")[0].name, "cacheControl");
+    assert_eq!(directives_for_type("Filter")[0].name, "tag");
+    assert_eq!(TYPE_DIRECTIVES.len(), 3);
+    assert_eq!(FIELD_DIRECTIVES.len(), 3);
+}
@@ -22,7 +22,7 @@ graphql_schema! {
 
         list(arg: [Int!] = [1, 2, 3]): [Int!]! @juniper(ownership: "owned")
 
-        enumeration(arg: Unit = METER): UNIT! @juniper(ownership: "owned")
+        enumeration(arg: Unit = METER): Unit! @juniper(ownership: "owned")
 
         object(arg: CoordinateIn = { lat: 1.0, long: 2.0 }): CoordinateOut! @juniper(ownership: "owned")
 
@@ -33,8 +33,18 @@ graphql_schema! {
         objectNullablePartial(arg: A = { a: "a arg" }): [String]! @juniper(ownership: "owned")
 
         objectNullableNesting(arg: B = { c: { x: 1 } }): [Int]! @juniper(ownership: "owned")
+
+        floatFromInt(arg: Float = 1): Float! @juniper(ownership: "owned")
+
+        listFromSingleValue(arg: [Int!] = 1): [Int!]! @juniper(ownership: "owned")
+
+        id(arg: ID = 1): ID! @juniper(ownership: "owned")
+
+        cursor(arg: Cursor = "abc"): Cursor! @juniper(ownership: "owned")
     }
 
+    scalar Cursor
+
     input CoordinateIn {
         lat: Float!
         long: Float!
@@ -90,12 +100,7 @@ impl QueryFields for Query {
         Ok(arg)
     }
 
-    fn field_enumeration(
-        &self,
-        _: &Executor<'_, Context>,
-        _: &QueryTrail<'_, Unit, Walked>,
-        arg: Unit,
-    ) -> FieldResult<Unit> {
+    fn field_enumeration(&self, _: &Executor<'_, Context>, arg: Unit) -> FieldResult<Unit> {
         Ok(arg)
     }
 
@@ -142,6 +147,26 @@ impl QueryFields for Query {
     ) -> FieldResult<Vec<Option<i32>>> {
         Ok(vec![b.c.and_then(|c| c.x)])
     }
+
+    fn field_float_from_int(&self, _: &Executor<'_, Context>, arg: f64) -> FieldResult<f64> {
+        Ok(arg)
+    }
+
+    fn field_list_from_single_value(
+        &self,
+        _: &Executor<'_, Context>,
+        arg: Vec<i32>,
+    ) -> FieldResult<Vec<i32>> {
+        Ok(arg)
+    }
+
+    fn field_id(&self, _: &Executor<'_, Context>, arg: juniper::ID) -> FieldResult<juniper::ID> {
+        Ok(arg)
+    }
+
+    fn field_cursor(&self, _: &Executor<'_, Context>, arg: Cursor) -> FieldResult<Cursor> {
+        Ok(arg)
+    }
 }
 
 pub struct CoordinateOut {
@@ -291,6 +316,20 @@ fn test_object_nesting() {
     );
 }
 
+#[test]
+fn test_coerced_defaults() {
+    let value = run_query(r#"query { floatFromInt listFromSingleValue id cursor }"#);
+    assert_json_include!(
+        actual: value,
+        expected: json!({
+            "floatFromInt": 1.0,
+            "listFromSingleValue": [1],
+            "id": "1",
+            "cursor": "abc",
+        })
+    );
+}
+
 fn run_query(query: &str) -> Value {
     let ctx = ();
 
@@ -77,7 +77,11 @@ impl MutationFields for Mutation {
             episode,
             stars: review.stars,
             commentary: review.commentary,
-            favorite_color: review.favorite_color,
+            favorite_color: review.favorite_color.map(|color| Color {
+                red: color.red,
+                green: color.green,
+                blue: color.blue,
+            }),
         };
 
         // the fact that everything type checks is test enough, we don't need to actually insert
@@ -91,7 +95,7 @@ pub struct Review {
     episode: Option<Episode>,
     stars: i32,
     commentary: Option<String>,
-    favorite_color: Option<ColorInput>,
+    favorite_color: Option<Color>,
 }
 
 impl ReviewFields for Review {
@@ -113,12 +117,32 @@ impl ReviewFields for Review {
     fn field_favorite_color<'a>(
         &self,
         executor: &Executor<'a, Context>,
-        _: &QueryTrail<'a, ColorInput, Walked>,
-    ) -> FieldResult<&Option<ColorInput>> {
+        _: &QueryTrail<'a, Color, Walked>,
+    ) -> FieldResult<&Option<Color>> {
         Ok(&self.favorite_color)
     }
 }
 
+pub struct Color {
+    red: i32,
+    green: i32,
+    blue: i32,
+}
+
+impl ColorFields for Color {
+    fn field_red<'a>(&self, executor: &Executor<'a, Context>) -> FieldResult<&i32> {
+        Ok(&self.red)
+    }
+
+    fn field_green<'a>(&self, executor: &Executor<'a, Context>) -> FieldResult<&i32> {
+        Ok(&self.green)
+    }
+
+    fn field_blue<'a>(&self, executor: &Executor<'a, Context>) -> FieldResult<&i32> {
+        Ok(&self.blue)
+    }
+}
+
 #[derive(Clone)]
 pub struct Human {
     id: &'static str,
@@ -0,0 +1,175 @@
+#![allow(dead_code, unused_variables, unused_imports)]
+
+#[macro_use]
+extern crate juniper;
+
+use assert_json_diff::assert_json_include;
+use juniper::{Executor, FieldError, FieldResult, Variables};
+use juniper_from_schema::{graphql_schema, Guard};
+use serde_json::{self, json, Value};
+
+graphql_schema! {
+    type Query {
+        public: String! @juniper(ownership: "owned")
+        secret: String! @juniper(ownership: "owned", guard: "Role::Admin")
+        admin: Admin! @juniper(ownership: "owned")
+        node: Node! @juniper(ownership: "owned")
+    }
+
+    type Admin @juniper(guard: "Role::Admin") {
+        name: String! @juniper(ownership: "owned", infallible: true)
+    }
+
+    interface Node @juniper(guard: "Role::Admin") {
+        id: ID! @juniper(ownership: "owned", infallible: true)
+    }
+
+    type User implements Node {
+        id: ID! @juniper(ownership: "owned", infallible: true)
+        secret: String! @juniper(ownership: "owned", infallible: true)
+    }
+
+    schema { query: Query }
+}
+
+pub enum Role {
+    Admin,
+}
+
+pub struct Context {
+    is_admin: bool,
+}
+
+impl juniper::Context for Context {}
+
+impl Guard<Role> for Context {
+    fn check(&self, role: &Role) -> FieldResult<()> {
+        match role {
+            Role::Admin if self.is_admin => Ok(()),
+            Role::Admin => Err(FieldError::from("Must be an admin")),
+        }
+    }
+}
+
+pub struct Query;
+
+impl QueryFields for Query {
+    fn field_public(&self, _: &Executor<'_, Context>) -> FieldResult<String> {
+        Ok("public".to_string())
+    }
+
+    fn field_secret(&self, _: &Executor<'_, Context>) -> FieldResult<String> {
+        Ok("secret".to_string())
+    }
+
+    fn field_admin(
+        &self,
+        _: &Executor<'_, Context>,
+        _: &QueryTrail<'_, Admin, Walked>,
+    ) -> FieldResult<Admin> {
+        Ok(Admin)
+    }
+
+    fn field_node(
+        &self,
+        _: &Executor<'_, Context>,
+        _: &QueryTrail<'_, Node, Walked>,
+    ) -> FieldResult<Node> {
+        Ok(Node::from(User))
+    }
+}
+
+pub struct Admin;
+
+impl AdminFields for Admin {
+    fn field_name(&self, _: &Executor<'_, Context>) -> String {
+        "Bob".to_string()
+    }
+}
+
+pub struct User;
+
+impl UserFields for User {
+    fn field_id(&self, _: &Executor<'_, Context>) -> juniper::ID {
+        juniper::ID::new("1")
+    }
+
+    fn field_secret(&self, _: &Executor<'_, Context>) -> String {
+        "secret".to_string()
+    }
+}
+
+#[test]
+fn test_guard_allows_access() {
+    let (value, errors) = run_query(
+        r#"query { public secret admin { name } }"#,
+        Context { is_admin: true },
+    );
+    assert_eq!(errors, 0);
+    assert_json_include!(
+        actual: value,
+        expected: json!({
+            "public": "public",
+            "secret": "secret",
+            "admin": { "name": "Bob" },
+        })
+    );
+}
+
+#[test]
+fn test_guard_denies_access_to_field() {
+    let (value, errors) = run_query(r#"query { public secret }"#, Context { is_admin: false });
+    assert_eq!(errors, 1);
+    assert_eq!(value, Value::Null);
+}
+
+#[test]
+fn test_guard_denies_access_to_type() {
+    let (value, errors) = run_query(r#"query { admin { name } }"#, Context { is_admin: false });
+    assert_eq!(errors, 1);
+    assert_eq!(value, Value::Null);
+}
+
+#[test]
+fn test_interface_guard_allows_access() {
+    let (value, errors) = run_query(
+        r#"query { node { id ... on User { secret } } }"#,
+        Context { is_admin: true },
+    );
+    assert_eq!(errors, 0);
+    assert_json_include!(
+        actual: value,
+        expected: json!({ "node": { "id": "1", "secret": "secret" } })
+    );
+}
+
+#[test]
+fn test_interface_guard_denies_access_to_interface_field() {
+    let (value, errors) = run_query(r#"query { node { id } }"#, Context { is_admin: false });
+    assert_eq!(errors, 1);
+    assert_eq!(value, Value::Null);
+}
+
+#[test]
+fn test_interface_guard_denies_access_through_fragment() {
+    let (value, errors) = run_query(
+        r#"query { node { ... on User { secret } } }"#,
+        Context { is_admin: false },
+    );
+    assert_eq!(errors, 1);
+    assert_eq!(value["node"].get("secret"), None);
+}
+
+fn run_query(query: &str, ctx: Context) -> (Value, usize) {
+    let (res, errors) = juniper::execute(
+        query,
+        None,
+        &Schema::new(Query, juniper::EmptyMutation::new()),
+        &Variables::new(),
+        &ctx,
+    )
+    .unwrap();
+
+    let json = serde_json::from_str(&serde_json::to_string(&res).unwrap()).unwrap();
+    (json, errors.len())
+}
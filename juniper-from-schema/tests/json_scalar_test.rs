@@ -0,0 +1,138 @@
+#![cfg(feature = "json")]
+#![allow(dead_code, unused_variables, unused_imports)]
+
+#[macro_use]
+extern crate juniper;
+
+use juniper::{Executor, FieldResult, InputValue, Variables};
+use juniper_from_schema::graphql_schema;
+use serde_json::{self, json, Value};
+
+graphql_schema! {
+    type Query {
+        echo(value: Metadata!): Metadata! @juniper(ownership: "owned")
+        point: Point! @juniper(ownership: "owned")
+        lookAhead: Wrapper! @juniper(ownership: "owned")
+    }
+
+    type Wrapper {
+        value(arg: Metadata!): Metadata! @juniper(ownership: "owned")
+    }
+
+    scalar Metadata @juniper(repr: "json")
+    scalar Point @juniper(repr: "json")
+
+    schema { query: Query }
+}
+
+pub struct Context;
+impl juniper::Context for Context {}
+
+pub struct Query;
+
+impl QueryFields for Query {
+    fn field_echo(&self, _: &Executor<'_, Context>, value: Metadata) -> FieldResult<Metadata> {
+        Ok(value)
+    }
+
+    fn field_point(&self, _: &Executor<'_, Context>) -> FieldResult<Point> {
+        Ok(Point(
+            json!({ "coordinates": [1.5, -2.25], "exact": false }),
+        ))
+    }
+
+    fn field_look_ahead(
+        &self,
+        _: &Executor<'_, Context>,
+        trail: &QueryTrail<'_, Wrapper, Walked>,
+    ) -> FieldResult<Wrapper> {
+        Ok(Wrapper {
+            from_trail: trail.value_args().arg(),
+        })
+    }
+}
+
+pub struct Wrapper {
+    from_trail: Metadata,
+}
+
+impl WrapperFields for Wrapper {
+    fn field_value(&self, _: &Executor<'_, Context>, arg: Metadata) -> FieldResult<Metadata> {
+        assert_eq!(self.from_trail, arg);
+        Ok(arg)
+    }
+}
+
+#[test]
+fn test_json_scalar_from_variables() {
+    let mut variables = Variables::new();
+    variables.insert("value".to_string(), InputValue::scalar(1.5));
+
+    let value = run_query(
+        "query Echo($value: Metadata!) { echo(value: $value) }",
+        variables,
+    );
+    assert_eq!(value, json!({ "echo": 1.5 }));
+}
+
+// Juniper only accepts scalar values as input for custom scalars, so objects and lists can only be
+// returned.
+#[test]
+fn test_json_scalar_rejects_object_input() {
+    let mut variables = Variables::new();
+    variables.insert(
+        "value".to_string(),
+        serde_json::from_value::<InputValue>(json!({ "name": "Bob" })).unwrap(),
+    );
+
+    let schema = Schema::new(Query, juniper::EmptyMutation::new());
+    let result = juniper::execute(
+        "query Echo($value: Metadata!) { echo(value: $value) }",
+        None,
+        &schema,
+        &variables,
+        &Context,
+    );
+    assert!(result.is_err());
+}
+
+#[test]
+fn test_json_scalar_output() {
+    let value = run_query("query { point }", Variables::new());
+    assert_eq!(
+        value,
+        json!({ "point": { "coordinates": [1.5, -2.25], "exact": false } })
+    );
+}
+
+#[test]
+fn test_json_scalar_from_literals() {
+    let value = run_query(
+        r#"query { a: echo(value: "text") b: echo(value: 5) c: echo(value: 3000000000) }"#,
+        Variables::new(),
+    );
+    assert_eq!(value, json!({ "a": "text", "b": 5, "c": 3000000000.0 }));
+}
+
+#[test]
+fn test_json_scalar_in_query_trail() {
+    let value = run_query(
+        r#"query { lookAhead { value(arg: "hi") } }"#,
+        Variables::new(),
+    );
+    assert_eq!(value, json!({ "lookAhead": { "value": "hi" } }));
+}
+
+fn run_query(query: &str, variables: Variables) -> Value {
+    let (res, errors) = juniper::execute(
+        query,
+        None,
+        &Schema::new(Query, juniper::EmptyMutation::new()),
+        &variables,
+        &Context,
+    )
+    .unwrap();
+    assert!(errors.is_empty(), "{:?}", errors);
+
+    serde_json::from_str(&serde_json::to_string(&res).unwrap()).unwrap()
+}
@@ -0,0 +1,166 @@
+#![allow(dead_code, unused_variables, unused_imports)]
+
+#[macro_use]
+extern crate juniper;
+
+use juniper::{Executor, FieldResult, Variables, ID};
+use juniper_from_schema::{graphql_schema_from_file, FieldMiddleware};
+use std::cell::RefCell;
+
+graphql_schema_from_file!(
+    "tests/schemas/middleware.graphql",
+    middleware: RecordCalls
+);
+
+pub struct Context {
+    calls: RefCell<Vec<String>>,
+}
+
+impl juniper::Context for Context {}
+
+pub struct RecordCalls;
+
+impl FieldMiddleware<Context> for RecordCalls {
+    fn around<T, F>(
+        type_name: &'static str,
+        field_name: &'static str,
+        executor: &Executor<'_, Context>,
+        resolve: F,
+    ) -> FieldResult<T>
+    where
+        F: FnOnce() -> FieldResult<T>,
+    {
+        let calls = &executor.context().calls;
+        calls
+            .borrow_mut()
+            .push(format!("before {}.{}", type_name, field_name));
+        let result = resolve();
+        calls
+            .borrow_mut()
+            .push(format!("after {}.{}", type_name, field_name));
+        if let Err(error) = &result {
+            calls.borrow_mut().push(format!(
+                "error {}.{}: {}",
+                type_name,
+                field_name,
+                error.message()
+            ));
+        }
+        result
+    }
+}
+
+pub struct Query;
+
+impl QueryFields for Query {
+    fn field_user(
+        &self,
+        _: &Executor<'_, Context>,
+        _: &QueryTrail<'_, User, Walked>,
+    ) -> FieldResult<User> {
+        Ok(User {
+            id: ID::new("1"),
+            name: "Bob".to_string(),
+        })
+    }
+
+    fn field_entity(
+        &self,
+        _: &Executor<'_, Context>,
+        _: &QueryTrail<'_, Entity, Walked>,
+    ) -> FieldResult<Entity> {
+        Ok(Entity::from(User {
+            id: ID::new("2"),
+            name: "Alice".to_string(),
+        }))
+    }
+
+    fn field_failing(&self, _: &Executor<'_, Context>) -> FieldResult<Option<String>> {
+        Err("boom".into())
+    }
+}
+
+pub struct User {
+    id: ID,
+    name: String,
+}
+
+impl UserFields for User {
+    fn field_id(&self, _: &Executor<'_, Context>) -> FieldResult<&ID> {
+        Ok(&self.id)
+    }
+
+    fn field_name(&self, _: &Executor<'_, Context>) -> &String {
+        &self.name
+    }
+}
+
+#[test]
+fn test_middleware_wraps_every_field() {
+    let calls = run_query("query { user { id name } }");
+
+    assert_eq!(
+        calls,
+        vec![
+            "before Query.user",
+            "after Query.user",
+            "before User.id",
+            "after User.id",
+            "before User.name",
+            "after User.name",
+        ]
+    );
+}
+
+#[test]
+fn test_middleware_wraps_interface_fields() {
+    let calls = run_query("query { entity { id } }");
+
+    assert_eq!(
+        calls,
+        vec![
+            "before Query.entity",
+            "after Query.entity",
+            "before User.id",
+            "after User.id",
+        ]
+    );
+}
+
+#[test]
+fn test_middleware_sees_errors() {
+    let (calls, errors) = run_query_with_errors("query { failing }");
+
+    assert_eq!(errors, 1);
+    assert_eq!(
+        calls,
+        vec![
+            "before Query.failing",
+            "after Query.failing",
+            "error Query.failing: boom",
+        ]
+    );
+}
+
+fn run_query(query: &str) -> Vec<String> {
+    let (calls, errors) = run_query_with_errors(query);
+    assert_eq!(errors, 0);
+    calls
+}
+
+fn run_query_with_errors(query: &str) -> (Vec<String>, usize) {
+    let ctx = Context {
+        calls: RefCell::new(Vec::new()),
+    };
+
+    let (_, errors) = juniper::execute(
+        query,
+        None,
+        &Schema::new(Query, juniper::EmptyMutation::new()),
+        &Variables::new(),
+        &ctx,
+    )
+    .unwrap();
+
+    (ctx.calls.into_inner(), errors.len())
+}
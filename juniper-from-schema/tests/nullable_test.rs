@@ -0,0 +1,234 @@
+#![allow(dead_code, unused_variables, unused_imports)]
+
+#[macro_use]
+extern crate juniper;
+
+use assert_json_diff::assert_json_include;
+use juniper::{Executor, FieldResult, InputValue, Variables};
+use juniper_from_schema::{graphql_schema, Nullable};
+use serde_json::{self, json, Value};
+
+graphql_schema! {
+    type Query {
+        name(name: String @juniper(tri_state: true)): String! @juniper(ownership: "owned")
+        update(input: UpdateInput!): String! @juniper(ownership: "owned")
+        user: User! @juniper(ownership: "owned")
+    }
+
+    type User {
+        name(format: String @juniper(tri_state: true), input: UpdateInput): String!
+            @juniper(ownership: "owned")
+    }
+
+    "Fields to update"
+    input UpdateInput @juniper(builder: true) {
+        "The new bio"
+        bio: String @juniper(tri_state: true)
+        age: Int
+    }
+
+    schema { query: Query }
+}
+
+pub struct Context;
+impl juniper::Context for Context {}
+
+pub struct Query;
+
+impl QueryFields for Query {
+    fn field_name(&self, _: &Executor<'_, Context>, name: Nullable<String>) -> FieldResult<String> {
+        Ok(format!("{:?}", name))
+    }
+
+    fn field_update(&self, _: &Executor<'_, Context>, input: UpdateInput) -> FieldResult<String> {
+        Ok(format!("{:?} {:?}", input.bio, input.age))
+    }
+
+    fn field_user(
+        &self,
+        _: &Executor<'_, Context>,
+        trail: &QueryTrail<'_, User, Walked>,
+    ) -> FieldResult<User> {
+        let args = trail.name_args();
+        let bio = args.input().map(|input| input.bio);
+        Ok(User(format!("{:?} {:?}", args.format(), bio)))
+    }
+}
+
+pub struct User(String);
+
+impl UserFields for User {
+    fn field_name(
+        &self,
+        _: &Executor<'_, Context>,
+        _: Nullable<String>,
+        _: Option<UpdateInput>,
+    ) -> FieldResult<String> {
+        Ok(self.0.clone())
+    }
+}
+
+#[test]
+fn test_arguments() {
+    let value = run_query(
+        r#"query { a: name b: name(name: null) c: name(name: "Bob") }"#,
+        Variables::new(),
+    );
+    assert_json_include!(
+        actual: value,
+        expected: json!({
+            "a": "Absent",
+            "b": "Null",
+            "c": "Value(\"Bob\")",
+        })
+    );
+}
+
+#[test]
+fn test_arguments_from_variables() {
+    let mut variables = Variables::new();
+    variables.insert("null".to_string(), InputValue::null());
+    variables.insert("bob".to_string(), InputValue::scalar("Bob"));
+
+    let value = run_query(
+        r#"query($null: String, $bob: String) { a: name(name: $null) b: name(name: $bob) }"#,
+        variables,
+    );
+    assert_json_include!(
+        actual: value,
+        expected: json!({
+            "a": "Null",
+            "b": "Value(\"Bob\")",
+        })
+    );
+}
+
+// Juniper replaces variables that are declared but not sent with `null` before arguments and look
+// aheads are built, so we can't tell them apart from an explicit `null`.
+#[test]
+fn test_arguments_from_missing_variables() {
+    let value = run_query(
+        r#"query($name: String) { name(name: $name) }"#,
+        Variables::new(),
+    );
+    assert_json_include!(actual: value, expected: json!({ "name": "Null" }));
+}
+
+#[test]
+fn test_input_object_fields_from_missing_variables() {
+    let value = run_query(
+        r#"query($bio: String) { update(input: { bio: $bio }) }"#,
+        Variables::new(),
+    );
+    assert_json_include!(actual: value, expected: json!({ "update": "Null None" }));
+}
+
+#[test]
+fn test_input_object_fields() {
+    let value = run_query(
+        r#"query {
+            a: update(input: {})
+            b: update(input: { bio: null, age: null })
+            c: update(input: { bio: "Hi", age: 1 })
+        }"#,
+        Variables::new(),
+    );
+    assert_json_include!(
+        actual: value,
+        expected: json!({
+            "a": "Absent None",
+            "b": "Null None",
+            "c": "Value(\"Hi\") Some(1)",
+        })
+    );
+}
+
+#[test]
+fn test_input_object_fields_from_variables() {
+    let mut variables = Variables::new();
+    variables.insert(
+        "input".to_string(),
+        InputValue::object(vec![("age", InputValue::scalar(2))].into_iter().collect()),
+    );
+
+    let value = run_query(
+        r#"query($input: UpdateInput!) { update(input: $input) }"#,
+        variables,
+    );
+    assert_json_include!(
+        actual: value,
+        expected: json!({ "update": "Absent Some(2)" })
+    );
+}
+
+#[test]
+fn test_query_trail_arguments() {
+    let value = run_query(
+        r#"query {
+            a: user { name(input: null) }
+            b: user { name(format: null, input: { bio: null, age: null }) }
+            c: user { name(format: "short", input: { age: 1 }) }
+        }"#,
+        Variables::new(),
+    );
+    assert_json_include!(
+        actual: value,
+        expected: json!({
+            "a": { "name": "Absent None" },
+            "b": { "name": "Null Some(Null)" },
+            "c": { "name": "Value(\"short\") Some(Absent)" },
+        })
+    );
+}
+
+#[test]
+fn test_input_object_introspection() {
+    let value = run_query(
+        r#"query {
+            __type(name: "UpdateInput") {
+                description
+                inputFields { name description type { name kind } }
+            }
+        }"#,
+        Variables::new(),
+    );
+    assert_json_include!(
+        actual: value,
+        expected: json!({
+            "__type": {
+                "description": "Fields to update",
+                "inputFields": [
+                    { "name": "bio", "description": "The new bio", "type": { "name": "String", "kind": "SCALAR" } },
+                    { "name": "age", "description": null, "type": { "name": "Int", "kind": "SCALAR" } },
+                ],
+            },
+        })
+    );
+}
+
+#[test]
+fn test_builder() {
+    let input = UpdateInput::new();
+    assert_eq!(Nullable::Absent, input.bio);
+
+    let input = UpdateInput::new().with_bio("Hi".to_string());
+    assert_eq!(Nullable::Value("Hi".to_string()), input.bio);
+}
+
+fn run_query(query: &str, variables: Variables) -> Value {
+    let ctx = Context;
+
+    let (res, errors) = juniper::execute(
+        query,
+        None,
+        &Schema::new(Query, juniper::EmptyMutation::new()),
+        &variables,
+        &ctx,
+    )
+    .unwrap();
+
+    assert!(errors.is_empty(), "{:?}", errors);
+
+    let json: Value = serde_json::from_str(&serde_json::to_string(&res).unwrap()).unwrap();
+    json
+}
@@ -0,0 +1,184 @@
+#![allow(dead_code, unused_variables, unused_imports)]
+
+#[macro_use]
+extern crate juniper;
+
+use assert_json_diff::assert_json_include;
+use juniper::{Executor, FieldResult, Variables};
+use juniper_from_schema::graphql_schema;
+use serde_json::{self, json, Value};
+use std::{borrow::Cow, sync::Arc};
+
+graphql_schema! {
+    type Query {
+        shared: User! @juniper(ownership: "arc")
+        sharedNames: [String!]! @juniper(ownership: "arc")
+        boxed: User @juniper(ownership: "boxed")
+        borrowed: User! @juniper(ownership: "cow")
+        computed: User! @juniper(ownership: "cow")
+        names(limit: Int! @juniper(min: 0)): [String!]! @juniper(ownership: "cow")
+        node: Node! @juniper(ownership: "owned")
+    }
+
+    interface Node {
+        name: String! @juniper(ownership: "cow", infallible: true)
+    }
+
+    type User implements Node {
+        name: String! @juniper(ownership: "cow", infallible: true)
+    }
+
+    schema { query: Query }
+}
+
+pub struct Context;
+impl juniper::Context for Context {}
+
+pub struct Query {
+    user: Arc<User>,
+    names: Vec<String>,
+}
+
+impl QueryFields for Query {
+    fn field_shared(
+        &self,
+        _: &Executor<'_, Context>,
+        _: &QueryTrail<'_, User, Walked>,
+    ) -> FieldResult<Arc<User>> {
+        Ok(Arc::clone(&self.user))
+    }
+
+    fn field_shared_names(&self, _: &Executor<'_, Context>) -> FieldResult<Arc<Vec<String>>> {
+        Ok(Arc::new(self.names.clone()))
+    }
+
+    fn field_boxed(
+        &self,
+        _: &Executor<'_, Context>,
+        _: &QueryTrail<'_, User, Walked>,
+    ) -> FieldResult<Box<Option<User>>> {
+        Ok(Box::new(Some(User {
+            name: "Boxed".to_string(),
+        })))
+    }
+
+    fn field_borrowed(
+        &self,
+        _: &Executor<'_, Context>,
+        _: &QueryTrail<'_, User, Walked>,
+    ) -> FieldResult<Cow<'_, User>> {
+        Ok(Cow::Borrowed(&self.user))
+    }
+
+    fn field_computed(
+        &self,
+        _: &Executor<'_, Context>,
+        _: &QueryTrail<'_, User, Walked>,
+    ) -> FieldResult<Cow<'_, User>> {
+        Ok(Cow::Owned(User {
+            name: "Computed".to_string(),
+        }))
+    }
+
+    fn field_names(
+        &self,
+        _: &Executor<'_, Context>,
+        limit: i32,
+    ) -> FieldResult<Cow<'_, Vec<String>>> {
+        if limit as usize >= self.names.len() {
+            Ok(Cow::Borrowed(&self.names))
+        } else {
+            Ok(Cow::Owned(self.names[..limit as usize].to_vec()))
+        }
+    }
+
+    fn field_node(
+        &self,
+        _: &Executor<'_, Context>,
+        _: &QueryTrail<'_, Node, Walked>,
+    ) -> FieldResult<Node> {
+        Ok(Node::from((*self.user).clone()))
+    }
+}
+
+#[derive(Clone)]
+pub struct User {
+    name: String,
+}
+
+impl UserFields for User {
+    fn field_name(&self, _: &Executor<'_, Context>) -> Cow<'_, String> {
+        Cow::Borrowed(&self.name)
+    }
+}
+
+#[test]
+fn test_arc_and_boxed_ownership() {
+    let (value, errors) = run_query(r#"query { shared { name } sharedNames boxed { name } }"#);
+    assert!(errors.is_empty(), "{:?}", errors);
+    assert_json_include!(
+        actual: value,
+        expected: json!({
+            "shared": { "name": "Bob" },
+            "sharedNames": ["a", "b"],
+            "boxed": { "name": "Boxed" },
+        })
+    );
+}
+
+#[test]
+fn test_cow_ownership() {
+    let (value, errors) = run_query(
+        r#"query {
+            borrowed { name }
+            computed { name }
+            all: names(limit: 5)
+            some: names(limit: 1)
+            node { name }
+        }"#,
+    );
+    assert!(errors.is_empty(), "{:?}", errors);
+    assert_json_include!(
+        actual: value,
+        expected: json!({
+            "borrowed": { "name": "Bob" },
+            "computed": { "name": "Computed" },
+            "all": ["a", "b"],
+            "some": ["a"],
+            "node": { "name": "Bob" },
+        })
+    );
+}
+
+#[test]
+fn test_cow_ownership_with_validation() {
+    let (value, errors) = run_query(r#"query { names(limit: -1) }"#);
+    assert_eq!(value, Value::Null);
+    assert_eq!(errors.len(), 1);
+}
+
+fn run_query(query: &str) -> (Value, Vec<Value>) {
+    let ctx = Context;
+    let query_root = Query {
+        user: Arc::new(User {
+            name: "Bob".to_string(),
+        }),
+        names: vec!["a".to_string(), "b".to_string()],
+    };
+
+    let (res, errors) = juniper::execute(
+        query,
+        None,
+        &Schema::new(query_root, juniper::EmptyMutation::new()),
+        &Variables::new(),
+        &ctx,
+    )
+    .unwrap();
+
+    let value: Value = serde_json::from_str(&serde_json::to_string(&res).unwrap()).unwrap();
+    let errors = errors
+        .iter()
+        .map(|error| serde_json::to_value(error).unwrap())
+        .collect();
+    (value, errors)
+}
@@ -1,3 +1,4 @@
+#![cfg(all(feature = "chrono", feature = "uuid", feature = "url"))]
 #![allow(clippy::too_many_arguments)]
 #![allow(dead_code, unused_variables, unused_imports)]
 
@@ -0,0 +1,136 @@
+#![allow(dead_code, unused_variables, unused_imports)]
+
+#[macro_use]
+extern crate juniper;
+
+use assert_json_diff::assert_json_include;
+use juniper::{Executor, FieldResult, Variables};
+use juniper_from_schema::graphql_schema;
+use serde_json::{self, json, Value};
+
+graphql_schema! {
+    type Query {
+        status(status: OrderStatus = PENDING_REVIEW): OrderStatus! @juniper(ownership: "owned")
+        compare(a: Int!, b: Int!): Ordering! @juniper(ownership: "owned")
+    }
+
+    "The status of an order"
+    enum OrderStatus @juniper(rust_type: "domain::OrderStatus") {
+        PENDING_REVIEW
+        SHIPPED @deprecated(reason: "Orders are delivered directly")
+    }
+
+    enum Ordering @juniper(rust_type: "std::cmp::Ordering") {
+        LESS
+        EQUAL
+        GREATER
+    }
+
+    schema { query: Query }
+}
+
+mod domain {
+    #[derive(Debug, Clone, Copy, PartialEq)]
+    pub enum OrderStatus {
+        PendingReview,
+        Shipped,
+    }
+}
+
+pub struct Context;
+impl juniper::Context for Context {}
+
+pub struct Query;
+
+impl QueryFields for Query {
+    fn field_status(
+        &self,
+        _: &Executor<'_, Context>,
+        status: OrderStatus,
+    ) -> FieldResult<OrderStatus> {
+        let status: domain::OrderStatus = status.into();
+        Ok(status.into())
+    }
+
+    fn field_compare(&self, _: &Executor<'_, Context>, a: i32, b: i32) -> FieldResult<Ordering> {
+        Ok(a.cmp(&b).into())
+    }
+}
+
+#[test]
+fn test_existing_enum_as_argument_and_return_type() {
+    let value = run_query(r#"query { a: status b: status(status: SHIPPED) }"#);
+    assert_json_include!(
+        actual: value,
+        expected: json!({
+            "a": "PENDING_REVIEW",
+            "b": "SHIPPED",
+        })
+    );
+}
+
+#[test]
+fn test_enum_from_another_crate() {
+    let value = run_query(r#"query { a: compare(a: 1, b: 2) b: compare(a: 2, b: 2) }"#);
+    assert_json_include!(
+        actual: value,
+        expected: json!({
+            "a": "LESS",
+            "b": "EQUAL",
+        })
+    );
+}
+
+#[test]
+fn test_conversions() {
+    assert_eq!(
+        domain::OrderStatus::from(OrderStatus::PendingReview),
+        domain::OrderStatus::PendingReview
+    );
+    assert_eq!(
+        OrderStatus::from(domain::OrderStatus::Shipped),
+        OrderStatus::Shipped
+    );
+}
+
+#[test]
+fn test_existing_enum_introspection() {
+    let value = run_query(
+        r#"query {
+            __type(name: "OrderStatus") {
+                description
+                enumValues(includeDeprecated: true) { name isDeprecated }
+            }
+        }"#,
+    );
+    assert_json_include!(
+        actual: value,
+        expected: json!({
+            "__type": {
+                "description": "The status of an order",
+                "enumValues": [
+                    { "name": "PENDING_REVIEW", "isDeprecated": false },
+                    { "name": "SHIPPED", "isDeprecated": true },
+                ],
+            },
+        })
+    );
+}
+
+fn run_query(query: &str) -> Value {
+    let ctx = Context;
+
+    let (res, errors) = juniper::execute(
+        query,
+        None,
+        &Schema::new(Query, juniper::EmptyMutation::new()),
+        &Variables::new(),
+        &ctx,
+    )
+    .unwrap();
+
+    assert!(errors.is_empty(), "{:?}", errors);
+
+    let json: Value = serde_json::from_str(&serde_json::to_string(&res).unwrap()).unwrap();
+    json
+}
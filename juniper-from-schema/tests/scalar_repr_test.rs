@@ -0,0 +1,125 @@
+#![allow(dead_code, unused_variables, unused_imports)]
+
+#[macro_use]
+extern crate juniper;
+
+use assert_json_diff::assert_json_include;
+use juniper::{Executor, FieldResult, Variables};
+use juniper_from_schema::graphql_schema;
+use serde_json::{self, json, Value};
+
+graphql_schema! {
+    type Query {
+        price(cents: Cents!): Cents! @juniper(ownership: "owned")
+        ratio(value: Ratio!): Ratio! @juniper(ownership: "owned")
+        flag(value: Flag!): Flag! @juniper(ownership: "owned")
+        total: Total! @juniper(ownership: "owned")
+    }
+
+    type Total {
+        amount(minimum: Cents!): Cents! @juniper(ownership: "owned")
+    }
+
+    scalar Cents @juniper(repr: "i64")
+    scalar Ratio @juniper(repr: "f64")
+    scalar Flag @juniper(repr: "bool")
+
+    schema { query: Query }
+}
+
+pub struct Context;
+impl juniper::Context for Context {}
+
+pub struct Query;
+
+impl QueryFields for Query {
+    fn field_price(&self, _: &Executor<'_, Context>, cents: Cents) -> FieldResult<Cents> {
+        Ok(Cents(*cents * 2))
+    }
+
+    fn field_ratio(&self, _: &Executor<'_, Context>, value: Ratio) -> FieldResult<Ratio> {
+        Ok(Ratio(value.0 / 2.0))
+    }
+
+    fn field_flag(&self, _: &Executor<'_, Context>, value: Flag) -> FieldResult<Flag> {
+        Ok(Flag(!value.0))
+    }
+
+    fn field_total(
+        &self,
+        _: &Executor<'_, Context>,
+        trail: &QueryTrail<'_, Total, Walked>,
+    ) -> FieldResult<Total> {
+        Ok(Total {
+            minimum: trail.amount_args().minimum(),
+        })
+    }
+}
+
+pub struct Total {
+    minimum: Cents,
+}
+
+impl TotalFields for Total {
+    fn field_amount(&self, _: &Executor<'_, Context>, minimum: Cents) -> FieldResult<Cents> {
+        assert_eq!(self.minimum, minimum);
+        Ok(Cents(*minimum + 1))
+    }
+}
+
+#[test]
+fn test_i64_scalar() {
+    let value = run_query("query { small: price(cents: 21) big: price(cents: 3000000000) }");
+    assert_json_include!(
+        actual: value,
+        expected: json!({ "small": 42, "big": 6000000000.0 })
+    );
+}
+
+#[test]
+fn test_i64_scalar_in_query_trail() {
+    let value = run_query("query { total { amount(minimum: 3000000000) } }");
+    assert_json_include!(
+        actual: value,
+        expected: json!({ "total": { "amount": 3000000001.0 } })
+    );
+}
+
+#[test]
+fn test_i64_scalar_that_would_lose_precision() {
+    let schema = Schema::new(Query, juniper::EmptyMutation::new());
+    let result = juniper::execute(
+        "query { price(cents: 9007199254740993) }",
+        None,
+        &schema,
+        &Variables::new(),
+        &Context,
+    );
+    assert!(result.is_err());
+}
+
+#[test]
+fn test_f64_scalar() {
+    let value = run_query("query { a: ratio(value: 3.0) b: ratio(value: 1) }");
+    assert_json_include!(actual: value, expected: json!({ "a": 1.5, "b": 0.5 }));
+}
+
+#[test]
+fn test_bool_scalar() {
+    let value = run_query("query { flag(value: true) }");
+    assert_json_include!(actual: value, expected: json!({ "flag": false }));
+}
+
+fn run_query(query: &str) -> Value {
+    let (res, errors) = juniper::execute(
+        query,
+        None,
+        &Schema::new(Query, juniper::EmptyMutation::new()),
+        &Variables::new(),
+        &Context,
+    )
+    .unwrap();
+    assert!(errors.is_empty(), "{:?}", errors);
+
+    serde_json::from_str(&serde_json::to_string(&res).unwrap()).unwrap()
+}
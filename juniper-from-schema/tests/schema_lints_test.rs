@@ -0,0 +1,82 @@
+#![deny(deprecated)]
+#![allow(dead_code, unused_variables, unused_imports)]
+
+#[macro_use]
+extern crate juniper;
+
+use juniper::{Executor, FieldResult, Variables};
+use juniper_from_schema::graphql_schema_from_file;
+use serde_json::{self, json, Value};
+
+graphql_schema_from_file!(
+    "tests/schemas/lints.graphql",
+    lints: [
+        missing_descriptions: allow,
+        deprecated_without_reason: allow,
+        nullable_list_of_nullable_items: allow,
+        non_screaming_case_enum_values: allow,
+        unreachable_types: allow,
+    ]
+);
+
+pub struct Context;
+impl juniper::Context for Context {}
+
+pub struct Query;
+
+impl QueryFields for Query {
+    fn field_users(
+        &self,
+        _: &Executor<'_, Context>,
+        _: &QueryTrail<'_, User, Walked>,
+    ) -> FieldResult<&Option<Vec<Option<User>>>> {
+        unimplemented!()
+    }
+
+    fn field_old_users(
+        &self,
+        _: &Executor<'_, Context>,
+        _: &QueryTrail<'_, User, Walked>,
+    ) -> FieldResult<&Vec<User>> {
+        unimplemented!()
+    }
+}
+
+pub struct User;
+
+impl UserFields for User {
+    fn field_name(&self, _: &Executor<'_, Context>) -> FieldResult<&String> {
+        unimplemented!()
+    }
+
+    fn field_role(&self, _: &Executor<'_, Context>) -> FieldResult<&Role> {
+        unimplemented!()
+    }
+}
+
+pub struct Orphan;
+
+impl OrphanFields for Orphan {
+    fn field_id(&self, _: &Executor<'_, Context>) -> FieldResult<&juniper::ID> {
+        unimplemented!()
+    }
+}
+
+#[test]
+fn test_allowed_lints_dont_change_the_schema() {
+    let (res, errors) = juniper::execute(
+        r#"query { __type(name: "Role") { enumValues { name } } }"#,
+        None,
+        &Schema::new(Query, juniper::EmptyMutation::new()),
+        &Variables::new(),
+        &Context,
+    )
+    .unwrap();
+    assert!(errors.is_empty(), "{:?}", errors);
+
+    let value: Value = serde_json::from_str(&serde_json::to_string(&res).unwrap()).unwrap();
+    assert_eq!(
+        value,
+        json!({ "__type": { "enumValues": [{ "name": "ADMIN" }, { "name": "member" }] } })
+    );
+}
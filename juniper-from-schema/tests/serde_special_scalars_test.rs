@@ -0,0 +1,68 @@
+#![cfg(all(
+    feature = "serde",
+    feature = "chrono",
+    feature = "uuid",
+    feature = "url"
+))]
+#![allow(dead_code, unused_variables, unused_imports)]
+
+#[macro_use]
+extern crate juniper;
+
+use juniper::{Executor, FieldResult};
+use juniper_from_schema::graphql_schema;
+use serde_json::json;
+
+graphql_schema! {
+    type Query {
+        search(filter: EventFilter!): Boolean!
+    }
+
+    input EventFilter {
+        day: Date!
+        after: DateTimeUtc
+        id: Uuid
+        source: Url
+    }
+
+    scalar Date
+    scalar DateTimeUtc
+    scalar Uuid
+    scalar Url
+
+    schema { query: Query }
+}
+
+pub struct Context;
+impl juniper::Context for Context {}
+
+pub struct Query;
+
+impl QueryFields for Query {
+    fn field_search(
+        &self,
+        executor: &Executor<'_, Context>,
+        filter: EventFilter,
+    ) -> FieldResult<&bool> {
+        unimplemented!()
+    }
+}
+
+#[test]
+fn input_objects_with_special_case_scalars_round_trip() {
+    let value = json!({
+        "day": "2019-11-03",
+        "after": "2019-11-03T10:00:00Z",
+        "id": "936da01f-9abd-4d9d-80c7-02af85c822a8",
+        "source": "https://example.com/",
+    });
+
+    let filter = serde_json::from_value::<EventFilter>(value.clone()).unwrap();
+    assert_eq!(filter.day, chrono::NaiveDate::from_ymd(2019, 11, 3));
+    assert_eq!(
+        filter.source.as_ref().unwrap().as_str(),
+        "https://example.com/"
+    );
+
+    assert_eq!(serde_json::to_value(&filter).unwrap(), value);
+}
@@ -0,0 +1,99 @@
+#![cfg(feature = "serde")]
+#![allow(dead_code, unused_variables, unused_imports)]
+
+#[macro_use]
+extern crate juniper;
+
+use juniper::{Executor, FieldResult};
+use juniper_from_schema::{graphql_schema, Nullable};
+use serde_json::json;
+
+graphql_schema! {
+    type Query {
+        search(filter: SearchFilter!, tag: Tag, profile: ProfileUpdate): Boolean!
+    }
+
+    input SearchFilter {
+        userName: String!
+        sortOrder: SortOrder
+    }
+
+    input ProfileUpdate {
+        bio: String @juniper(tri_state: true)
+    }
+
+    enum SortOrder {
+        ASC
+        CREATED_AT_DESC
+    }
+
+    scalar Tag
+
+    schema { query: Query }
+}
+
+pub struct Context;
+impl juniper::Context for Context {}
+
+pub struct Query;
+
+impl QueryFields for Query {
+    fn field_search(
+        &self,
+        executor: &Executor<'_, Context>,
+        filter: SearchFilter,
+        tag: Option<Tag>,
+        profile: Option<ProfileUpdate>,
+    ) -> FieldResult<&bool> {
+        unimplemented!()
+    }
+}
+
+#[test]
+fn enums_use_graphql_names() {
+    assert_eq!(
+        serde_json::to_value(SortOrder::CreatedAtDesc).unwrap(),
+        json!("CREATED_AT_DESC")
+    );
+    assert_eq!(
+        serde_json::from_value::<SortOrder>(json!("ASC")).unwrap(),
+        SortOrder::Asc
+    );
+}
+
+#[test]
+fn custom_scalars_are_strings() {
+    assert_eq!(
+        serde_json::to_value(Tag("rust".to_string())).unwrap(),
+        json!("rust")
+    );
+    assert_eq!(
+        serde_json::from_value::<Tag>(json!("rust")).unwrap(),
+        Tag("rust".to_string())
+    );
+}
+
+#[test]
+fn input_objects_round_trip_with_graphql_names() {
+    let value = json!({
+        "userName": "bob",
+        "sortOrder": "CREATED_AT_DESC",
+    });
+
+    let filter = serde_json::from_value::<SearchFilter>(value.clone()).unwrap();
+    assert_eq!(filter.user_name, "bob");
+    assert_eq!(filter.sort_order, Some(SortOrder::CreatedAtDesc));
+
+    assert_eq!(serde_json::to_value(&filter).unwrap(), value);
+}
+
+#[test]
+fn absent_tri_state_fields_are_skipped() {
+    let absent = serde_json::from_value::<ProfileUpdate>(json!({})).unwrap();
+    assert_eq!(absent.bio, Nullable::Absent);
+    assert_eq!(serde_json::to_value(&absent).unwrap(), json!({}));
+
+    let null = serde_json::from_value::<ProfileUpdate>(json!({ "bio": null })).unwrap();
+    assert_eq!(null.bio, Nullable::Null);
+    assert_eq!(serde_json::to_value(&null).unwrap(), json!({ "bio": null }));
+}
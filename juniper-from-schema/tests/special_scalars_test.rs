@@ -0,0 +1,263 @@
+#![cfg(all(
+    feature = "json",
+    feature = "decimal",
+    feature = "bigint",
+    feature = "duration",
+    feature = "naive-time"
+))]
+#![allow(dead_code, unused_variables, unused_imports)]
+
+#[macro_use]
+extern crate juniper;
+
+use juniper::{Executor, FieldResult, InputValue, Variables};
+use juniper_from_schema::graphql_schema;
+use serde_json::{self, json, Value};
+use std::str::FromStr;
+
+graphql_schema! {
+    type Query {
+        price(value: Decimal!): Decimal! @juniper(ownership: "owned")
+        bytes(value: BigInt!): BigInt! @juniper(ownership: "owned")
+        timeout(value: Duration!): Duration! @juniper(ownership: "owned")
+        opensAt(value: Time!): Time! @juniper(ownership: "owned")
+        metadata: JSON! @juniper(ownership: "owned")
+        lookAhead: Wrapper! @juniper(ownership: "owned")
+    }
+
+    type Wrapper {
+        value(
+            price: Decimal!,
+            bytes: BigInt!,
+            timeout: Duration!,
+            opensAt: Time!,
+        ): Boolean!
+    }
+
+    scalar Decimal @juniper(repr: "decimal")
+    scalar BigInt @juniper(repr: "bigint")
+    scalar Duration @juniper(repr: "duration")
+    scalar Time @juniper(repr: "naive_time")
+    scalar JSON @juniper(repr: "json")
+
+    schema { query: Query }
+}
+
+pub struct Context;
+impl juniper::Context for Context {}
+
+pub struct Query;
+
+impl QueryFields for Query {
+    fn field_price(&self, _: &Executor<'_, Context>, value: Decimal) -> FieldResult<Decimal> {
+        Ok(Decimal(*value * rust_decimal::Decimal::from(2)))
+    }
+
+    fn field_bytes(&self, _: &Executor<'_, Context>, value: BigInt) -> FieldResult<BigInt> {
+        Ok(BigInt(*value + 1))
+    }
+
+    fn field_timeout(&self, _: &Executor<'_, Context>, value: Duration) -> FieldResult<Duration> {
+        Ok(Duration(*value * 2))
+    }
+
+    fn field_opens_at(&self, _: &Executor<'_, Context>, value: Time) -> FieldResult<Time> {
+        Ok(Time(*value + chrono::Duration::hours(1)))
+    }
+
+    fn field_metadata(&self, _: &Executor<'_, Context>) -> FieldResult<Json> {
+        Ok(Json(json!({ "tags": ["a", "b"] })))
+    }
+
+    fn field_look_ahead(
+        &self,
+        _: &Executor<'_, Context>,
+        trail: &QueryTrail<'_, Wrapper, Walked>,
+    ) -> FieldResult<Wrapper> {
+        let args = trail.value_args();
+        assert_eq!(
+            *args.price(),
+            rust_decimal::Decimal::from_str("0.1").unwrap()
+        );
+        assert_eq!(*args.bytes(), 10_000_000_000);
+        assert_eq!(*args.timeout(), std::time::Duration::from_secs(90));
+        assert_eq!(
+            *args.opens_at(),
+            chrono::NaiveTime::from_hms_opt(9, 30, 0).unwrap()
+        );
+        Ok(Wrapper)
+    }
+}
+
+pub struct Wrapper;
+
+impl WrapperFields for Wrapper {
+    fn field_value(
+        &self,
+        _: &Executor<'_, Context>,
+        price: Decimal,
+        bytes: BigInt,
+        timeout: Duration,
+        opens_at: Time,
+    ) -> FieldResult<&bool> {
+        Ok(&true)
+    }
+}
+
+#[test]
+fn test_decimal() {
+    let value =
+        run_query(r#"query { a: price(value: "0.1") b: price(value: 3) c: price(value: 1.25) }"#);
+    assert_eq!(value, json!({ "a": "0.2", "b": "6", "c": "2.50" }));
+}
+
+#[test]
+fn test_big_int() {
+    let value = run_query(
+        r#"query {
+            a: bytes(value: 1)
+            b: bytes(value: 3000000000)
+            c: bytes(value: 9007199254740993)
+            d: bytes(value: "9007199254740993")
+        }"#,
+    );
+    assert_eq!(
+        value,
+        json!({
+            "a": "2",
+            "b": "3000000001",
+            "c": "9007199254740994",
+            "d": "9007199254740994",
+        })
+    );
+}
+
+#[test]
+fn test_big_int_from_variables() {
+    let mut variables = Variables::new();
+    variables.insert("small".to_string(), InputValue::scalar(1));
+    variables.insert("big".to_string(), InputValue::scalar("9007199254740993"));
+
+    let (res, errors) = juniper::execute(
+        "query($small: BigInt!, $big: BigInt!) { a: bytes(value: $small) b: bytes(value: $big) }",
+        None,
+        &Schema::new(Query, juniper::EmptyMutation::new()),
+        &variables,
+        &Context,
+    )
+    .unwrap();
+    assert!(errors.is_empty(), "{:?}", errors);
+    let value: Value = serde_json::from_str(&serde_json::to_string(&res).unwrap()).unwrap();
+    assert_eq!(value, json!({ "a": "2", "b": "9007199254740994" }));
+}
+
+#[test]
+fn test_big_int_float() {
+    let schema = Schema::new(Query, juniper::EmptyMutation::new());
+    let result = juniper::execute(
+        "query { bytes(value: 1.5) }",
+        None,
+        &schema,
+        &Variables::new(),
+        &Context,
+    );
+    assert!(result.is_err());
+}
+
+#[test]
+fn test_duration() {
+    let value = run_query(r#"query { a: timeout(value: "PT1M30S") b: timeout(value: "PT0.25S") }"#);
+    assert_eq!(value, json!({ "a": "PT180S", "b": "PT0.5S" }));
+}
+
+#[test]
+fn test_invalid_duration() {
+    let schema = Schema::new(Query, juniper::EmptyMutation::new());
+    let result = juniper::execute(
+        r#"query { timeout(value: "P1Y") }"#,
+        None,
+        &schema,
+        &Variables::new(),
+        &Context,
+    );
+    assert!(result.is_err());
+}
+
+#[test]
+fn test_time() {
+    let value = run_query(r#"query { opensAt(value: "09:30:00") }"#);
+    assert_eq!(value, json!({ "opensAt": "10:30:00" }));
+}
+
+#[test]
+fn test_json() {
+    let value = run_query("query { metadata }");
+    assert_eq!(value, json!({ "metadata": { "tags": ["a", "b"] } }));
+}
+
+#[test]
+fn test_special_scalars_in_query_trail() {
+    let value = run_query(
+        r#"query {
+            lookAhead {
+                value(price: "0.1", bytes: 10000000000, timeout: "PT90S", opensAt: "09:30:00")
+            }
+        }"#,
+    );
+    assert_eq!(value, json!({ "lookAhead": { "value": true } }));
+}
+
+// Enabling a feature doesn't change scalars that don't opt in with `repr`.
+mod without_repr {
+    use super::Context;
+    use juniper::{Executor, FieldResult};
+    use juniper_from_schema::graphql_schema;
+
+    graphql_schema! {
+        type Query {
+            price: Decimal! @juniper(ownership: "owned")
+        }
+
+        scalar Decimal
+
+        schema { query: Query }
+    }
+
+    pub struct Query;
+
+    impl QueryFields for Query {
+        fn field_price(&self, _: &Executor<'_, Context>) -> FieldResult<Decimal> {
+            Ok(Decimal("0.1".to_string()))
+        }
+    }
+}
+
+#[test]
+fn test_scalar_without_repr_is_a_string() {
+    let (res, errors) = juniper::execute(
+        "query { price }",
+        None,
+        &without_repr::Schema::new(without_repr::Query, juniper::EmptyMutation::new()),
+        &Variables::new(),
+        &Context,
+    )
+    .unwrap();
+    assert!(errors.is_empty(), "{:?}", errors);
+
+    let value: Value = serde_json::from_str(&serde_json::to_string(&res).unwrap()).unwrap();
+    assert_eq!(value, json!({ "price": "0.1" }));
+}
+
+fn run_query(query: &str) -> Value {
+    let (res, errors) = juniper::execute(
+        query,
+        None,
+        &Schema::new(Query, juniper::EmptyMutation::new()),
+        &Variables::new(),
+        &Context,
+    )
+    .unwrap();
+    assert!(errors.is_empty(), "{:?}", errors);
+
+    serde_json::from_str(&serde_json::to_string(&res).unwrap()).unwrap()
+}
@@ -0,0 +1,165 @@
+#![cfg(feature = "time")]
+#![allow(dead_code, unused_variables, unused_imports)]
+
+#[macro_use]
+extern crate juniper;
+
+use juniper::{Executor, FieldResult, Variables};
+use juniper_from_schema::graphql_schema;
+use serde_json::{self, json, Value};
+use time::macros::{date, datetime};
+
+graphql_schema! {
+    type Query {
+        nextDay(date: Date!): Date! @juniper(ownership: "owned")
+        later(dateTime: DateTimeUtc!): DateTimeUtc! @juniper(ownership: "owned")
+        lookAhead: Wrapper! @juniper(ownership: "owned")
+    }
+
+    type Wrapper {
+        value(date: Date!, dateTime: DateTimeUtc!): Boolean!
+    }
+
+    scalar Date @juniper(backend: "time")
+    scalar DateTimeUtc @juniper(backend: "time")
+
+    schema { query: Query }
+}
+
+pub struct Context;
+impl juniper::Context for Context {}
+
+pub struct Query;
+
+impl QueryFields for Query {
+    fn field_next_day(&self, _: &Executor<'_, Context>, date: Date) -> FieldResult<Date> {
+        Ok(Date(date.next_day().unwrap()))
+    }
+
+    fn field_later(
+        &self,
+        _: &Executor<'_, Context>,
+        date_time: DateTimeUtc,
+    ) -> FieldResult<DateTimeUtc> {
+        Ok(DateTimeUtc(*date_time + time::Duration::hours(1)))
+    }
+
+    fn field_look_ahead(
+        &self,
+        _: &Executor<'_, Context>,
+        trail: &QueryTrail<'_, Wrapper, Walked>,
+    ) -> FieldResult<Wrapper> {
+        let args = trail.value_args();
+        assert_eq!(*args.date(), date!(2020 - 02 - 29));
+        assert_eq!(*args.date_time(), datetime!(2020-02-29 12:00 +1));
+        Ok(Wrapper)
+    }
+}
+
+pub struct Wrapper;
+
+impl WrapperFields for Wrapper {
+    fn field_value(
+        &self,
+        _: &Executor<'_, Context>,
+        date: Date,
+        date_time: DateTimeUtc,
+    ) -> FieldResult<&bool> {
+        Ok(&true)
+    }
+}
+
+mod without_time_zone {
+    use juniper::{Executor, FieldResult};
+    use juniper_from_schema::graphql_schema;
+
+    graphql_schema! {
+        type Query {
+            later(dateTime: DateTimeUtc!): DateTimeUtc! @juniper(ownership: "owned")
+        }
+
+        scalar DateTimeUtc @juniper(backend: "time", with_time_zone: false)
+
+        schema { query: Query }
+    }
+
+    pub struct Context;
+    impl juniper::Context for Context {}
+
+    pub struct Query;
+
+    impl QueryFields for Query {
+        fn field_later(
+            &self,
+            _: &Executor<'_, Context>,
+            date_time: DateTimeUtc,
+        ) -> FieldResult<DateTimeUtc> {
+            Ok(DateTimeUtc(*date_time + time::Duration::minutes(30)))
+        }
+    }
+}
+
+#[test]
+fn test_date() {
+    let value = run_query(r#"query { nextDay(date: "2020-02-28") }"#);
+    assert_eq!(value, json!({ "nextDay": "2020-02-29" }));
+}
+
+#[test]
+fn test_offset_date_time() {
+    let value = run_query(r#"query { later(dateTime: "2020-02-29T23:30:00+01:00") }"#);
+    assert_eq!(value, json!({ "later": "2020-03-01T00:30:00+01:00" }));
+}
+
+#[test]
+fn test_invalid_date() {
+    let schema = Schema::new(Query, juniper::EmptyMutation::new());
+    let result = juniper::execute(
+        r#"query { nextDay(date: "2020-02-30") }"#,
+        None,
+        &schema,
+        &Variables::new(),
+        &Context,
+    );
+    assert!(result.is_err());
+}
+
+#[test]
+fn test_time_scalars_in_query_trail() {
+    let value = run_query(
+        r#"query {
+            lookAhead { value(date: "2020-02-29", dateTime: "2020-02-29T12:00:00+01:00") }
+        }"#,
+    );
+    assert_eq!(value, json!({ "lookAhead": { "value": true } }));
+}
+
+#[test]
+fn test_primitive_date_time() {
+    let (res, errors) = juniper::execute(
+        r#"query { later(dateTime: "2020-02-29 23:45:00") }"#,
+        None,
+        &without_time_zone::Schema::new(without_time_zone::Query, juniper::EmptyMutation::new()),
+        &Variables::new(),
+        &without_time_zone::Context,
+    )
+    .unwrap();
+    assert!(errors.is_empty(), "{:?}", errors);
+
+    let value: Value = serde_json::from_str(&serde_json::to_string(&res).unwrap()).unwrap();
+    assert_eq!(value, json!({ "later": "2020-03-01 00:15:00" }));
+}
+
+fn run_query(query: &str) -> Value {
+    let (res, errors) = juniper::execute(
+        query,
+        None,
+        &Schema::new(Query, juniper::EmptyMutation::new()),
+        &Variables::new(),
+        &Context,
+    )
+    .unwrap();
+    assert!(errors.is_empty(), "{:?}", errors);
+
+    serde_json::from_str(&serde_json::to_string(&res).unwrap()).unwrap()
+}
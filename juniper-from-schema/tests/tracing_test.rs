@@ -0,0 +1,102 @@
+#![cfg(feature = "tracing")]
+#![allow(dead_code, unused_variables, unused_imports)]
+
+#[macro_use]
+extern crate juniper;
+
+use juniper::{Executor, FieldResult, Variables};
+use juniper_from_schema::graphql_schema;
+use std::sync::{
+    atomic::{AtomicUsize, Ordering},
+    Mutex,
+};
+use tracing::{
+    span::{Attributes, Id, Record},
+    Event, Metadata, Subscriber,
+};
+
+graphql_schema! {
+    type Query {
+        hello(name: String!, greeting: String): String! @juniper(ownership: "owned")
+    }
+
+    schema { query: Query }
+}
+
+pub struct Context;
+impl juniper::Context for Context {}
+
+pub struct Query;
+
+impl QueryFields for Query {
+    fn field_hello(
+        &self,
+        _: &Executor<'_, Context>,
+        name: String,
+        greeting: Option<String>,
+    ) -> FieldResult<String> {
+        Ok(format!("Hello, {}!", name))
+    }
+}
+
+#[derive(Default)]
+struct RecordSpans {
+    next_id: AtomicUsize,
+    spans: Mutex<Vec<(String, String)>>,
+}
+
+impl Subscriber for RecordSpans {
+    fn enabled(&self, _: &Metadata<'_>) -> bool {
+        true
+    }
+
+    fn new_span(&self, attrs: &Attributes<'_>) -> Id {
+        let fields = attrs
+            .metadata()
+            .fields()
+            .iter()
+            .map(|field| field.name())
+            .collect::<Vec<_>>()
+            .join(", ");
+        self.spans
+            .lock()
+            .unwrap()
+            .push((attrs.metadata().name().to_string(), fields));
+        Id::from_u64(self.next_id.fetch_add(1, Ordering::SeqCst) as u64 + 1)
+    }
+
+    fn record(&self, _: &Id, _: &Record<'_>) {}
+
+    fn record_follows_from(&self, _: &Id, _: &Id) {}
+
+    fn event(&self, _: &Event<'_>) {}
+
+    fn enter(&self, _: &Id) {}
+
+    fn exit(&self, _: &Id) {}
+}
+
+#[test]
+fn test_fields_are_resolved_inside_spans() {
+    let subscriber = std::sync::Arc::new(RecordSpans::default());
+
+    tracing::subscriber::with_default(subscriber.clone(), || {
+        let (_, errors) = juniper::execute(
+            r#"query { hello(name: "Ferris") }"#,
+            None,
+            &Schema::new(Query, juniper::EmptyMutation::new()),
+            &Variables::new(),
+            &Context,
+        )
+        .unwrap();
+        assert_eq!(errors.len(), 0);
+    });
+
+    assert_eq!(
+        *subscriber.spans.lock().unwrap(),
+        vec![(
+            "Query.hello".to_string(),
+            "type_name, field_name, arguments".to_string()
+        )]
+    );
+}
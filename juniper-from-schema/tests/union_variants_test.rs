@@ -0,0 +1,274 @@
+#![allow(dead_code, unused_variables, unused_imports)]
+
+#[macro_use]
+extern crate juniper;
+
+use assert_json_diff::assert_json_include;
+use juniper::{Executor, FieldResult, Variables};
+use juniper_from_schema::graphql_schema;
+use serde_json::{self, json, Value};
+use std::sync::Arc;
+
+graphql_schema! {
+    type Query {
+        tree: Tree! @juniper(ownership: "owned")
+        search: [SearchResult!]! @juniper(ownership: "owned")
+        shared: [Node!]! @juniper(ownership: "owned")
+        borrowed: [Entry!]! @juniper(ownership: "owned")
+    }
+
+    interface Node @juniper(payload: "arc") {
+        name: String!
+    }
+
+    union Entry @juniper(payload: "cow") = User | Post
+
+    union Tree @juniper(payload: "boxed") = Leaf | Branch
+
+    type Leaf {
+        value: Int!
+    }
+
+    type Branch {
+        left: Tree!
+        right: Tree!
+    }
+
+    union SearchResult @juniper(rust_type: "domain::SearchResult") = User | Post
+
+    type User implements Node {
+        name: String!
+    }
+
+    type Post {
+        title: String!
+    }
+
+    schema { query: Query }
+}
+
+mod domain {
+    use super::{Post, User};
+    use std::sync::Arc;
+
+    pub enum SearchResult {
+        User(Arc<User>),
+        Post(Box<Post>),
+    }
+}
+
+pub struct Context;
+impl juniper::Context for Context {}
+
+pub struct Query {
+    user: User,
+}
+
+impl QueryFields for Query {
+    fn field_tree(
+        &self,
+        _: &Executor<'_, Context>,
+        _: &QueryTrail<'_, Tree, Walked>,
+    ) -> FieldResult<Tree> {
+        Ok(Tree::from(Branch {
+            left: Tree::from(Leaf { value: 1 }),
+            right: Tree::from(Box::new(Leaf { value: 2 })),
+        }))
+    }
+
+    fn field_search(
+        &self,
+        _: &Executor<'_, Context>,
+        _: &QueryTrail<'_, SearchResult, Walked>,
+    ) -> FieldResult<Vec<SearchResult>> {
+        let user = Arc::new(User {
+            name: "Bob".to_string(),
+        });
+
+        Ok(vec![
+            domain::SearchResult::User(Arc::clone(&user)),
+            domain::SearchResult::Post(Box::new(Post {
+                title: "Hello".to_string(),
+            })),
+        ])
+    }
+
+    fn field_shared(
+        &self,
+        _: &Executor<'_, Context>,
+        _: &QueryTrail<'_, Node, Walked>,
+    ) -> FieldResult<Vec<Node>> {
+        let user = Arc::new(User {
+            name: "Bob".to_string(),
+        });
+
+        Ok(vec![Node::from(Arc::clone(&user)), Node::from(user)])
+    }
+
+    fn field_borrowed(
+        &self,
+        _: &Executor<'_, Context>,
+        _: &QueryTrail<'_, Entry, Walked>,
+    ) -> FieldResult<Vec<Entry<'_>>> {
+        Ok(vec![
+            Entry::from(&self.user),
+            Entry::from(Post {
+                title: "Owned".to_string(),
+            }),
+        ])
+    }
+}
+
+pub struct Leaf {
+    value: i32,
+}
+
+impl LeafFields for Leaf {
+    fn field_value(&self, _: &Executor<'_, Context>) -> FieldResult<&i32> {
+        Ok(&self.value)
+    }
+}
+
+pub struct Branch {
+    left: Tree,
+    right: Tree,
+}
+
+impl BranchFields for Branch {
+    fn field_left(
+        &self,
+        _: &Executor<'_, Context>,
+        _: &QueryTrail<'_, Tree, Walked>,
+    ) -> FieldResult<&Tree> {
+        Ok(&self.left)
+    }
+
+    fn field_right(
+        &self,
+        _: &Executor<'_, Context>,
+        _: &QueryTrail<'_, Tree, Walked>,
+    ) -> FieldResult<&Tree> {
+        Ok(&self.right)
+    }
+}
+
+#[derive(Clone)]
+pub struct User {
+    name: String,
+}
+
+impl UserFields for User {
+    fn field_name(&self, _: &Executor<'_, Context>) -> FieldResult<&String> {
+        Ok(&self.name)
+    }
+}
+
+#[derive(Clone)]
+pub struct Post {
+    title: String,
+}
+
+impl PostFields for Post {
+    fn field_title(&self, _: &Executor<'_, Context>) -> FieldResult<&String> {
+        Ok(&self.title)
+    }
+}
+
+#[test]
+fn test_boxed_union() {
+    let value = run_query(
+        r#"query {
+            tree {
+                ... on Branch {
+                    left { ... on Leaf { value } }
+                    right { ... on Leaf { value } }
+                }
+            }
+        }"#,
+    );
+    assert_json_include!(
+        actual: value,
+        expected: json!({
+            "tree": {
+                "left": { "value": 1 },
+                "right": { "value": 2 },
+            },
+        })
+    );
+}
+
+#[test]
+fn test_arc_interface() {
+    let value = run_query(r#"query { shared { name } }"#);
+    assert_json_include!(
+        actual: value,
+        expected: json!({
+            "shared": [{ "name": "Bob" }, { "name": "Bob" }],
+        })
+    );
+}
+
+#[test]
+fn test_cow_union() {
+    let value = run_query(
+        r#"query {
+            borrowed {
+                ... on User { name }
+                ... on Post { title }
+            }
+        }"#,
+    );
+    assert_json_include!(
+        actual: value,
+        expected: json!({
+            "borrowed": [{ "name": "Alice" }, { "title": "Owned" }],
+        })
+    );
+}
+
+#[test]
+fn test_union_mapped_to_existing_enum() {
+    let value = run_query(
+        r#"query {
+            search {
+                __typename
+                ... on User { name }
+                ... on Post { title }
+            }
+        }"#,
+    );
+    assert_json_include!(
+        actual: value,
+        expected: json!({
+            "search": [
+                { "__typename": "User", "name": "Bob" },
+                { "__typename": "Post", "title": "Hello" },
+            ],
+        })
+    );
+}
+
+fn run_query(query: &str) -> Value {
+    let ctx = Context;
+
+    let (res, errors) = juniper::execute(
+        query,
+        None,
+        &Schema::new(
+            Query {
+                user: User {
+                    name: "Alice".to_string(),
+                },
+            },
+            juniper::EmptyMutation::new(),
+        ),
+        &Variables::new(),
+        &ctx,
+    )
+    .unwrap();
+
+    assert!(errors.is_empty(), "{:?}", errors);
+
+    let json: Value = serde_json::from_str(&serde_json::to_string(&res).unwrap()).unwrap();
+    json
+}
@@ -0,0 +1,159 @@
+#![cfg(feature = "regex")]
+#![allow(dead_code, unused_variables, unused_imports)]
+
+#[macro_use]
+extern crate juniper;
+
+use assert_json_diff::assert_json_include;
+use juniper::{Executor, FieldResult, Variables};
+use juniper_from_schema::{graphql_schema, Nullable};
+use serde_json::{self, json, Value};
+
+graphql_schema! {
+    type Query {
+        search(
+            query: String! @juniper(max_length: 10, pattern: "^[a-z]+$"),
+            limit: Int = 10 @juniper(min: 1, max: 100),
+            tags: [String!] @juniper(max_length: 3),
+            filter: Filter,
+        ): Boolean! @juniper(ownership: "owned", infallible: true)
+    }
+
+    input Filter {
+        name: String @juniper(max_length: 5)
+        price: Float @juniper(min: 0.5)
+        items: [Item!]
+    }
+
+    input Item {
+        code: ID! @juniper(pattern: "^[A-Z]+$")
+        note: String @juniper(tri_state: true, max_length: 2)
+    }
+
+    schema { query: Query }
+}
+
+pub struct Context;
+impl juniper::Context for Context {}
+
+pub struct Query;
+
+impl QueryFields for Query {
+    fn field_search(
+        &self,
+        _: &Executor<'_, Context>,
+        query: String,
+        limit: i32,
+        tags: Option<Vec<String>>,
+        filter: Option<Filter>,
+    ) -> bool {
+        true
+    }
+}
+
+#[test]
+fn test_valid_arguments() {
+    let (value, errors) = run_query(
+        r#"query {
+            search(
+                query: "rust",
+                limit: 100,
+                tags: ["a", "bcd"],
+                filter: { name: "hat", price: 0.5, items: [{ code: "AB", note: null }] },
+            )
+        }"#,
+    );
+    assert!(errors.is_empty(), "{:?}", errors);
+    assert_json_include!(actual: value, expected: json!({ "search": true }));
+}
+
+#[test]
+fn test_every_violation_is_reported() {
+    let (value, errors) = run_query(
+        r#"query {
+            search(
+                query: "Way too long",
+                limit: 0,
+                tags: ["a", "abcd"],
+                filter: { name: "sunglasses", price: 0.1, items: [{ code: "AB" }, { code: "x", note: "abc" }] },
+            )
+        }"#,
+    );
+    assert_eq!(value, Value::Null);
+    assert_eq!(errors.len(), 1);
+
+    let error = &errors[0];
+    assert_eq!(error["message"], json!("Invalid input"));
+    assert_eq!(
+        error["extensions"]["violations"],
+        json!([
+            {
+                "path": "query",
+                "constraint": "max_length",
+                "message": "must be at most 10 characters long",
+            },
+            {
+                "path": "query",
+                "constraint": "pattern",
+                "message": "must match `^[a-z]+$`",
+            },
+            {
+                "path": "limit",
+                "constraint": "min",
+                "message": "must be at least 1",
+            },
+            {
+                "path": "tags[1]",
+                "constraint": "max_length",
+                "message": "must be at most 3 characters long",
+            },
+            {
+                "path": "filter.name",
+                "constraint": "max_length",
+                "message": "must be at most 5 characters long",
+            },
+            {
+                "path": "filter.price",
+                "constraint": "min",
+                "message": "must be at least 0.5",
+            },
+            {
+                "path": "filter.items[1].code",
+                "constraint": "pattern",
+                "message": "must match `^[A-Z]+$`",
+            },
+            {
+                "path": "filter.items[1].note",
+                "constraint": "max_length",
+                "message": "must be at most 2 characters long",
+            },
+        ])
+    );
+}
+
+#[test]
+fn test_absent_arguments_are_not_validated() {
+    let (value, errors) = run_query(r#"query { search(query: "rust") }"#);
+    assert!(errors.is_empty(), "{:?}", errors);
+    assert_json_include!(actual: value, expected: json!({ "search": true }));
+}
+
+fn run_query(query: &str) -> (Value, Vec<Value>) {
+    let ctx = Context;
+
+    let (res, errors) = juniper::execute(
+        query,
+        None,
+        &Schema::new(Query, juniper::EmptyMutation::new()),
+        &Variables::new(),
+        &ctx,
+    )
+    .unwrap();
+
+    let value: Value = serde_json::from_str(&serde_json::to_string(&res).unwrap()).unwrap();
+    let errors = errors
+        .iter()
+        .map(|error| serde_json::to_value(error).unwrap())
+        .collect();
+    (value, errors)
+}